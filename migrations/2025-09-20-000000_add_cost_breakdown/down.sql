@@ -0,0 +1,2 @@
+-- Drop the itemized study costs column
+ALTER TABLE profiles DROP COLUMN cost_breakdown;
@@ -0,0 +1,2 @@
+-- Store itemized study costs as JSON
+ALTER TABLE profiles ADD COLUMN cost_breakdown TEXT;
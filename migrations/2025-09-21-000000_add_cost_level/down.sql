@@ -0,0 +1,2 @@
+-- Drop the cost level column
+ALTER TABLE profiles DROP COLUMN cost_level;
@@ -0,0 +1,2 @@
+-- Cost level (Budget/Standard/Comfortable) used when evaluating a profile
+ALTER TABLE profiles ADD COLUMN cost_level TEXT NOT NULL DEFAULT 'Standard';
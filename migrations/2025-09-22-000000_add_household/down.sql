@@ -0,0 +1,2 @@
+-- Drop the household column
+ALTER TABLE profiles DROP COLUMN household;
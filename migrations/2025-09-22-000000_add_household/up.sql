@@ -0,0 +1,2 @@
+-- Household composition (spouse/children) stored as JSON
+ALTER TABLE profiles ADD COLUMN household TEXT;
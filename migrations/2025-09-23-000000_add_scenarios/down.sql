@@ -0,0 +1,2 @@
+-- Drop the scenarios column
+ALTER TABLE profiles DROP COLUMN scenarios;
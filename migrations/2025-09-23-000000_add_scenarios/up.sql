@@ -0,0 +1,2 @@
+-- Scenario sets (weighted parameter overrides) stored as JSON
+ALTER TABLE profiles ADD COLUMN scenarios TEXT;
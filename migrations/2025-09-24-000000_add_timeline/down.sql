@@ -0,0 +1,2 @@
+-- Drop the timeline column
+ALTER TABLE profiles DROP COLUMN timeline;
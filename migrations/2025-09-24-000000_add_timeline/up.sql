@@ -0,0 +1,2 @@
+-- Intake month, study length in months and term system stored as JSON
+ALTER TABLE profiles ADD COLUMN timeline TEXT;
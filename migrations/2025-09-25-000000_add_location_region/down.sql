@@ -0,0 +1,2 @@
+-- Drop the location_region column
+ALTER TABLE profiles DROP COLUMN location_region;
@@ -0,0 +1,2 @@
+-- Region key (regions.yaml) used to pick regional tax tables
+ALTER TABLE profiles ADD COLUMN location_region TEXT;
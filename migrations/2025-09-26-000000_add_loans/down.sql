@@ -0,0 +1,2 @@
+-- Drop the loans column
+ALTER TABLE profiles DROP COLUMN loans;
@@ -0,0 +1,2 @@
+-- Student loans (source, principal, rate, grace period, term) stored as JSON
+ALTER TABLE profiles ADD COLUMN loans TEXT;
@@ -0,0 +1,2 @@
+-- Drop the funding column
+ALTER TABLE profiles DROP COLUMN funding;
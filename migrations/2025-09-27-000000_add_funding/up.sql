@@ -0,0 +1,2 @@
+-- Scholarships, tuition waivers and TA/RA stipends with probabilities stored as JSON
+ALTER TABLE profiles ADD COLUMN funding TEXT;
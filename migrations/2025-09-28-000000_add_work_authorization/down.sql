@@ -0,0 +1,2 @@
+-- Drop the work_authorization column
+ALTER TABLE profiles DROP COLUMN work_authorization;
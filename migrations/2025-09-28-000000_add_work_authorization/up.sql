@@ -0,0 +1,2 @@
+-- H-1B lottery odds, OPT/STEM OPT months and fallback profile stored as JSON
+ALTER TABLE profiles ADD COLUMN work_authorization TEXT;
@@ -0,0 +1,2 @@
+-- Drop the employment column
+ALTER TABLE profiles DROP COLUMN employment;
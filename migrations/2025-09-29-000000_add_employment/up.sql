@@ -0,0 +1,2 @@
+-- Job search duration distribution and layoff risk stored as JSON
+ALTER TABLE profiles ADD COLUMN employment TEXT;
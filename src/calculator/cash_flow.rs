@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// IRR求解的收益率区间
+const IRR_BOUNDS: (f64, f64) = (-0.99, 10.0);
+/// IRR二分求解的最大迭代次数
+const IRR_MAX_ITERATIONS: usize = 200;
+
+/// 基于逐年现金流的投资指标
+///
+/// 第1年的现金流视为发生在期初（不折现），第 `n` 年折现 `n-1` 期
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CashFlowMetrics {
+    /// 折现率
+    pub discount_rate:           f64,
+    /// 净现值
+    pub npv:                     f64,
+    /// 内部收益率（现金流未变号时为None）
+    pub irr:                     Option<f64>,
+    /// 此后累计折现现金流持续不低于0的第一年
+    pub discounted_payback_year: Option<u32>,
+}
+
+impl CashFlowMetrics {
+    /// `cash_flows[i]` 为第 `i+1` 年的净现金流
+    pub fn from_cash_flows(cash_flows: &[f64], discount_rate: f64) -> Self {
+        let mut cumulative = 0.0;
+        let cumulative: Vec<f64> = discounted(cash_flows, discount_rate)
+            .map(|value| {
+                cumulative += value;
+                cumulative
+            })
+            .collect();
+        let discounted_payback_year = cumulative
+            .iter()
+            .enumerate()
+            .rev()
+            .take_while(|(_, value)| **value >= 0.0)
+            .last()
+            .map(|(i, _)| i as u32 + 1);
+
+        Self {
+            discount_rate,
+            npv: cumulative.last().copied().unwrap_or(0.0),
+            irr: irr(cash_flows),
+            discounted_payback_year,
+        }
+    }
+}
+
+/// 逐年折现后的现金流
+fn discounted(cash_flows: &[f64], rate: f64) -> impl Iterator<Item = f64> + '_ {
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(move |(i, value)| value / (1.0 + rate).powi(i as i32))
+}
+
+/// 按 `rate` 折现的净现值
+pub fn npv(cash_flows: &[f64], rate: f64) -> f64 { discounted(cash_flows, rate).sum() }
+
+/// 内部收益率：使净现值为0的折现率，在 [-99%, 1000%] 内二分求解
+pub fn irr(cash_flows: &[f64]) -> Option<f64> {
+    let has_outflow = cash_flows.iter().any(|value| *value < 0.0);
+    let has_inflow = cash_flows.iter().any(|value| *value > 0.0);
+    if !has_outflow || !has_inflow {
+        return None;
+    }
+
+    let (mut low, mut high) = IRR_BOUNDS;
+    let (mut npv_low, npv_high) = (npv(cash_flows, low), npv(cash_flows, high));
+    if npv_low.signum() == npv_high.signum() {
+        return None;
+    }
+    for _ in 0..IRR_MAX_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        let npv_mid = npv(cash_flows, mid);
+        if npv_mid.signum() == npv_low.signum() {
+            low = mid;
+            npv_low = npv_mid;
+        } else {
+            high = mid;
+        }
+        if high - low < 1e-9 {
+            break;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npv_and_irr() {
+        let flows = [-100.0, 60.0, 60.0];
+        assert!((npv(&flows, 0.0) - 20.0).abs() < 1e-9);
+        assert!((npv(&flows, 0.1) - (-100.0 + 60.0 / 1.1 + 60.0 / 1.21)).abs() < 1e-9);
+
+        // -100 + 60/(1+r) + 60/(1+r)^2 = 0 => r ≈ 13.07%
+        let rate = irr(&flows).unwrap();
+        assert!((rate - 0.130662).abs() < 1e-5);
+        assert!(npv(&flows, rate).abs() < 1e-6);
+
+        assert_eq!(irr(&[10.0, 20.0]), None);
+        assert_eq!(irr(&[]), None);
+    }
+
+    #[test]
+    fn test_discounted_payback() {
+        let metrics = CashFlowMetrics::from_cash_flows(&[-100.0, 30.0, 40.0, 50.0, 60.0], 0.05);
+        // 累计折现: -100, -71.4, -35.1, 8.0, 57.4
+        assert_eq!(metrics.discounted_payback_year, Some(4));
+        assert!(metrics.npv > 0.0);
+
+        let metrics = CashFlowMetrics::from_cash_flows(&[-100.0, 10.0, 10.0], 0.05);
+        assert_eq!(metrics.discounted_payback_year, None);
+        assert!(metrics.irr.unwrap() < 0.0);
+    }
+}
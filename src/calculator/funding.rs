@@ -0,0 +1,107 @@
+use super::ROICalculator;
+use crate::models::{AwardKind, PathCalculationParams};
+
+impl ROICalculator {
+    /// 第 `month` 个学习月的资助（美元）：抵扣学习成本的奖学金和学费减免、
+    /// 津贴及其税后金额，各项按 `funding_mode` 的权重计入
+    pub fn funding_for_month(
+        &self,
+        params: &PathCalculationParams,
+        month: u32,
+        year: u32,
+    ) -> (f64, f64, f64) {
+        let Some(cost_params) = &params.cost_params else {
+            return (0.0, 0.0, 0.0);
+        };
+        if month > params.study_months {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let term = &params.term_schedule;
+        let (offset, stipend) =
+            params
+                .funding
+                .iter()
+                .fold((0.0, 0.0), |(offset, stipend), award| {
+                    let weight = self.funding_mode.weight(award);
+                    match award.kind {
+                        AwardKind::Scholarship { amount } if term.starts.contains(&month) => {
+                            let per_term = amount.to_usd(&self.rates) / term.starts.len() as f64;
+                            (offset + per_term * weight, stipend)
+                        }
+                        AwardKind::Scholarship { .. } => (offset, stipend),
+                        AwardKind::TuitionWaiver { fraction } => {
+                            let tuition =
+                                cost_params.tuition_for_month(month, params.study_months, term);
+                            (offset + tuition * fraction * weight, stipend)
+                        }
+                        AwardKind::Stipend { monthly } => {
+                            (offset, stipend + monthly.to_usd(&self.rates) * weight)
+                        }
+                    }
+                });
+        if stipend <= 0.0 {
+            return (offset, 0.0, 0.0);
+        }
+        let tax_rate = self.effective_tax_rate(stipend * 12.0, params, year);
+        (offset, stipend, stipend * (1.0 - tax_rate))
+    }
+
+    /// 学习期内计入的资助合计（津贴按税后计）
+    pub fn funding_total(&self, params: &PathCalculationParams) -> f64 {
+        (1..=params.study_months)
+            .map(|month| {
+                let (offset, _, net_stipend) =
+                    self.funding_for_month(params, month, (month - 1) / 12 + 1);
+                offset + net_stipend
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculator::RateShocks,
+        models::{Currency, FundingMode, ProfileType, test_profile},
+    };
+
+    #[test]
+    fn test_funding() {
+        let mut profile = test_profile(ProfileType::Education, Currency::Usd, 120000.0, 36000.0)
+            .with_funding(vec![
+                "waiver:0.25".parse().unwrap(),
+                "stipend:2000:0.5".parse().unwrap(),
+            ]);
+        profile.financial_params.tax_rate = 0.2;
+        let confirmed = ROICalculator::default();
+        let expected = ROICalculator {
+            funding_mode: FundingMode::Expected,
+            ..ROICalculator::default()
+        };
+        let none = ROICalculator {
+            funding_mode: FundingMode::None,
+            ..ROICalculator::default()
+        };
+
+        // 已确认的25%学费减免：无明细时总成本视为学费
+        let params = profile.to_path_params();
+        assert!((confirmed.funding_total(&params) - 20000.0).abs() < 1e-6);
+        // 期望值另计50%概率的津贴：24个月 × 1000 × (1 - 20%)
+        assert!((expected.funding_total(&params) - (20000.0 + 19200.0)).abs() < 1e-6);
+
+        // 资助抵扣学习成本，工作后不再发放
+        let shocks = RateShocks::default();
+        let months = expected.calculate_monthly_path(&profile, &shocks);
+        let unfunded = none.calculate_monthly_path(&profile, &shocks);
+        assert!((months[23].net_worth - unfunded[23].net_worth - 39200.0).abs() < 1e-6);
+        assert_eq!(months[1].income_usd, 1000.0);
+        assert_eq!(months[1].disposable_income_usd, 0.0);
+        assert_eq!(months[24].income_usd, 10000.0);
+
+        // 增量成本按扣除资助后的净成本计算
+        let path = confirmed.profile_path(&profile);
+        assert_eq!(path.total_cost_usd, Some(60000.0));
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ROICalculator, RateShocks};
+use crate::models::{Currency, CurrencyRates, Profile};
+
+/// 一种汇率变动下的最终净资产
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxMoveOutcome {
+    /// 变动的汇率，如 USD/CNY
+    pub pair:            String,
+    /// 分析期内的总变动，0.2表示期末汇率高20%
+    pub change:          f64,
+    pub final_net_worth: f64,
+}
+
+/// 单个Profile的汇率敏感性
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxSensitivity {
+    pub profile:   String,
+    /// Profile收入和成本的计价货币
+    pub currency:  Currency,
+    /// 结果的报告货币
+    pub reporting: Currency,
+    /// 当前汇率走势下的最终净资产
+    pub base:      f64,
+    pub moves:     Vec<FxMoveOutcome>,
+}
+
+impl FxSensitivity {
+    /// 各变动相对当前走势的净资产变化
+    pub fn deltas(&self) -> impl Iterator<Item = (&FxMoveOutcome, f64)> {
+        self.moves
+            .iter()
+            .map(|outcome| (outcome, outcome.final_net_worth - self.base))
+    }
+}
+
+impl ROICalculator {
+    /// 各分析年的换算表：第1年为基准汇率，之后按汇率走势和抽样偏差逐年累计
+    pub fn fx_rates(&self, shocks: &RateShocks) -> Vec<CurrencyRates> {
+        let (usd_cny, cad_cny) = self.fx_path.annual_change();
+        let mut rates = self.rates;
+        (1..=self.total_years)
+            .map(|year| {
+                if year > 1 {
+                    rates = rates.moved(
+                        usd_cny + RateShocks::at(&shocks.usd_cny_change, year).unwrap_or(0.0),
+                        cad_cny + RateShocks::at(&shocks.cad_cny_change, year).unwrap_or(0.0),
+                    );
+                }
+                rates
+            })
+            .collect()
+    }
+
+    /// 以 `currency` 计价的收支在各分析年相对基准汇率的折算系数
+    ///
+    /// 内部金额仍以基准汇率下的美元表示，乘以系数后按基准汇率换算为报告货币，
+    /// 即得到按当年汇率换算的报告货币金额
+    pub(super) fn fx_factors(&self, currency: Currency, shocks: &RateShocks) -> Vec<f64> {
+        let base = self.rates.convert(1.0, currency, self.reporting_currency);
+        self.fx_rates(shocks)
+            .iter()
+            .map(|rates| rates.convert(1.0, currency, self.reporting_currency) / base)
+            .collect()
+    }
+
+    /// USD/CNY、CAD/CNY 在分析期内分别变动 ±`change` 时的最终净资产
+    pub fn evaluate_fx_moves(&self, profile: &Profile, change: f64) -> FxSensitivity {
+        let final_net_worth = |calculator: &ROICalculator| {
+            calculator
+                .calculate_profile_path(profile)
+                .last()
+                .map_or(0.0, |data| data.net_worth)
+        };
+        let moves = [
+            ("USD/CNY", -change, 0.0),
+            ("USD/CNY", change, 0.0),
+            ("CAD/CNY", 0.0, -change),
+            ("CAD/CNY", 0.0, change),
+        ]
+        .into_iter()
+        .map(|(pair, usd_cny, cad_cny)| {
+            let calculator = ROICalculator {
+                fx_path: self
+                    .fx_path
+                    .with_total_move(usd_cny, cad_cny, self.total_years),
+                ..self.clone()
+            };
+            FxMoveOutcome {
+                pair:            pair.to_string(),
+                change:          usd_cny + cad_cny,
+                final_net_worth: final_net_worth(&calculator),
+            }
+        })
+        .collect();
+
+        FxSensitivity {
+            profile: profile.name.clone(),
+            currency: profile.location.native_currency().unwrap_or_default(),
+            reporting: self.reporting_currency,
+            base: final_net_worth(self),
+            moves,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ProfileType, test_profile};
+
+    fn profile(currency: Currency, profile_type: ProfileType) -> Profile {
+        test_profile(profile_type, currency, 120000.0, 30000.0)
+    }
+
+    #[test]
+    fn test_fx_moves() {
+        let calculator = ROICalculator {
+            reporting_currency: Currency::Cny,
+            ..ROICalculator::default()
+        };
+
+        // 汇率不变时结果与按基准汇率换算一致
+        let study = profile(Currency::Usd, ProfileType::Education);
+        let base = calculator.evaluate_fx_moves(&study, 0.2);
+        let usd_path = ROICalculator::default().calculate_profile_path(&study);
+        assert!((base.base - usd_path[9].net_worth).abs() < 1e-6);
+
+        // 人民币贬值：美元学费稍贵，但之后的美元收入折合更多人民币
+        let (usd_down, usd_up) = (&base.moves[0], &base.moves[1]);
+        assert!(usd_up.final_net_worth > base.base);
+        assert!(usd_down.final_net_worth < base.base);
+        // 加元汇率不影响美元计价的Profile
+        assert!((base.moves[3].final_net_worth - base.base).abs() < 1e-6);
+
+        // 人民币计价、以人民币报告的Profile不受汇率影响
+        let home = calculator.evaluate_fx_moves(&profile(Currency::Cny, ProfileType::Work), 0.2);
+        assert!(home.deltas().all(|(_, delta)| delta.abs() < 1e-6));
+
+        // 抽样的汇率偏差逐年累计，第1年为基准汇率
+        let rates = calculator.fx_rates(&RateShocks {
+            usd_cny_change: vec![0.0, 0.1],
+            ..RateShocks::default()
+        });
+        assert!((rates[1].usd_to_cny - 7.92).abs() < 1e-9);
+        assert_eq!(rates[2], rates[1]);
+        let factors = calculator.fx_factors(Currency::Usd, &RateShocks::default());
+        assert!(factors.iter().all(|factor| (*factor - 1.0).abs() < 1e-12));
+    }
+}
@@ -0,0 +1,91 @@
+use super::{PathYearlyData, ROICalculator, RateShocks};
+
+impl PathYearlyData {
+    /// 按物价指数折算为分析开始时价格的实际值
+    pub fn real(&self) -> PathYearlyData {
+        let deflate = |amount: f64| amount / self.price_index;
+        PathYearlyData {
+            income_usd: deflate(self.income_usd),
+            net_income_usd: deflate(self.net_income_usd),
+            living_cost_usd: deflate(self.living_cost_usd),
+            disposable_income_usd: deflate(self.disposable_income_usd),
+            cash_savings: deflate(self.cash_savings),
+            investment_amount: deflate(self.investment_amount),
+            investment_return: deflate(self.investment_return),
+            total_investment: deflate(self.total_investment),
+            total_investment_principal: deflate(self.total_investment_principal),
+            total_cash: deflate(self.total_cash),
+            loan_proceeds: deflate(self.loan_proceeds),
+            loan_interest: deflate(self.loan_interest),
+            loan_payment: deflate(self.loan_payment),
+            debt: deflate(self.debt),
+            net_worth: deflate(self.net_worth),
+            ..self.clone()
+        }
+    }
+}
+
+impl ROICalculator {
+    /// 报告货币所在国家的年通胀率
+    pub fn inflation_rate(&self) -> f64 { self.inflation.for_currency(self.reporting_currency) }
+
+    /// 各分析年年末相对分析开始时的物价指数，含抽样的通胀偏差
+    pub fn price_indices(&self, shocks: &RateShocks) -> Vec<f64> {
+        let rate = self.inflation_rate();
+        let mut index = 1.0;
+        (1..=self.total_years)
+            .map(|year| {
+                index *=
+                    1.0 + rate + RateShocks::at(&shocks.living_cost_growth, year).unwrap_or(0.0);
+                index
+            })
+            .collect()
+    }
+
+    /// 金额口径说明，如 "人民币名义值"、"人民币实际值"
+    pub fn value_label(&self) -> String {
+        let kind = if self.real_terms {
+            "实际值"
+        } else {
+            "名义值"
+        };
+        format!("{}{}", self.reporting_currency.label(), kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, test_profile};
+
+    #[test]
+    fn test_real_terms() {
+        let profile = test_profile(ProfileType::Work, Currency::Cny, 60000.0, 20000.0);
+        let nominal = ROICalculator {
+            reporting_currency: Currency::Cny,
+            ..ROICalculator::default()
+        };
+        let real = ROICalculator {
+            real_terms: true,
+            ..nominal.clone()
+        };
+
+        // 第10年末物价指数按国内1.5%通胀累计
+        let nominal_path = nominal.calculate_profile_path(&profile);
+        let real_path = real.calculate_profile_path(&profile);
+        assert!((nominal_path[9].price_index - 1.015f64.powi(10)).abs() < 1e-9);
+        assert!(
+            (real_path[9].net_worth - nominal_path[9].net_worth / nominal_path[9].price_index)
+                .abs()
+                < 1e-6
+        );
+        assert!(real_path[0].income_usd < nominal_path[0].income_usd);
+
+        // 通胀偏差计入物价指数
+        let indices = real.price_indices(&RateShocks {
+            living_cost_growth: vec![0.01],
+            ..RateShocks::default()
+        });
+        assert!((indices[0] - 1.025).abs() < 1e-12);
+    }
+}
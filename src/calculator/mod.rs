@@ -1,2 +1,20 @@
+pub mod cash_flow;
+pub mod funding;
+pub mod fx;
+pub mod inflation;
+pub mod monte_carlo;
+pub mod monthly;
 pub mod roi_calculator;
+pub mod scenario;
+pub mod sensitivity;
+pub mod study_cost;
+pub mod tax;
+pub mod work_auth;
+pub use cash_flow::*;
+pub use fx::*;
+pub use monte_carlo::*;
+pub use monthly::*;
 pub use roi_calculator::*;
+pub use scenario::*;
+pub use sensitivity::*;
+pub use study_cost::*;
@@ -0,0 +1,421 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+use super::{ROICalculator, RateShocks};
+use crate::models::{EmploymentRisk, Profile};
+
+/// 年化比率的随机分布，以确定性参数为中心抽样
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RateDistribution {
+    /// 不随机，始终取中心值
+    Fixed,
+    /// 正态分布
+    Normal { std_dev: f64 },
+    /// [中心值 - spread, 中心值 + spread] 上的均匀分布
+    Uniform { spread: f64 },
+}
+
+impl RateDistribution {
+    /// 以 `center` 为中心抽样
+    pub fn sample<R: Rng + ?Sized>(&self, center: f64, rng: &mut R) -> f64 {
+        match *self {
+            RateDistribution::Fixed => center,
+            RateDistribution::Normal { std_dev } => {
+                center + std_dev * rng.sample::<f64, _>(StandardNormal)
+            }
+            RateDistribution::Uniform { spread } => {
+                rng.gen_range(center - spread..=center + spread)
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let width = match *self {
+            RateDistribution::Fixed => return Ok(()),
+            RateDistribution::Normal { std_dev } => std_dev,
+            RateDistribution::Uniform { spread } => spread,
+        };
+        if !width.is_finite() || width < 0.0 {
+            return Err(anyhow::anyhow!("无效的分布参数: {}", self));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RateDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateDistribution::Fixed => write!(f, "fixed"),
+            RateDistribution::Normal { std_dev } => write!(f, "normal:{}", std_dev),
+            RateDistribution::Uniform { spread } => write!(f, "uniform:{}", spread),
+        }
+    }
+}
+
+impl FromStr for RateDistribution {
+    type Err = anyhow::Error;
+
+    /// 格式: `fixed`、`normal:<标准差>`、`uniform:<半宽>`
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, param) = match s.trim().split_once(':') {
+            Some((kind, param)) => (kind, Some(param)),
+            None => (s.trim(), None),
+        };
+        let param = || -> Result<f64> {
+            param
+                .ok_or_else(|| anyhow::anyhow!("分布 '{}' 缺少参数", kind))?
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| anyhow::anyhow!("无效的分布参数: '{}'", s))
+        };
+
+        let distribution = match kind.to_lowercase().as_str() {
+            "fixed" => RateDistribution::Fixed,
+            "normal" => RateDistribution::Normal { std_dev: param()? },
+            "uniform" => RateDistribution::Uniform { spread: param()? },
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "未知的分布: '{}'，可选 fixed | normal:<标准差> | uniform:<半宽>",
+                    kind
+                ));
+            }
+        };
+        distribution.validate()?;
+        Ok(distribution)
+    }
+}
+
+/// 蒙特卡洛模拟参数
+///
+/// 投资回报率以 `investment_return_rate` 为中心抽样，
+/// 薪资增长和通胀以Profile的设定值为中心抽样，汇率年变动以汇率走势为中心抽样
+#[derive(Debug, Clone, Serialize, Deserialize, SmartDefault)]
+pub struct MonteCarloConfig {
+    #[default = 5000] // 模拟次数
+    pub runs: usize,
+    #[default = 42] // 随机种子，相同种子结果可复现
+    pub seed: u64,
+    #[default(RateDistribution::Normal { std_dev: 0.15 })] // 年投资回报率
+    pub market_return: RateDistribution,
+    #[default(RateDistribution::Normal { std_dev: 0.02 })] // 年薪资增长率
+    pub salary_growth: RateDistribution,
+    #[default(RateDistribution::Normal { std_dev: 0.01 })] // 年通胀
+    pub inflation: RateDistribution,
+    #[default(RateDistribution::Fixed)] // USD/CNY、CAD/CNY年变动，默认不抽样
+    pub fx: RateDistribution,
+}
+
+impl MonteCarloConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.runs == 0 {
+            return Err(anyhow::anyhow!("模拟次数必须大于0"));
+        }
+        self.market_return.validate()?;
+        self.salary_growth.validate()?;
+        self.inflation.validate()?;
+        self.fx.validate()
+    }
+}
+
+/// 某一年净资产的分位数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PercentileBand {
+    pub year: u32,
+    pub p10:  f64,
+    pub p50:  f64,
+    pub p90:  f64,
+}
+
+/// 单个Profile的模拟结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileSimulation {
+    pub name:            String,
+    pub bands:           Vec<PercentileBand>,
+    /// 每次模拟的最终净资产（按模拟顺序）
+    #[serde(skip)]
+    pub final_net_worth: Vec<f64>,
+}
+
+impl ProfileSimulation {
+    /// 分析期末的净资产分位数
+    pub fn final_band(&self) -> Option<&PercentileBand> { self.bands.last() }
+}
+
+/// 蒙特卡洛模拟结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloResult {
+    pub runs:     usize,
+    pub seed:     u64,
+    pub profiles: Vec<ProfileSimulation>,
+}
+
+impl MonteCarloResult {
+    /// 第 `a` 个Profile最终净资产高于第 `b` 个的概率
+    pub fn probability_ahead(&self, a: usize, b: usize) -> f64 {
+        let (Some(a), Some(b)) = (self.profiles.get(a), self.profiles.get(b)) else {
+            return 0.0;
+        };
+        let ahead = a
+            .final_net_worth
+            .iter()
+            .zip(&b.final_net_worth)
+            .filter(|(a, b)| a > b)
+            .count();
+        ahead as f64 / self.runs as f64
+    }
+}
+
+/// 对市场回报、薪资增长和通胀随机抽样的模拟器
+#[derive(Debug, Clone)]
+pub struct MonteCarloSimulator {
+    calculator: ROICalculator,
+    config:     MonteCarloConfig,
+}
+
+impl MonteCarloSimulator {
+    pub fn new(calculator: ROICalculator, config: MonteCarloConfig) -> Result<Self> {
+        config.validate()?;
+        Ok(Self { calculator, config })
+    }
+
+    pub fn config(&self) -> &MonteCarloConfig { &self.config }
+
+    pub fn calculator(&self) -> &ROICalculator { &self.calculator }
+
+    /// 对每个Profile模拟 `runs` 次
+    ///
+    /// 同一次模拟中所有Profile共享市场回报、通胀和汇率，
+    /// 薪资增长和求职、裁员造成的失业各自独立抽样
+    pub fn run(&self, profiles: &[Profile]) -> MonteCarloResult {
+        let config = &self.config;
+        let years = self.calculator.total_years as usize;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+
+        // samples[profile][year][run]
+        let mut samples = vec![vec![Vec::with_capacity(config.runs); years]; profiles.len()];
+        for _ in 0..config.runs {
+            let mut shocks = RateShocks {
+                investment_return:  (0..years)
+                    .map(|_| {
+                        config
+                            .market_return
+                            .sample(self.calculator.investment_return_rate, &mut rng)
+                    })
+                    .collect(),
+                salary_growth:      Vec::new(),
+                living_cost_growth: (0..years)
+                    .map(|_| config.inflation.sample(0.0, &mut rng))
+                    .collect(),
+                usd_cny_change:     (0..years)
+                    .map(|_| config.fx.sample(0.0, &mut rng))
+                    .collect(),
+                cad_cny_change:     (0..years)
+                    .map(|_| config.fx.sample(0.0, &mut rng))
+                    .collect(),
+                employment:         Vec::new(),
+            };
+
+            for (profile, samples) in profiles.iter().zip(samples.iter_mut()) {
+                shocks.salary_growth = (0..years)
+                    .map(|_| config.salary_growth.sample(0.0, &mut rng))
+                    .collect();
+                shocks.employment = sample_employment(&profile.employment, years * 12, &mut rng);
+                let path = self.calculator.calculate_path_with_shocks(profile, &shocks);
+                for (year, data) in samples.iter_mut().zip(&path) {
+                    year.push(data.net_worth);
+                }
+            }
+        }
+
+        let profiles = profiles
+            .iter()
+            .zip(samples)
+            .map(|(profile, samples)| {
+                let final_net_worth = samples.last().cloned().unwrap_or_default();
+                let bands = samples
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, mut values)| {
+                        values.sort_by(f64::total_cmp);
+                        PercentileBand {
+                            year: i as u32 + 1,
+                            p10:  percentile(&values, 0.10),
+                            p50:  percentile(&values, 0.50),
+                            p90:  percentile(&values, 0.90),
+                        }
+                    })
+                    .collect();
+                ProfileSimulation {
+                    name: profile.name.clone(),
+                    bands,
+                    final_net_worth,
+                }
+            })
+            .collect();
+
+        MonteCarloResult {
+            runs: config.runs,
+            seed: config.seed,
+            profiles,
+        }
+    }
+}
+
+/// 按求职月数分布和裁员率抽样每个工作月是否在职，没有就业风险时不抽样
+fn sample_employment<R: Rng + ?Sized>(
+    risk: &EmploymentRisk,
+    months: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    if risk.is_default() {
+        return Vec::new();
+    }
+    let draw = rng.gen_range(0.0..1.0);
+    let mut cumulative = 0.0;
+    let mut unemployed = risk
+        .job_search
+        .iter()
+        .find(|outcome| {
+            cumulative += outcome.probability;
+            draw < cumulative
+        })
+        .or(risk.job_search.last())
+        .map_or(0, |outcome| outcome.months);
+    (0..months)
+        .map(|_| {
+            if unemployed == 0
+                && let Some(layoff) = &risk.layoff
+                && rng.gen_bool(layoff.monthly_rate())
+            {
+                unemployed = layoff.reemployment_months;
+            }
+            if unemployed > 0 {
+                unemployed -= 1;
+                0.0
+            } else {
+                1.0
+            }
+        })
+        .collect()
+}
+
+/// 已排序样本的分位数（线性插值）
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, test_profile};
+
+    fn work_profile(name: &str, salary: f64) -> Profile {
+        Profile {
+            name: name.to_string(),
+            ..test_profile(ProfileType::Work, Currency::Cny, salary, 15000.0)
+        }
+    }
+
+    #[test]
+    fn test_parse_distribution() {
+        assert_eq!(
+            "normal:0.15".parse::<RateDistribution>().unwrap(),
+            RateDistribution::Normal { std_dev: 0.15 }
+        );
+        assert_eq!(
+            "Fixed".parse::<RateDistribution>().unwrap(),
+            RateDistribution::Fixed
+        );
+        assert!("normal".parse::<RateDistribution>().is_err());
+        assert!("uniform:-1".parse::<RateDistribution>().is_err());
+        assert!("lognormal:0.1".parse::<RateDistribution>().is_err());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&values, 0.5), 3.0);
+        assert!((percentile(&values, 0.1) - 1.4).abs() < 1e-9);
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+
+    #[test]
+    fn test_sample_employment() {
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!(sample_employment(&EmploymentRisk::default(), 12, &mut rng).is_empty());
+
+        let search = EmploymentRisk {
+            job_search: vec!["3".parse().unwrap()],
+            layoff:     None,
+        };
+        assert_eq!(
+            sample_employment(&search, 5, &mut rng),
+            vec![0.0, 0.0, 0.0, 1.0, 1.0]
+        );
+
+        // 抽样的平均在职比例接近在职概率
+        let layoff = EmploymentRisk {
+            job_search: Vec::new(),
+            layoff:     Some("0.2:6".parse().unwrap()),
+        };
+        let runs = 2000;
+        let employed: f64 = (0..runs)
+            .map(|_| sample_employment(&layoff, 60, &mut rng)[59])
+            .sum::<f64>()
+            / runs as f64;
+        assert!((employed - layoff.employment_probabilities(60)[59]).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_simulation() {
+        let calculator = ROICalculator::default();
+        let profiles = [work_profile("low", 40000.0), work_profile("high", 60000.0)];
+
+        // 固定分布时所有分位数都等于确定性结果
+        let fixed = MonteCarloConfig {
+            runs: 10,
+            market_return: RateDistribution::Fixed,
+            salary_growth: RateDistribution::Fixed,
+            inflation: RateDistribution::Fixed,
+            ..MonteCarloConfig::default()
+        };
+        let result = MonteCarloSimulator::new(calculator.clone(), fixed)
+            .unwrap()
+            .run(&profiles);
+        let expected = calculator
+            .calculate_profile_path(&profiles[0])
+            .last()
+            .unwrap()
+            .net_worth;
+        let band = result.profiles[0].final_band().unwrap();
+        assert!((band.p10 - expected).abs() < 1e-6 && (band.p90 - expected).abs() < 1e-6);
+
+        // 相同种子结果可复现
+        let config = MonteCarloConfig {
+            runs: 200,
+            ..MonteCarloConfig::default()
+        };
+        let simulator = MonteCarloSimulator::new(calculator, config).unwrap();
+        let (first, second) = (simulator.run(&profiles), simulator.run(&profiles));
+        assert_eq!(
+            first.profiles[1].final_net_worth,
+            second.profiles[1].final_net_worth
+        );
+
+        let band = first.profiles[0].final_band().unwrap();
+        assert!(band.p10 < band.p50 && band.p50 < band.p90);
+        assert!(first.probability_ahead(1, 0) > 0.9);
+        assert!(first.probability_ahead(0, 1) < 0.1);
+    }
+}
@@ -0,0 +1,428 @@
+use serde::{Deserialize, Serialize};
+
+use super::{PathYearlyData, ROICalculator, RateShocks, tax::Earnings};
+use crate::models::{LoanMonth, PathCalculationParams, Profile};
+
+/// 单月的财务数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathMonthlyData {
+    /// 从路径开始计的月份（从1开始）
+    pub month: u32,
+    /// 所在的分析年（每12个月为一年）
+    pub year: u32,
+    /// 工作的第几个月，未工作时为None
+    pub work_month: Option<u32>,
+    pub income_usd: f64,
+    pub net_income_usd: f64,
+    /// 生活成本，学习期间含学费等学习成本
+    pub living_cost_usd: f64,
+    pub disposable_income_usd: f64,
+    pub cash_savings: f64,
+    pub investment_amount: f64,
+    pub investment_return: f64,
+    pub total_investment: f64,
+    pub total_investment_principal: f64,
+    pub total_cash: f64,
+    /// 贷款放款、当月利息、还款和月末未偿余额
+    pub loan_proceeds: f64,
+    pub loan_interest: f64,
+    pub loan_payment: f64,
+    pub debt: f64,
+    pub net_worth: f64,
+}
+
+/// 路径中途转入另一个Profile，如H-1B未中签后回国或转往加拿大
+#[derive(Debug, Clone, Copy)]
+pub struct PathSwitch<'a> {
+    /// 第 `after_month` 个月之后按 `profile` 的收入、生活成本和税务计算
+    pub after_month: u32,
+    pub profile:     &'a Profile,
+}
+
+impl ROICalculator {
+    /// 按月模拟路径：支持年中入学、按学期支付学费、非整年的工作期和每月定投
+    ///
+    /// 生活成本每满12个月按年增长率上调一次，薪资在每个工作周年上调，
+    /// 投资按年回报率折算的月回报率复利，当月投入从下个月开始计息。
+    /// 当地货币的收支按当年汇率折算为报告货币，投资以报告货币持有。
+    /// 求职和失业期间没有收入、生活成本照常支出，未抽样时收入按在职概率计入。
+    /// 地区税表按每个自然年累计的实际收入计算累进税。
+    /// 贷款放款计入现金，还款从可支配收入中支付（不足时动用现金），
+    /// 未偿余额计入负债
+    pub fn calculate_monthly_path(
+        &self,
+        profile: &Profile,
+        shocks: &RateShocks,
+    ) -> Vec<PathMonthlyData> {
+        self.calculate_switched_path(profile, None, shocks)
+    }
+
+    /// 按月模拟路径，`switch` 之后改按转入Profile的初始薪资重新开始工作，
+    /// 生活成本按转入Profile的水平和此前累计的增长计算，贷款照常还款
+    pub fn calculate_switched_path(
+        &self,
+        profile: &Profile,
+        switch: Option<PathSwitch>,
+        shocks: &RateShocks,
+    ) -> Vec<PathMonthlyData> {
+        let params = self.apply_cost_level(profile.to_path_params());
+        let currency = profile.location.native_currency().unwrap_or_default();
+        let fx_factors = self.fx_factors(currency, shocks);
+        // 转入Profile只取工作和生活参数，不再计入其学习成本和资助
+        let switch = switch.map(|switch| {
+            let currency = switch
+                .profile
+                .location
+                .native_currency()
+                .unwrap_or_default();
+            let params = PathCalculationParams {
+                cost_params: None,
+                funding: Vec::new(),
+                ..self.apply_cost_level(switch.profile.to_path_params())
+            };
+            let employment = params
+                .employment
+                .employment_probabilities(self.total_years * 12);
+            (
+                switch.after_month,
+                params,
+                self.fx_factors(currency, shocks),
+                employment,
+            )
+        });
+        let employment = params
+            .employment
+            .employment_probabilities(self.total_years * 12);
+        // 各笔贷款的还款计划：学习期结束并过宽限期后开始还款，按贷款货币的汇率折算
+        let loans: Vec<_> = params
+            .loans
+            .iter()
+            .map(|loan| {
+                let repay_start = params.work_delay_months + loan.grace_months + 1;
+                (
+                    loan.schedule(&self.rates, repay_start, self.total_years * 12),
+                    self.fx_factors(loan.principal.currency, shocks),
+                )
+            })
+            .collect();
+        let mut results = Vec::new();
+        let mut total_cash = 0.0;
+        let mut total_investment = 0.0;
+        let mut total_investment_principal = 0.0;
+        let mut total_cost_paid = 0.0;
+        // 相对初始薪资和初始生活成本的累计增长
+        let mut salary_factor = 1.0;
+        let mut living_factor = 1.0;
+        // 当前纳税年度已取得的税前收入和工作月数，按累计收入计算累进税
+        let mut earned = Earnings::default();
+
+        for month in 1..=self.total_years * 12 {
+            let year = (month - 1) / 12 + 1;
+            // 转入后按转入Profile计算，工作月份从转入时重新计起
+            let (params, fx_factors, employment, work_delay_months) = match &switch {
+                Some((after_month, switched, factors, employment)) if month > *after_month => {
+                    (switched, factors, employment, *after_month)
+                }
+                _ => (&params, &fx_factors, &employment, params.work_delay_months),
+            };
+            let work_month = month
+                .checked_sub(work_delay_months)
+                .filter(|work_month| *work_month > 0)
+                .filter(|work_month| {
+                    params
+                        .work_duration_limit
+                        .is_none_or(|limit| *work_month <= limit * 12)
+                });
+            if work_month == Some(1) {
+                salary_factor = 1.0;
+            }
+            // 纳税年度为自然年，转入其他Profile重新开始工作时也重新累计
+            if profile.timeline.calendar_month(month) == 1 || work_month == Some(1) {
+                earned = Earnings::default();
+            }
+
+            if month > 1 && (month - 1) % 12 == 0 {
+                living_factor *= 1.0
+                    + params.living_cost_growth
+                    + RateShocks::at(&shocks.living_cost_growth, year).unwrap_or(0.0);
+            }
+            if work_month.is_some_and(|work_month| work_month > 1 && (work_month - 1) % 12 == 0) {
+                salary_factor *= 1.0
+                    + params.salary_growth_rate
+                    + RateShocks::at(&shocks.salary_growth, year).unwrap_or(0.0);
+            }
+
+            let fx = fx_factors[year as usize - 1];
+            let base_living_cost = params.living_cost_usd * living_factor / 12.0;
+            let household = &params.household;
+            let (offset, stipend, net_stipend) = self.funding_for_month(params, month, year);
+            let (income_usd, net_income_usd, living_cost_usd) =
+                match (work_month, &params.cost_params) {
+                    (Some(work_month), _) => {
+                        let annual_salary = params.initial_salary_usd * salary_factor;
+                        let employed = RateShocks::at(&shocks.employment, work_month)
+                            .unwrap_or(employment[work_month as usize - 1]);
+                        let income_usd = annual_salary / 12.0 * employed;
+                        let income = Earnings::new(income_usd, employed);
+                        let tax = self.tax_on_earnings(earned, income, params, year);
+                        earned = earned + income;
+                        let living_cost_usd = base_living_cost
+                            * household.living_cost_multiplier(year, &self.special_costs)
+                            + household.dependent_costs_for_year(year, false, &self.special_costs)
+                                / 12.0;
+                        (income_usd, income_usd - tax, living_cost_usd)
+                    }
+                    (None, Some(cost_params)) => {
+                        // 学习期间：学习成本和随行家属的额外支出扣除资助后计入总成本，
+                        // 各费用项在实际发生的月份计入，税后津贴同样用于抵扣；
+                        // 本人的基本生活费不计入净资产，生活成本也只记学习成本
+                        let dependent_cost = base_living_cost
+                            * (household.living_cost_multiplier(year, &self.special_costs) - 1.0)
+                            + household.dependent_costs_for_year(year, true, &self.special_costs)
+                                / 12.0;
+                        let study_cost = cost_params.cost_for_month(
+                            month,
+                            params.study_months,
+                            &params.term_schedule,
+                        ) + dependent_cost
+                            - offset;
+                        total_cost_paid += (study_cost - net_stipend) * fx;
+                        (stipend, net_stipend, study_cost)
+                    }
+                    // 无成本的非工作期间（如退休）
+                    _ => (0.0, 0.0, 0.0),
+                };
+            let (income_usd, net_income_usd, living_cost_usd) =
+                (income_usd * fx, net_income_usd * fx, living_cost_usd * fx);
+            let loan = loans
+                .iter()
+                .map(|(schedule, factors)| {
+                    schedule[month as usize - 1].scaled(factors[year as usize - 1])
+                })
+                .fold(LoanMonth::default(), |sum, loan| sum + loan);
+            // 学习期的津贴已用于抵扣学习成本
+            let studying = work_month.is_none() && params.cost_params.is_some();
+            let surplus = if studying {
+                0.0
+            } else {
+                net_income_usd - living_cost_usd
+            };
+            let disposable_income_usd = (surplus - loan.payment).max(0.0);
+
+            // 每月定投，第一个月另投入机会成本
+            let mut investment_amount = disposable_income_usd * self.investment_portion;
+            let cash_savings = disposable_income_usd - investment_amount;
+            if month == 1 {
+                investment_amount += params.first_year_opportunity_cost.unwrap_or(0.0) * fx;
+            }
+
+            let annual_return = RateShocks::at(&shocks.investment_return, year)
+                .unwrap_or(self.investment_return_rate);
+            let investment_return = total_investment * monthly_rate(annual_return);
+
+            total_investment += investment_return + investment_amount;
+            total_investment_principal += investment_amount;
+            // 收入不足以支付生活成本（如求职和失业期间）和贷款还款的部分动用现金
+            total_cash += cash_savings + loan.proceeds - (loan.payment - surplus).max(0.0);
+
+            results.push(PathMonthlyData {
+                month,
+                year,
+                work_month,
+                income_usd,
+                net_income_usd,
+                living_cost_usd,
+                disposable_income_usd,
+                cash_savings,
+                investment_amount,
+                investment_return,
+                total_investment,
+                total_investment_principal,
+                total_cash,
+                loan_proceeds: loan.proceeds,
+                loan_interest: loan.interest,
+                loan_payment: loan.payment,
+                debt: loan.balance,
+                net_worth: total_cash + total_investment - total_cost_paid - loan.balance,
+            });
+        }
+
+        results
+    }
+}
+
+/// 年回报率对应的月复利回报率
+fn monthly_rate(annual_rate: f64) -> f64 { (1.0 + annual_rate).max(0.0).powf(1.0 / 12.0) - 1.0 }
+
+/// 按分析年汇总月度数据，流量累加，存量取年末值，物价指数由调用方填写
+pub fn roll_up(months: &[PathMonthlyData]) -> Vec<PathYearlyData> {
+    months
+        .chunk_by(|a, b| a.year == b.year)
+        .map(|chunk| {
+            let last = &chunk[chunk.len() - 1];
+            let sum = |field: fn(&PathMonthlyData) -> f64| chunk.iter().map(field).sum::<f64>();
+            PathYearlyData {
+                year: last.year,
+                work_year: chunk
+                    .iter()
+                    .rev()
+                    .find_map(|data| data.work_month)
+                    .map(|work_month| (work_month - 1) / 12 + 1),
+                income_usd: sum(|d| d.income_usd),
+                net_income_usd: sum(|d| d.net_income_usd),
+                living_cost_usd: sum(|d| d.living_cost_usd),
+                disposable_income_usd: sum(|d| d.disposable_income_usd),
+                cash_savings: sum(|d| d.cash_savings),
+                investment_amount: sum(|d| d.investment_amount),
+                investment_return: sum(|d| d.investment_return),
+                total_investment: last.total_investment,
+                total_investment_principal: last.total_investment_principal,
+                total_cash: last.total_cash,
+                loan_proceeds: sum(|d| d.loan_proceeds),
+                loan_interest: sum(|d| d.loan_interest),
+                loan_payment: sum(|d| d.loan_payment),
+                debt: last.debt,
+                net_worth: last.net_worth,
+                price_index: 1.0,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CostBreakdown, CostCategory, CostFrequency, CostItem, CostParams, Currency, EmploymentRisk,
+        ProfileType, Timeline, test_profile,
+    };
+
+    fn education_profile(timeline: Timeline) -> Profile {
+        test_profile(ProfileType::Education, Currency::Usd, 120000.0, 36000.0)
+            .with_timeline(timeline)
+    }
+
+    #[test]
+    fn test_monthly_path() {
+        let calculator = ROICalculator::default();
+        let profile = education_profile(Timeline {
+            start_month: 1,
+            study_months: Some(16),
+            ..Timeline::default()
+        });
+        let months = calculator.calculate_monthly_path(&profile, &RateShocks::default());
+        assert_eq!(months.len(), 120);
+
+        // 16个月的项目在第17个月开始工作，总成本分三个学期支付
+        assert!(months[15].work_month.is_none());
+        assert_eq!(months[16].work_month, Some(1));
+        assert_eq!(months[16].income_usd, 10000.0);
+        assert!((months[15].net_worth - (-80000.0)).abs() < 1e-6);
+        assert!(months[0].living_cost_usd > months[1].living_cost_usd);
+
+        // 第二年有8个月工资，年度汇总与月度数据一致
+        let years = roll_up(&months);
+        assert_eq!(years.len(), 10);
+        assert_eq!(years[1].work_year, Some(1));
+        assert!((years[1].income_usd - 80000.0).abs() < 1e-6);
+        assert_eq!(years[9].net_worth, months[119].net_worth);
+        // 学习期的现金流与净资产变化口径一致
+        assert!((years[0].cash_flow() - years[0].net_worth).abs() < 1e-6);
+
+        // 工作满12个月后加薪
+        assert!((months[28].income_usd - 10000.0 * 1.04).abs() < 1e-6);
+
+        // 分项成本按16个月计入，报告的初始成本与净资产扣除的成本一致
+        let breakdown = CostBreakdown::new()
+            .with_item(CostItem::new(
+                CostCategory::Tuition,
+                "tuition_per_year",
+                30000.0,
+                CostFrequency::PerYear,
+            ))
+            .with_item(CostItem::new(
+                CostCategory::Communication,
+                "phone_plan_per_month",
+                50.0,
+                CostFrequency::PerMonth,
+            ));
+        let term = profile.timeline.term_schedule(16);
+        let itemized = profile
+            .clone()
+            .with_cost_params(CostParams::itemized(breakdown, 2, 16, &term));
+        let months = calculator.calculate_monthly_path(&itemized, &RateShocks::default());
+        let total_cost = calculator.profile_path(&itemized).total_cost_usd.unwrap();
+        assert!((total_cost + months[15].net_worth).abs() < 1e-6);
+        assert!((total_cost - (15000.0 * 3.0 + 50.0 * 16.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loan_financing() {
+        let calculator = ROICalculator::default();
+        let profile = education_profile(Timeline::default());
+        let loan = "private:60000:0.1:6:10".parse().unwrap();
+        let without = calculator.calculate_monthly_path(&profile, &RateShocks::default());
+        let with = calculator
+            .calculate_monthly_path(&profile.with_loans(vec![loan]), &RateShocks::default());
+
+        // 放款不改变净资产，学习期的利息计入负债
+        assert!((with[0].net_worth - (without[0].net_worth - with[0].loan_interest)).abs() < 1e-6);
+        assert!(with[23].debt > 60000.0);
+
+        // 毕业6个月后开始还款，利息使期末净资产低于不贷款的路径
+        assert_eq!(with[29].loan_payment, 0.0);
+        assert!(with[30].loan_payment > 0.0);
+        assert!(with[119].net_worth < without[119].net_worth);
+    }
+
+    #[test]
+    fn test_job_search_gap() {
+        let calculator = ROICalculator::default();
+        let profile = education_profile(Timeline::default());
+        let immediate = calculator.calculate_monthly_path(&profile, &RateShocks::default());
+        let searching = calculator.calculate_monthly_path(
+            &profile.clone().with_employment(EmploymentRisk {
+                job_search: vec!["3".parse().unwrap()],
+                layoff:     None,
+            }),
+            &RateShocks::default(),
+        );
+
+        // 求职3个月没有收入，生活成本照常从现金支付
+        assert_eq!(searching[26].work_month, Some(3));
+        assert_eq!(searching[26].income_usd, 0.0);
+        assert_eq!(searching[26].living_cost_usd, immediate[26].living_cost_usd);
+        assert!(
+            (searching[26].net_worth - (searching[25].net_worth - searching[26].living_cost_usd))
+                .abs()
+                < 1e-6
+        );
+        assert!(searching[27].income_usd > 0.0);
+        assert!(searching[119].net_worth < immediate[119].net_worth);
+
+        // 抽样的在职状态优先于在职概率
+        let laid_off = calculator.calculate_monthly_path(
+            &profile,
+            &RateShocks {
+                employment: vec![1.0, 0.0],
+                ..RateShocks::default()
+            },
+        );
+        assert_eq!(laid_off[25].income_usd, 0.0);
+        assert_eq!(laid_off[26].income_usd, immediate[26].income_usd);
+    }
+
+    #[test]
+    fn test_monthly_contributions() {
+        assert!((monthly_rate(0.10) - 0.007974).abs() < 1e-6);
+
+        // 早4个月开始工作的路径期末净资产更高
+        let calculator = ROICalculator::default();
+        let full = calculator.calculate_profile_path(&education_profile(Timeline::default()));
+        let short = calculator.calculate_profile_path(&education_profile(Timeline {
+            study_months: Some(20),
+            ..Timeline::default()
+        }));
+        assert!(short[9].net_worth > full[9].net_worth);
+    }
+}
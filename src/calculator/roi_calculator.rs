@@ -1,22 +1,51 @@
 use serde::{Deserialize, Serialize};
 use smart_default::SmartDefault;
 use plotters::prelude::*;
-use tabled::{Table, Tabled, settings::{Alignment, Modify, Style, object::Columns}};
+use tabled::{Table, Tabled, builder::Builder, settings::Style};
 use anyhow::Result;
 
-use crate::models::{Profile, PathCalculationParams};
+use super::{CashFlowMetrics, PathMonthlyData, roll_up};
+use crate::models::{CanadaTaxRules, CategoryShares, ChinaTaxRules, CostLevel, CostLevels, CostParams, Currency, CurrencyRates, FundingMode, FxPath, InflationRates, Money, PathCalculationParams, Profile, SpecialCosts, UsTaxRules};
 
 #[derive(Debug, Clone, Serialize, Deserialize, SmartDefault)]
 pub struct ROICalculator {
     // 基础参数
-    #[default = 7.2] // 汇率 USD:CNY
-    pub exchange_rate:          f64,
+    // 货币换算表（默认与 regions.yaml 的 exchange_rates 一致）
+    pub rates:                  CurrencyRates,
+    #[default(Currency::Usd)] // 报告货币（内部计算统一使用美元）
+    pub reporting_currency:     Currency,
+    #[default(FxPath::Constant)] // 汇率走势（Profile当地货币与报告货币不同时生效）
+    pub fx_path:                FxPath,
+    // 各国通胀假设（默认与 regions.yaml 一致）
+    pub inflation:              InflationRates,
+    #[default = false] // 以实际值（分析开始时的价格）而非名义值报告
+    pub real_terms:             bool,
+    #[default(FundingMode::Confirmed)] // 资助的计入方式（已确认 / 按概率期望值 / 不计入）
+    pub funding_mode:           FundingMode,
     #[default = 0.10] // S&P 500 年化回报率
     pub investment_return_rate: f64,
     #[default = 0.20] // 可支配收入中用于投资的比例（20%）
     pub investment_portion:     f64,
+    #[default = 0.05] // 折现率（NPV、折现回收期）
+    pub discount_rate:          f64,
     #[default = 10] // 总分析年限
     pub total_years:            u32,
+    #[default(None)] // 成本档位（None时使用Profile自带的档位）
+    pub cost_level:             Option<CostLevel>,
+    // 各档位调整系数（默认与 costs.yaml 一致）
+    pub cost_levels:            CostLevels,
+    // 生活成本分项占比（默认与 regions.yaml 一致）
+    pub category_shares:        CategoryShares,
+    // 随行家属费用表（默认与 costs.yaml 一致）
+    pub special_costs:          SpecialCosts,
+    // 美国联邦与州税表（默认与 taxes.yaml 一致）
+    pub us_tax:                 UsTaxRules,
+    // 加拿大联邦与省税表（默认与 taxes.yaml 一致）
+    pub canada_tax:             CanadaTaxRules,
+    // 中国个人所得税与社保公积金（默认与 taxes.yaml 一致）
+    pub china_tax:              ChinaTaxRules,
+    #[default = 1.0] // 地区税表税负的缩放系数（情景和敏感性分析中的税率调整）
+    pub regional_tax_scale:     f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,37 +62,252 @@ pub struct PathYearlyData {
     pub total_investment: f64,
     pub total_investment_principal: f64, // 累计投资本金
     pub total_cash: f64,
+    pub loan_proceeds: f64,
+    pub loan_interest: f64,
+    pub loan_payment: f64,
+    pub debt: f64, // 年末未偿贷款余额
     pub net_worth: f64,
+    pub price_index: f64, // 年末相对分析开始时的物价指数
 }
 
+impl PathYearlyData {
+    /// 当年净现金流：税后收入减去生活成本（学习期间为扣除资助后的学习成本，
+    /// 与净资产的口径一致），加上贷款放款、减去贷款还款
+    pub fn cash_flow(&self) -> f64 {
+        self.net_income_usd - self.living_cost_usd + self.loan_proceeds - self.loan_payment
+    }
+}
+
+/// 逐年变化的投资回报率与增长率偏差（如蒙特卡洛模拟中的一次抽样）
+///
+/// 向量按年份排列（在职状态按工作月份排列），缺失的年份使用确定性参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateShocks {
+    /// 每年的投资回报率
+    pub investment_return:  Vec<f64>,
+    /// 每年薪资增长率相对Profile设定值的偏差
+    pub salary_growth:      Vec<f64>,
+    /// 每年生活成本增长率（通胀）相对Profile设定值的偏差
+    pub living_cost_growth: Vec<f64>,
+    /// 每年USD/CNY变动率相对汇率走势的偏差
+    pub usd_cny_change:     Vec<f64>,
+    /// 每年CAD/CNY变动率相对汇率走势的偏差
+    pub cad_cny_change:     Vec<f64>,
+    /// 每个工作月是否在职（1或0），按工作月份排列，缺失的月份使用在职概率
+    pub employment:         Vec<f64>,
+}
+
+impl RateShocks {
+    pub(super) fn at(values: &[f64], year: u32) -> Option<f64> {
+        values.get(year as usize - 1).copied()
+    }
+}
+
+/// 某一年某个Profile与基准Profile的数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComparisonData {
     pub year:          u32,
-    pub asu_data:      PathYearlyData,
-    pub shanghai_data: PathYearlyData,
+    pub profile_data:  PathYearlyData,
+    pub baseline_data: PathYearlyData,
+}
+
+impl ComparisonData {
+    /// 相对基准的增量净资产
+    pub fn net_worth_delta(&self) -> f64 {
+        self.profile_data.net_worth - self.baseline_data.net_worth
+    }
 }
 
-/// Profile比较数据
+/// 某个Profile相对基准Profile（如留在国内工作）的增量分析
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineComparison {
+    pub name:             String,
+    pub baseline_name:    String,
+    pub yearly:           Vec<ComparisonData>,
+    /// 比基准多付出的初始成本
+    pub incremental_cost: f64,
+    /// 此后净资产持续不低于基准的第一年
+    pub breakeven_year:   Option<u32>,
+    /// 增量现金流（相对基准）的NPV、IRR和折现回收期
+    pub metrics:          CashFlowMetrics,
+}
+
+impl BaselineComparison {
+    fn new(path: &ProfilePath, baseline: &ProfilePath, discount_rate: f64) -> Self {
+        let yearly: Vec<ComparisonData> = path
+            .data
+            .iter()
+            .zip(&baseline.data)
+            .map(|(profile_data, baseline_data)| ComparisonData {
+                year:          profile_data.year,
+                profile_data:  profile_data.clone(),
+                baseline_data: baseline_data.clone(),
+            })
+            .collect();
+        let cash_flows: Vec<f64> = yearly
+            .iter()
+            .map(|data| data.profile_data.cash_flow() - data.baseline_data.cash_flow())
+            .collect();
+
+        Self {
+            name: path.name.clone(),
+            baseline_name: baseline.name.clone(),
+            yearly,
+            incremental_cost: path.total_cost_usd.unwrap_or(0.0)
+                - baseline.total_cost_usd.unwrap_or(0.0),
+            breakeven_year: path.breakeven_year(Some(baseline)),
+            metrics: CashFlowMetrics::from_cash_flows(&cash_flows, discount_rate),
+        }
+    }
+
+    /// 分析期末的增量净资产
+    pub fn final_delta(&self) -> f64 {
+        self.yearly.last().map(|data| data.net_worth_delta()).unwrap_or(0.0)
+    }
+
+    /// 增量净资产的最低点（为这条路径垫付的最大资金缺口）
+    pub fn max_shortfall(&self) -> f64 {
+        self.yearly
+            .iter()
+            .map(|data| data.net_worth_delta())
+            .fold(0.0, f64::min)
+    }
+}
+
+/// 多Profile对比中单个Profile的路径
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfilePath {
+    pub name:           String,
+    /// 按成本档位调整、扣除资助后的初始成本
+    pub total_cost_usd: Option<f64>,
+    pub data:           Vec<PathYearlyData>,
+}
+
+impl ProfilePath {
+    /// 分析期末的净资产
+    pub fn final_net_worth(&self) -> f64 {
+        self.data.last().map(|d| d.net_worth).unwrap_or(0.0)
+    }
+
+    /// 最终净资产相对初始成本的回报率（无成本时为None）
+    pub fn roi(&self) -> Option<f64> {
+        self.total_cost_usd
+            .filter(|cost| *cost > 0.0)
+            .map(|cost| self.final_net_worth() / cost)
+    }
+
+    /// 逐年净现金流
+    pub fn cash_flows(&self) -> Vec<f64> { self.data.iter().map(|d| d.cash_flow()).collect() }
+
+    /// 按 `discount_rate` 计算的现金流指标
+    pub fn metrics(&self, discount_rate: f64) -> CashFlowMetrics {
+        CashFlowMetrics::from_cash_flows(&self.cash_flows(), discount_rate)
+    }
+
+    /// 此后净资产持续不低于基准（无基准时为0）的第一年
+    pub fn breakeven_year(&self, baseline: Option<&ProfilePath>) -> Option<u32> {
+        let baseline_net_worth =
+            |i: usize| baseline.and_then(|b| b.data.get(i)).map_or(0.0, |d| d.net_worth);
+
+        // 从最后一年往前找，直到差额转负为止
+        self.data
+            .iter()
+            .enumerate()
+            .rev()
+            .take_while(|(i, d)| d.net_worth - baseline_net_worth(*i) >= 0.0)
+            .last()
+            .map(|(_, d)| d.year)
+    }
+}
+
+/// 某一年各Profile的净资产
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileComparisonData {
-    pub year:          u32,
-    pub profile1_data: PathYearlyData,
-    pub profile2_data: PathYearlyData,
-    pub profile1_name: String,
-    pub profile2_name: String,
+    pub year:      u32,
+    /// 与 `ProfileComparison::paths` 顺序一致
+    pub net_worth: Vec<f64>,
 }
 
-// 辅助函数：格式化货币数字
-fn format_currency(amount: f64) -> String {
-    if amount.abs() < 0.01 {
-        "$0".to_string()
-    } else if amount.abs() >= 1_000_000.0 {
-        format!("${:.1}M", amount / 1_000_000.0)
-    } else if amount.abs() >= 1_000.0 {
-        format!("${:.1}K", amount / 1_000.0)
-    } else {
-        format!("${:.0}", amount)
+/// 排名表中的一行
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileRanking {
+    pub rank:            usize,
+    pub name:            String,
+    pub final_net_worth: f64,
+    pub roi:             Option<f64>,
+    pub metrics:         CashFlowMetrics,
+    /// 与第一名的净资产差距
+    pub gap_to_best:     f64,
+}
+
+/// 多个Profile的对比结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileComparison {
+    /// 计算NPV等现金流指标使用的折现率
+    pub discount_rate: f64,
+    pub paths:         Vec<ProfilePath>,
+}
+
+impl ProfileComparison {
+    /// 逐年净资产
+    pub fn yearly(&self) -> Vec<ProfileComparisonData> {
+        let years = self.paths.first().map(|path| path.data.len()).unwrap_or(0);
+        (0..years)
+            .map(|i| ProfileComparisonData {
+                year:      self.paths[0].data[i].year,
+                net_worth: self.paths.iter().map(|path| path.data[i].net_worth).collect(),
+            })
+            .collect()
     }
+
+    /// 按最终净资产从高到低排名
+    pub fn ranking(&self) -> Vec<ProfileRanking> {
+        let mut paths: Vec<&ProfilePath> = self.paths.iter().collect();
+        paths.sort_by(|a, b| b.final_net_worth().total_cmp(&a.final_net_worth()));
+        let best = paths.first().map(|path| path.final_net_worth()).unwrap_or(0.0);
+
+        paths
+            .into_iter()
+            .enumerate()
+            .map(|(i, path)| ProfileRanking {
+                rank:            i + 1,
+                name:            path.name.clone(),
+                final_net_worth: path.final_net_worth(),
+                roi:             path.roi(),
+                metrics:         path.metrics(self.discount_rate),
+                gap_to_best:     path.final_net_worth() - best,
+            })
+            .collect()
+    }
+
+    /// 其余每个Profile相对第 `baseline` 个Profile的增量分析
+    pub fn relative_to(&self, baseline: usize) -> Vec<BaselineComparison> {
+        let Some(base) = self.paths.get(baseline) else {
+            return Vec::new();
+        };
+        self.paths
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != baseline)
+            .map(|(_, path)| BaselineComparison::new(path, base, self.discount_rate))
+            .collect()
+    }
+
+    /// 所有路径净资产的最小值和最大值（包含0）
+    pub fn net_worth_range(&self) -> (f64, f64) {
+        self.paths
+            .iter()
+            .flat_map(|path| &path.data)
+            .fold((0.0f64, 0.0f64), |(min, max), d| {
+                (min.min(d.net_worth), max.max(d.net_worth))
+            })
+    }
+}
+
+// 辅助函数：格式化IRR（现金流未变号时无解）
+fn format_irr(irr: Option<f64>) -> String {
+    irr.map(|irr| format!("{:.2}%", irr * 100.0))
+        .unwrap_or_else(|| "-".to_string())
 }
 
 impl ROICalculator {
@@ -75,162 +319,55 @@ impl ROICalculator {
     /// # Returns
     /// 返回年度财务数据向量
     fn calculate_path_data(&self, profile: &Profile) -> Vec<PathYearlyData> {
-        let params = profile.to_path_params();
-        let mut results = Vec::new();
-        let mut total_cash = 0.0;
-        let mut total_investment = 0.0;
-        let mut total_investment_principal = 0.0;
-        let mut total_cost_paid = 0.0;
-
-        for year in 1..=self.total_years {
-            // 确定工作年数
-            let work_year = self.get_work_year(year, &params);
-
-            // 计算年度财务数据
-            let (income_usd, net_income_usd, living_cost_usd, disposable_income_usd) = self.calculate_year_finances(year, work_year, &params, &mut total_cost_paid);
-
-            // 计算投资分配
-            let (investment_amount, cash_savings) = self.calculate_investment_allocation(year, disposable_income_usd, &params);
-
-            // 计算投资收益
-            let (existing_return, new_investment_return) = self.calculate_investment_returns(total_investment, investment_amount);
-            let total_return = existing_return + new_investment_return;
-
-            // 更新投资和现金
-            total_investment += total_return + investment_amount;
-            total_investment_principal += investment_amount;
-            total_cash += cash_savings;
-
-            // 计算净资产
-            let net_worth = self.calculate_net_worth(
-                total_cash,
-                total_investment,
-                total_cost_paid,
-                params.total_cost_usd.is_some(),
-            );
-
-            results.push(PathYearlyData {
-                year,
-                work_year,
-                income_usd,
-                net_income_usd,
-                living_cost_usd,
-                disposable_income_usd,
-                cash_savings,
-                investment_amount,
-                investment_return: total_return,
-                total_investment,
-                total_investment_principal,
-                total_cash,
-                net_worth,
-            });
-        }
-
-        results
-    }
-
-    /// 获取指定年份的工作年数
-    fn get_work_year(&self, year: u32, params: &PathCalculationParams) -> Option<u32> {
-        if year > params.work_start_delay {
-            let work_year = year - params.work_start_delay;
-            match params.work_duration_limit {
-                Some(limit) if work_year > limit => None,
-                _ => Some(work_year),
-            }
-        } else {
-            None
-        }
+        self.calculate_path_with_shocks(profile, &RateShocks::default())
     }
 
-    /// 计算年度财务数据
-    fn calculate_year_finances(
+    /// 按逐年回报率与增长率偏差计算路径，按月模拟后汇总为年度数据，
+    /// `real_terms` 时折算为实际值
+    pub fn calculate_path_with_shocks(
         &self,
-        year: u32,
-        work_year: Option<u32>,
-        params: &PathCalculationParams,
-        total_cost_paid: &mut f64,
-    ) -> (f64, f64, f64, f64) {
-        if let Some(work_year) = work_year {
-            // 工作期间
-            let salary_usd = params.initial_salary_usd
-                * (1.0 + params.salary_growth_rate).powi(work_year as i32 - 1);
-            let living_cost_usd = 
-                params.living_cost_usd * (1.0 + params.living_cost_growth).powi(year as i32 - 1);
-            let net_income_usd = salary_usd * (1.0 - params.tax_rate);
-            let disposable_income_usd = (net_income_usd - living_cost_usd).max(0.0);
-
-            (
-                salary_usd,
-                net_income_usd,
-                living_cost_usd,
-                disposable_income_usd,
-            )
-        } else {
-            // 非工作期间（学习或退休）
-            if let (Some(total_cost), Some(cost_duration)) = 
-                (params.total_cost_usd, params.cost_duration)
-            {
-                // 有学习成本的情况（如ASU）
-                let annual_cost = total_cost / cost_duration as f64;
-                *total_cost_paid += annual_cost;
-                let living_cost_usd = params.living_cost_usd
-                    * (1.0 + params.living_cost_growth).powi(year as i32 - 1);
-                (0.0, 0.0, living_cost_usd + annual_cost, 0.0)
-            } else {
-                // 无成本的非工作期间（如退休）
-                (0.0, 0.0, 0.0, 0.0)
-            }
-        }
+        profile: &Profile,
+        shocks: &RateShocks,
+    ) -> Vec<PathYearlyData> {
+        self.yearly_path(&self.calculate_monthly_path(profile, shocks), shocks)
     }
 
-    /// 计算投资分配
-    fn calculate_investment_allocation(
+    /// 将月度数据汇总为年度数据并填写物价指数，`real_terms` 时折算为实际值
+    pub(super) fn yearly_path(
         &self,
-        year: u32,
-        disposable_income: f64,
-        params: &PathCalculationParams,
-    ) -> (f64, f64) {
-        let investment_amount = if year == 1 && params.first_year_opportunity_cost.is_some() {
-            // 第一年有机会成本投资
-            params.first_year_opportunity_cost.unwrap()
-                + disposable_income * self.investment_portion
-        } else {
-            // 正常投资分配
-            disposable_income * self.investment_portion
-        };
-
-        let cash_savings = disposable_income - (disposable_income * self.investment_portion);
-        (investment_amount, cash_savings)
+        months: &[PathMonthlyData],
+        shocks: &RateShocks,
+    ) -> Vec<PathYearlyData> {
+        roll_up(months)
+            .into_iter()
+            .zip(self.price_indices(shocks))
+            .map(|(data, price_index)| {
+                let data = PathYearlyData { price_index, ..data };
+                if self.real_terms { data.real() } else { data }
+            })
+            .collect()
     }
 
-    /// 计算净资产
-    fn calculate_net_worth(
-        &self,
-        total_cash: f64,
-        total_investment: f64,
-        total_cost_paid: f64,
-        has_costs: bool,
-    ) -> f64 {
-        if has_costs {
-            total_cash + total_investment - total_cost_paid
-        } else {
-            total_cash + total_investment
+    /// 按成本档位调整生活成本和分项成本
+    pub(super) fn apply_cost_level(&self, mut params: PathCalculationParams) -> PathCalculationParams {
+        let level = self.cost_level.unwrap_or(params.cost_level);
+        let factors = self.cost_levels.factors(level);
+
+        params.cost_level = level;
+        params.living_cost_usd *= factors.living_cost_factor(&self.category_shares);
+        if let Some(cost_params) = params.cost_params.as_mut()
+            && let Some(breakdown) = &cost_params.breakdown
+        {
+            *cost_params = CostParams::itemized(
+                factors.adjust_breakdown(breakdown),
+                cost_params.cost_duration,
+                params.study_months,
+                &params.term_schedule,
+            );
+            params.total_cost_usd = Some(cost_params.total_cost_usd);
         }
-    }
-
-    /// 计算投资收益（考虑每月定投）
-    fn calculate_investment_returns(
-        &self,
-        existing_investment: f64,
-        new_investment: f64,
-    ) -> (f64, f64) {
-        // 存量投资获得全年收益
-        let existing_return = existing_investment * self.investment_return_rate;
 
-        // 新投资按平均持有6个月计算（每月定投的近似）
-        let new_investment_return = new_investment * self.investment_return_rate * 0.5;
-
-        (existing_return, new_investment_return)
+        params
     }
 
     /// 使用Profile计算财务数据
@@ -238,278 +375,340 @@ impl ROICalculator {
         self.calculate_path_data(profile)
     }
 
-    /// 比较两个Profile的ROI数据
-    pub fn compare_profiles(
-        &self,
-        profile1: &Profile,
-        profile2: &Profile,
-    ) -> Vec<ProfileComparisonData> {
-        let data1 = self.calculate_profile_path(profile1);
-        let data2 = self.calculate_profile_path(profile2);
-
-        data1
-            .into_iter()
-            .zip(data2.into_iter())
-            .map(|(data1, data2)| ProfileComparisonData {
-                year:          data1.year,
-                profile1_data: data1,
-                profile2_data: data2,
-                profile1_name: profile1.name.clone(),
-                profile2_name: profile2.name.clone(),
+    /// 在三种成本档位下分别计算同一个Profile
+    pub fn compare_cost_levels(&self, profile: &Profile) -> Vec<(CostLevel, Vec<PathYearlyData>)> {
+        CostLevel::ALL
+            .iter()
+            .map(|&level| {
+                let calculator = ROICalculator {
+                    cost_level: Some(level),
+                    ..self.clone()
+                };
+                (level, calculator.calculate_profile_path(profile))
             })
             .collect()
     }
 
-    /// 计算Profile的最终ROI
-    pub fn calculate_profile_final_roi(
-        &self,
-        profile1: &Profile,
-        profile2: &Profile,
-    ) -> (f64, f64, f64) {
-        let results = self.compare_profiles(profile1, profile2);
-        let final_data = results.last().unwrap();
-
-        // 计算ROI时需要考虑不同的成本基准
-        let profile1_cost_basis = profile1.total_cost_usd.unwrap_or(1.0);
-        let profile2_cost_basis = profile2.total_cost_usd.unwrap_or(profile1_cost_basis);
-
-        let roi1 = if profile1.total_cost_usd.is_some() {
-            (final_data.profile1_data.net_worth + profile1_cost_basis) / profile1_cost_basis
-        } else {
-            final_data.profile1_data.net_worth / profile1_cost_basis
-        };
+    /// 成本档位并排对比分析
+    pub fn analyze_cost_levels(&self, profile: &Profile) {
+        let results = self.compare_cost_levels(profile);
 
-        let roi2 = if profile2.total_cost_usd.is_some() {
-            (final_data.profile2_data.net_worth + profile2_cost_basis) / profile2_cost_basis
-        } else {
-            final_data.profile2_data.net_worth / profile2_cost_basis
+        println!("=== {} 成本档位对比 ===\n", profile.name);
+
+        #[derive(Tabled)]
+        struct CostLevelTableRow {
+            #[tabled(rename = "年份")]
+            year:        u32,
+            #[tabled(rename = "节约型净资产")]
+            budget:      String,
+            #[tabled(rename = "标准型净资产")]
+            standard:    String,
+            #[tabled(rename = "舒适型净资产")]
+            comfortable: String,
+        }
+
+        let net_worth = |level_idx: usize, year_idx: usize| {
+            self.format_currency(results[level_idx].1[year_idx].net_worth)
         };
+        let table_data: Vec<CostLevelTableRow> = results[0]
+            .1
+            .iter()
+            .enumerate()
+            .map(|(i, data)| CostLevelTableRow {
+                year:        data.year,
+                budget:      net_worth(0, i),
+                standard:    net_worth(1, i),
+                comfortable: net_worth(2, i),
+            })
+            .collect();
 
-        let roi_difference = roi2 - roi1;
-        (roi1, roi2, roi_difference)
-    }
+        let mut table = Table::new(table_data);
+        table.with(Style::modern());
+        println!("{}", table);
 
-    /// Profile比较分析
-    pub fn analyze_profile_comparison(&self, profile1: &Profile, profile2: &Profile) {
-        let results = self.compare_profiles(profile1, profile2);
-        let (roi1, roi2, roi_diff) = self.calculate_profile_final_roi(profile1, profile2);
+        #[derive(Tabled)]
+        struct CostLevelSummary {
+            #[tabled(rename = "档位")]
+            level:       String,
+            #[tabled(rename = "累计生活成本")]
+            living_cost: String,
+            #[tabled(rename = "最终净资产")]
+            net_worth:   String,
+        }
 
-        println!(
-            "=== {} vs {} ROI 详细分析 ===\n",
-            profile1.name,
-            profile2.name
-        );
+        let summary_data: Vec<CostLevelSummary> = results
+            .iter()
+            .map(|(level, data)| CostLevelSummary {
+                level:       level.label().to_string(),
+                living_cost: self.format_currency(data.iter().map(|d| d.living_cost_usd).sum()),
+                net_worth:   self.format_currency(data.last().map(|d| d.net_worth).unwrap_or(0.0)),
+            })
+            .collect();
 
-        self.print_profile_parameters(profile1, profile2);
-        self.print_profile_yearly_data(&results);
-        self.print_profile_roi_summary(roi1, roi2, roi_diff, &results, profile1, profile2);
+        println!("{}", Table::new(summary_data));
+        println!();
     }
 
-    /// 打印Profile参数对比
-    fn print_profile_parameters(&self, profile1: &Profile, profile2: &Profile) {
-        println!("=== Profile参数对比 ==");
+    /// 计算Profile路径并附带按成本档位调整、扣除资助后的初始成本
+    pub fn profile_path(&self, profile: &Profile) -> ProfilePath {
+        let params = self.apply_cost_level(profile.to_path_params());
+        ProfilePath {
+            name:           profile.name.clone(),
+            total_cost_usd: params.total_cost_usd.map(|cost| cost - self.funding_total(&params)),
+            data:           self.calculate_profile_path(profile),
+        }
+    }
 
-        #[derive(Tabled)]
-        struct ProfileParameter {
-            #[tabled(rename = "参数")]
-            name:   String,
-            #[tabled(rename = "Profile 1")]
-            value1: String,
-            #[tabled(rename = "Profile 2")]
-            value2: String,
+    /// 同时计算多个Profile的路径
+    pub fn compare_profiles<'a>(
+        &self,
+        profiles: impl IntoIterator<Item = &'a Profile>,
+    ) -> ProfileComparison {
+        let paths = profiles
+            .into_iter()
+            .map(|profile| self.profile_path(profile))
+            .collect();
+
+        ProfileComparison {
+            discount_rate: self.discount_rate,
+            paths,
         }
+    }
 
-        let parameters = vec![
-            ProfileParameter {
-                name:   "Profile名称".to_string(),
-                value1: profile1.name.clone(),
-                value2: profile2.name.clone(),
-            },
-            ProfileParameter {
-                name:   "类型".to_string(),
-                value1: match profile1.profile_type {
-                    crate::models::ProfileType::Education => "教育路径".to_string(),
-                    crate::models::ProfileType::Work => "工作路径".to_string(),
-                },
-                value2: match profile2.profile_type {
-                    crate::models::ProfileType::Education => "教育路径".to_string(),
-                    crate::models::ProfileType::Work => "工作路径".to_string(),
-                },
-            },
-            ProfileParameter {
-                name:   "地点".to_string(),
-                value1: if let Some(city) = &profile1.location.city {
-                    format!("{}, {}", city, profile1.location.country)
-                } else {
-                    profile1.location.country.clone()
-                },
-                value2: if let Some(city) = &profile2.location.city {
-                    format!("{}, {}", city, profile2.location.country)
+    /// 多Profile比较分析
+    pub fn analyze_profile_comparison(&self, profiles: &[Profile]) {
+        let comparison = self.compare_profiles(profiles);
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+
+        println!("=== {} ROI 详细分析 ===\n", names.join(" vs "));
+
+        self.print_profile_parameters(profiles);
+        self.print_profile_yearly_data(&comparison);
+        self.print_profile_ranking(&comparison);
+    }
+
+    /// 打印Profile参数对比（每个Profile一列）
+    fn print_profile_parameters(&self, profiles: &[Profile]) {
+        println!("=== Profile参数对比 ==");
+
+        type ParameterRow<'a> = (&'static str, &'a dyn Fn(&Profile) -> String);
+        let rows: [ParameterRow; 10] = [
+            ("Profile名称", &|p| p.name.clone()),
+            ("类型", &|p| match p.profile_type {
+                crate::models::ProfileType::Education => "教育路径".to_string(),
+                crate::models::ProfileType::Work => "工作路径".to_string(),
+            }),
+            ("地点", &|p| match &p.location.city {
+                Some(city) => format!("{}, {}", city, p.location.country),
+                None => p.location.country.clone(),
+            }),
+            ("初始薪资", &|p| format!("{}/年", self.reporting(p.financial_params.initial_salary_usd))),
+            ("薪资增长率", &|p| format!("{:.1}%/年", p.financial_params.salary_growth_rate * 100.0)),
+            ("生活成本", &|p| format!("{}/年", self.reporting(p.financial_params.living_cost_usd))),
+            ("税率", &|p| self.tax_label(p)),
+            ("学生贷款", &|p| {
+                let principal: f64 = p.loans.iter().map(|loan| loan.principal.to_usd(&self.rates)).sum();
+                if p.loans.is_empty() { "无".to_string() } else { self.reporting(principal).to_string() }
+            }),
+            ("资助", &|p| {
+                let funding = self.funding_total(&self.apply_cost_level(p.to_path_params()));
+                if p.funding.is_empty() {
+                    "无".to_string()
                 } else {
-                    profile2.location.country.clone()
-                },
-            },
-            ProfileParameter {
-                name:   "初始薪资".to_string(),
-                value1: format!("${:.0}/年", profile1.initial_salary_usd),
-                value2: format!("${:.0}/年", profile2.initial_salary_usd),
-            },
-            ProfileParameter {
-                name:   "薪资增长率".to_string(),
-                value1: format!("{:.1}%/年", profile1.salary_growth_rate * 100.0),
-                value2: format!("{:.1}%/年", profile2.salary_growth_rate * 100.0),
-            },
-            ProfileParameter {
-                name:   "生活成本".to_string(),
-                value1: format!("${:.0}/年", profile1.living_cost_usd),
-                value2: format!("${:.0}/年", profile2.living_cost_usd),
-            },
-            ProfileParameter {
-                name:   "税率".to_string(),
-                value1: format!("{:.1}%", profile1.tax_rate * 100.0),
-                value2: format!("{:.1}%", profile2.tax_rate * 100.0),
-            },
+                    format!("{} ({})", self.reporting(funding), self.funding_mode.label())
+                }
+            }),
+            ("求职与裁员", &|p| p.employment.summary()),
         ];
 
-        let params_table = Table::new(parameters);
-        println!("{}", params_table);
+        let mut builder = Builder::default();
+        builder.push_record(
+            std::iter::once("参数".to_string())
+                .chain((1..=profiles.len()).map(|i| format!("Profile {}", i))),
+        );
+        for (name, value) in rows {
+            builder.push_record(
+                std::iter::once(name.to_string()).chain(profiles.iter().map(value)),
+            );
+        }
+
+        println!("{}", builder.build());
         println!();
     }
 
-    /// 打印Profile年度数据对比
-    fn print_profile_yearly_data(&self, results: &[ProfileComparisonData]) {
-        println!("=== 年度详细数据对比 ==");
+    /// 打印逐年净资产（每个Profile一列）
+    fn print_profile_yearly_data(&self, comparison: &ProfileComparison) {
+        println!("=== 年度净资产对比（{}） ==", self.value_label());
 
-        #[derive(Tabled)]
-        struct ProfileYearlyTableRow {
-            #[tabled(rename = "年份")]
-            year:               u32,
-            #[tabled(rename = "Profile1净资产")]
-            profile1_net_worth: String,
-            #[tabled(rename = "Profile2净资产")]
-            profile2_net_worth: String,
-            #[tabled(rename = "差异")]
-            difference:         String,
+        let mut builder = Builder::default();
+        builder.push_record(
+            std::iter::once("年份".to_string())
+                .chain(comparison.paths.iter().map(|path| path.name.clone())),
+        );
+        for row in comparison.yearly() {
+            builder.push_record(
+                std::iter::once(row.year.to_string())
+                    .chain(row.net_worth.iter().map(|nw| self.format_currency(*nw))),
+            );
         }
 
-        let table_data: Vec<ProfileYearlyTableRow> = results
-            .iter()
-            .map(|data| {
-                let difference = data.profile2_data.net_worth - data.profile1_data.net_worth;
-                ProfileYearlyTableRow {
-                    year:               data.year,
-                    profile1_net_worth: format_currency(data.profile1_data.net_worth),
-                    profile2_net_worth: format_currency(data.profile2_data.net_worth),
-                    difference:         format_currency(difference),
-                }
-            })
-            .collect();
-
-        let mut table = Table::new(table_data);
+        let mut table = builder.build();
         table.with(Style::modern());
         println!("{}", table);
         println!();
     }
 
-    /// 打印Profile ROI总结
-    fn print_profile_roi_summary(
-        &self,
-        roi1: f64,
-        roi2: f64,
-        roi_diff: f64,
-        results: &[ProfileComparisonData],
-        profile1: &Profile,
-        profile2: &Profile,
-    ) {
-        println!("\n=== 最终ROI分析 ===");
+    /// 打印最终排名
+    fn print_profile_ranking(&self, comparison: &ProfileComparison) {
+        println!("\n=== 最终排名 ===");
 
         #[derive(Tabled)]
-        struct ProfileROISummary {
+        struct ProfileRankingRow {
+            #[tabled(rename = "排名")]
+            rank:      usize,
             #[tabled(rename = "Profile")]
             profile:   String,
-            #[tabled(rename = "最终ROI")]
-            roi:       String,
-            #[tabled(rename = "净资产")]
+            #[tabled(rename = "最终净资产")]
             net_worth: String,
+            #[tabled(rename = "ROI")]
+            roi:       String,
+            #[tabled(rename = "NPV")]
+            npv:       String,
+            #[tabled(rename = "IRR")]
+            irr:       String,
+            #[tabled(rename = "折现回收期")]
+            payback:   String,
+            #[tabled(rename = "与第一名差距")]
+            gap:       String,
         }
 
-        let final_data = results.last().unwrap();
-        let summary_data = vec![
-            ProfileROISummary {
-                profile:   profile1.name.clone(),
-                roi:       format!("{:.2}%", (roi1 - 1.0) * 100.0),
-                net_worth: format_currency(final_data.profile1_data.net_worth),
-            },
-            ProfileROISummary {
-                profile:   profile2.name.clone(),
-                roi:       format!("{:.2}%", (roi2 - 1.0) * 100.0),
-                net_worth: format_currency(final_data.profile2_data.net_worth),
-            },
-            ProfileROISummary {
-                profile:   "差异".to_string(),
-                roi:       format!("{:.2}%", roi_diff * 100.0),
-                net_worth: format_currency(
-                    final_data.profile2_data.net_worth - final_data.profile1_data.net_worth,
-                ),
-            },
-        ];
+        let ranking = comparison.ranking();
+        let rows: Vec<ProfileRankingRow> = ranking
+            .iter()
+            .map(|r| ProfileRankingRow {
+                rank:      r.rank,
+                profile:   r.name.clone(),
+                net_worth: self.format_currency(r.final_net_worth),
+                roi:       r
+                    .roi
+                    .map(|roi| format!("{:.2}%", roi * 100.0))
+                    .unwrap_or_else(|| "-".to_string()),
+                npv:       self.format_currency(r.metrics.npv),
+                irr:       format_irr(r.metrics.irr),
+                payback:   self.format_payback(r.metrics.discounted_payback_year),
+                gap:       self.format_currency(r.gap_to_best),
+            })
+            .collect();
 
-        let summary_table = Table::new(summary_data);
-        println!("{}", summary_table);
+        let mut table = Table::new(rows);
+        table.with(Style::modern());
+        println!("{}", table);
+        println!("NPV按 {:.1}% 折现率计算", comparison.discount_rate * 100.0);
 
-        // 盈亏平衡点分析
-        let breakeven_year = results
-            .iter()
-            .find(|d| d.profile1_data.net_worth >= d.profile2_data.net_worth);
-        if let Some(data) = breakeven_year {
-            println!(
-                "\n💡 {}在第{}年追平{}",
-                profile1.name,
-                data.year,
-                profile2.name
-            );
-        } else {
-            println!(
-                "\n⚠️ 在{}年分析期内，{}未能追平{}",
-                self.total_years,
-                profile1.name,
-                profile2.name
+        if let Some(best) = ranking.first() {
+            println!("\n📊 结论: 在当前假设下，{}的财务回报最优", best.name);
+        }
+    }
+
+    /// 美元金额换算为报告货币
+    pub fn reporting(&self, amount_usd: f64) -> Money {
+        Money::usd(amount_usd).to(self.reporting_currency, &self.rates)
+    }
+
+    /// 以报告货币显示的金额（千分位），如 ¥1,234,567
+    pub fn format_amount(&self, amount_usd: f64) -> String {
+        self.reporting(amount_usd).to_string()
+    }
+
+    /// 以报告货币显示的紧凑金额，如 $1.2M
+    fn format_currency(&self, amount_usd: f64) -> String {
+        self.reporting(amount_usd).compact()
+    }
+
+    /// 折现回收期的显示文本
+    fn format_payback(&self, year: Option<u32>) -> String {
+        year.map(|year| format!("第{}年", year))
+            .unwrap_or_else(|| format!("{}年内未回收", self.total_years))
+    }
+
+    /// 以 `baseline` 为基准的增量分析
+    pub fn analyze_baseline_comparison(&self, baseline: &Profile, profiles: &[Profile]) {
+        let comparison = self.compare_profiles(std::iter::once(baseline).chain(profiles));
+        let deltas = comparison.relative_to(0);
+
+        println!("\n=== 相对基准「{}」的增量分析 ===", baseline.name);
+
+        let mut builder = Builder::default();
+        builder.push_record(
+            std::iter::once("年份".to_string()).chain(deltas.iter().map(|d| d.name.clone())),
+        );
+        for (i, path) in comparison.paths[0].data.iter().enumerate() {
+            builder.push_record(
+                std::iter::once(path.year.to_string())
+                    .chain(deltas.iter().map(|d| self.format_currency(d.yearly[i].net_worth_delta()))),
             );
         }
+        let mut table = builder.build();
+        table.with(Style::modern());
+        println!("{}", table);
 
-        // 结论
-        if final_data.profile2_data.net_worth > final_data.profile1_data.net_worth {
-            println!("\n📊 结论: 在当前假设下，{}的财务回报更优", profile2.name);
-        } else {
-            println!("\n📊 结论: 在当前假设下，{}的财务回报更优", profile1.name);
+        #[derive(Tabled)]
+        struct BaselineSummaryRow {
+            #[tabled(rename = "Profile")]
+            profile:          String,
+            #[tabled(rename = "增量成本")]
+            incremental_cost: String,
+            #[tabled(rename = "最大资金缺口")]
+            max_shortfall:    String,
+            #[tabled(rename = "最终增量净资产")]
+            final_delta:      String,
+            #[tabled(rename = "回本年份")]
+            breakeven:        String,
+            #[tabled(rename = "增量NPV")]
+            npv:              String,
+            #[tabled(rename = "增量IRR")]
+            irr:              String,
+            #[tabled(rename = "折现回收期")]
+            payback:          String,
         }
+
+        let rows: Vec<BaselineSummaryRow> = deltas
+            .iter()
+            .map(|d| BaselineSummaryRow {
+                profile:          d.name.clone(),
+                incremental_cost: self.format_currency(d.incremental_cost),
+                max_shortfall:    self.format_currency(d.max_shortfall()),
+                final_delta:      self.format_currency(d.final_delta()),
+                breakeven:        d
+                    .breakeven_year
+                    .map(|year| format!("第{}年", year))
+                    .unwrap_or_else(|| format!("{}年内未回本", self.total_years)),
+                npv:              self.format_currency(d.metrics.npv),
+                irr:              format_irr(d.metrics.irr),
+                payback:          self.format_payback(d.metrics.discounted_payback_year),
+            })
+            .collect();
+
+        let mut table = Table::new(rows);
+        table.with(Style::modern());
+        println!("{}", table);
     }
 
-    /// 生成Profile比较图表
+    /// 生成多Profile净资产对比图（每个Profile一条曲线）
     pub fn generate_profile_comparison_chart(
         &self,
-        profile1: &Profile,
-        profile2: &Profile,
+        profiles: &[Profile],
         filename: &str,
     ) -> Result<()> {
-        let results = self.compare_profiles(profile1, profile2);
+        let comparison = self.compare_profiles(profiles);
+        let (min_net_worth, max_net_worth) = comparison.net_worth_range();
 
         let root = BitMapBackend::new(filename, (1200, 800)).into_drawing_area();
         root.fill(&WHITE)?;
 
-        let max_net_worth = results
-            .iter()
-            .map(|d| d.profile1_data.net_worth.max(d.profile2_data.net_worth))
-            .fold(0.0f64, |acc, x| acc.max(x));
-
-        let min_net_worth = results
-            .iter()
-            .map(|d| d.profile1_data.net_worth.min(d.profile2_data.net_worth))
-            .fold(0.0f64, |acc, x| acc.min(x));
-
         let mut chart = ChartBuilder::on(&root)
             .caption(
-                &format!("{} vs {} 净资产对比", profile1.name, profile2.name),
+                format!("{}个Profile净资产对比", comparison.paths.len()),
                 ("Arial", 30),
             )
             .margin(10)
@@ -517,37 +716,148 @@ impl ROICalculator {
             .y_label_area_size(80)
             .build_cartesian_2d(
                 1u32..self.total_years,
-                (min_net_worth * 1.1)..(max_net_worth * 1.1),
+                self.reporting(min_net_worth * 1.1).amount..self.reporting(max_net_worth * 1.1).amount,
             )?;
 
         chart
             .configure_mesh()
             .x_desc("年份")
-            .y_desc("净资产 (美元)")
+            .y_desc(format!("净资产 ({})", self.value_label()))
             .draw()?;
 
-        // Profile 1路径
-        chart
-            .draw_series(LineSeries::new(
-                results.iter().map(|d| (d.year, d.profile1_data.net_worth)),
-                &RED,
-            ))? 
-            .label(&profile1.name)
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], RED));
-
-        // Profile 2路径
+        for (i, path) in comparison.paths.iter().enumerate() {
+            let color = Palette99::pick(i).to_rgba();
+            chart
+                .draw_series(LineSeries::new(
+                    path.data.iter().map(|d| (d.year, self.reporting(d.net_worth).amount)),
+                    color.stroke_width(2),
+                ))?
+                .label(&path.name)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], color));
+        }
+
         chart
-            .draw_series(LineSeries::new(
-                results.iter().map(|d| (d.year, d.profile2_data.net_worth)),
-                &BLUE,
-            ))? 
-            .label(&profile2.name)
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], BLUE));
-
-        chart.configure_series_labels().draw()?;
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
         root.present()?;
 
         println!("图表已保存到: {}", filename);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ProfileType, test_profile};
+
+    fn profiles() -> Vec<Profile> {
+        let mut education = test_profile(ProfileType::Education, Currency::Usd, 120000.0, 36000.0);
+        education.name = "Education".to_string();
+        let mut work = test_profile(ProfileType::Work, Currency::Usd, 60000.0, 24000.0);
+        work.name = "Work".to_string();
+        vec![education, work]
+    }
+
+    /// 以指定的逐年净资产构造路径
+    fn path(name: &str, net_worth: &[f64]) -> ProfilePath {
+        let template = ROICalculator::default().calculate_profile_path(&profiles()[1])[0].clone();
+        ProfilePath {
+            name:           name.to_string(),
+            total_cost_usd: None,
+            data:           net_worth
+                .iter()
+                .enumerate()
+                .map(|(i, &net_worth)| PathYearlyData {
+                    year: i as u32 + 1,
+                    net_worth,
+                    ..template.clone()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_breakeven_year() {
+        assert_eq!(path("Never", &[-1.0, -2.0, -3.0]).breakeven_year(None), None);
+        assert_eq!(path("Always", &[0.0, 2.0, 3.0]).breakeven_year(None), Some(1));
+
+        // 转正后再次转负，以最后一次转正的年份为准
+        let dips = path("Dips", &[-1.0, 1.0, -1.0, 2.0, 3.0]);
+        assert_eq!(dips.breakeven_year(None), Some(4));
+        assert_eq!(path("Ends", &[-1.0, 1.0, -1.0]).breakeven_year(None), None);
+
+        // 与基准比较，持平也算不低于基准
+        let baseline = path("Baseline", &[1.0, 5.0, 8.0, 9.0, 10.0]);
+        assert_eq!(dips.breakeven_year(Some(&baseline)), None);
+        let path = path("Path", &[0.0, 5.0, 10.0, 9.0, 12.0]);
+        assert_eq!(path.breakeven_year(Some(&baseline)), Some(2));
+    }
+
+    #[test]
+    fn test_ranking_and_range() {
+        let comparison = ProfileComparison {
+            discount_rate: 0.05,
+            paths:         vec![
+                path("A", &[-3.0, 4.0]),
+                path("B", &[5.0, 10.0]),
+                path("C", &[2.0, 10.0]),
+            ],
+        };
+
+        // 并列时保持输入顺序，名次依次递增
+        let ranking = comparison.ranking();
+        let names: Vec<&str> = ranking.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(names, ["B", "C", "A"]);
+        let ranks: Vec<usize> = ranking.iter().map(|row| row.rank).collect();
+        assert_eq!(ranks, [1, 2, 3]);
+        assert_eq!(ranking[1].gap_to_best, 0.0);
+        assert_eq!(ranking[2].gap_to_best, -6.0);
+
+        assert_eq!(comparison.net_worth_range(), (-3.0, 10.0));
+        let positive = ProfileComparison {
+            discount_rate: 0.05,
+            paths:         vec![path("B", &[5.0, 10.0])],
+        };
+        assert_eq!(positive.net_worth_range(), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_relative_to_baseline() {
+        let comparison = ROICalculator::default().compare_profiles(&profiles());
+
+        // 基准本身不参与比较
+        let relative = comparison.relative_to(1);
+        assert_eq!(relative.len(), 1);
+        let education = &relative[0];
+        assert_eq!(education.name, "Education");
+        assert_eq!(education.baseline_name, "Work");
+        assert!(comparison.relative_to(2).is_empty());
+
+        // 学习期内落后于基准，毕业后反超
+        assert!(education.yearly[0].net_worth_delta() < 0.0);
+        assert!(education.final_delta() > 0.0);
+        assert_eq!(
+            education.incremental_cost,
+            comparison.paths[0].total_cost_usd.unwrap()
+        );
+
+        // 最大资金缺口出现在学习期最后一年
+        let shortfall = education
+            .yearly
+            .iter()
+            .min_by(|a, b| a.net_worth_delta().total_cmp(&b.net_worth_delta()))
+            .unwrap();
+        assert_eq!(shortfall.year, 2);
+        assert_eq!(education.max_shortfall(), shortfall.net_worth_delta());
+
+        // 反过来以教育路径为基准时增量符号相反
+        let work = &comparison.relative_to(0)[0];
+        assert_eq!(work.name, "Work");
+        assert_eq!(work.final_delta(), -education.final_delta());
+        assert_eq!(work.incremental_cost, -education.incremental_cost);
+        assert_eq!(work.max_shortfall(), -education.final_delta());
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{ProfilePath, ROICalculator};
+use crate::models::{ParamOverride, Profile, Scenario, ScenarioSet};
+
+/// 单个情景的计算结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioResult {
+    pub name:        String,
+    pub probability: f64,
+    /// 覆盖项说明
+    pub summary:     String,
+    pub path:        ProfilePath,
+}
+
+impl ScenarioResult {
+    pub fn final_net_worth(&self) -> f64 { self.path.final_net_worth() }
+
+    pub fn roi(&self) -> Option<f64> { self.path.roi() }
+}
+
+/// 一个Profile在各情景下的结果及概率加权期望
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioAnalysis {
+    pub profile:   String,
+    pub scenarios: Vec<ScenarioResult>,
+}
+
+impl ScenarioAnalysis {
+    /// 概率加权的期望最终净资产
+    pub fn expected_net_worth(&self) -> f64 {
+        self.scenarios
+            .iter()
+            .map(|s| s.probability * s.final_net_worth())
+            .sum()
+    }
+
+    /// 概率加权的期望ROI（任一情景无初期成本时为None）
+    pub fn expected_roi(&self) -> Option<f64> {
+        self.scenarios
+            .iter()
+            .map(|s| s.roi().map(|roi| s.probability * roi))
+            .sum()
+    }
+}
+
+impl ROICalculator {
+    /// 应用情景中计算器设定覆盖后的计算器
+    pub fn with_scenario(&self, scenario: &Scenario) -> ROICalculator {
+        let mut calculator = self.clone();
+        if let Some(param) = scenario.investment_return_rate {
+            calculator.investment_return_rate = param.apply(calculator.investment_return_rate);
+        }
+        if let Some(param) = scenario.investment_portion {
+            calculator.investment_portion = param.apply(calculator.investment_portion);
+        }
+        if scenario.cost_level.is_some() {
+            calculator.cost_level = scenario.cost_level;
+        }
+        // 统一税率已在Profile上调整，地区税表的税负按同一系数缩放
+        if let Some(ParamOverride::Scale(factor)) = scenario.tax_rate {
+            calculator.regional_tax_scale *= factor;
+        }
+        calculator
+    }
+
+    /// 检查情景能否应用于Profile：按地区税表计税时税率只能按系数缩放
+    fn check_scenario(&self, profile: &Profile, scenario: &Scenario) -> Result<()> {
+        if matches!(
+            scenario.tax_rate,
+            Some(ParamOverride::Set(_) | ParamOverride::Add(_))
+        ) && self.has_regional_tax(profile.location.region.as_deref())
+        {
+            return Err(anyhow::anyhow!(
+                "情景 '{}' 的税率覆盖不适用于 {}：该地区按税表计税，请使用 scale:<系数>",
+                scenario.name,
+                profile.name
+            ));
+        }
+        Ok(())
+    }
+
+    /// 按情景集合计算，`scenarios`
+    /// 为None时使用Profile附带的情景（没有则用标准情景）
+    pub fn evaluate_scenarios(
+        &self,
+        profile: &Profile,
+        scenarios: Option<&ScenarioSet>,
+    ) -> Result<ScenarioAnalysis> {
+        let standard;
+        let scenarios = match scenarios {
+            Some(scenarios) => scenarios,
+            None if !profile.scenarios.is_empty() => &profile.scenarios,
+            None => {
+                standard = ScenarioSet::standard();
+                &standard
+            }
+        };
+        scenarios.validate()?;
+        for scenario in &scenarios.scenarios {
+            self.check_scenario(profile, scenario)?;
+        }
+
+        let results = scenarios
+            .scenarios
+            .iter()
+            .map(|scenario| {
+                let calculator = self.with_scenario(scenario);
+                let adjusted = scenario.apply_to_profile(profile);
+                ScenarioResult {
+                    name:        scenario.name.clone(),
+                    probability: scenario.probability,
+                    summary:     scenario.summary(),
+                    path:        calculator.profile_path(&adjusted),
+                }
+            })
+            .collect();
+
+        Ok(ScenarioAnalysis {
+            profile:   profile.name.clone(),
+            scenarios: results,
+        })
+    }
+}
@@ -0,0 +1,314 @@
+use anyhow::Result;
+use plotters::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ProfilePath, ROICalculator};
+use crate::models::{ParamOverride, Profile, Scenario};
+
+/// 参与敏感性分析的参数（仅连续参数，年数等整数参数不做扰动）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensitivityParam {
+    InitialSalary,
+    SalaryGrowth,
+    LivingCost,
+    LivingCostGrowth,
+    TaxRate,
+    TotalCost,
+    InvestmentReturn,
+    InvestmentPortion,
+}
+
+impl SensitivityParam {
+    pub const ALL: [SensitivityParam; 8] = [
+        SensitivityParam::InitialSalary,
+        SensitivityParam::SalaryGrowth,
+        SensitivityParam::LivingCost,
+        SensitivityParam::LivingCostGrowth,
+        SensitivityParam::TaxRate,
+        SensitivityParam::TotalCost,
+        SensitivityParam::InvestmentReturn,
+        SensitivityParam::InvestmentPortion,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SensitivityParam::InitialSalary => "初始年薪",
+            SensitivityParam::SalaryGrowth => "薪资增长率",
+            SensitivityParam::LivingCost => "生活成本",
+            SensitivityParam::LivingCostGrowth => "生活成本增长率",
+            SensitivityParam::TaxRate => "税率",
+            SensitivityParam::TotalCost => "初期成本",
+            SensitivityParam::InvestmentReturn => "投资回报率",
+            SensitivityParam::InvestmentPortion => "投资比例",
+        }
+    }
+
+    /// 计算器层面的参数（对基准Profile同样生效）
+    pub fn is_calculator_setting(&self) -> bool {
+        matches!(
+            self,
+            SensitivityParam::InvestmentReturn | SensitivityParam::InvestmentPortion
+        )
+    }
+
+    /// 将该参数乘以 `factor` 的情景
+    fn scenario(&self, factor: f64) -> Scenario {
+        let scale = Some(ParamOverride::Scale(factor));
+        let mut scenario = Scenario::new(self.label(), 1.0);
+        match self {
+            SensitivityParam::InitialSalary => scenario.initial_salary_usd = scale,
+            SensitivityParam::SalaryGrowth => scenario.salary_growth_rate = scale,
+            SensitivityParam::LivingCost => scenario.living_cost_usd = scale,
+            SensitivityParam::LivingCostGrowth => scenario.living_cost_growth = scale,
+            SensitivityParam::TaxRate => scenario.tax_rate = scale,
+            SensitivityParam::TotalCost => scenario.total_cost_usd = scale,
+            SensitivityParam::InvestmentReturn => scenario.investment_return_rate = scale,
+            SensitivityParam::InvestmentPortion => scenario.investment_portion = scale,
+        }
+        scenario
+    }
+}
+
+/// 某组参数下的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityOutcome {
+    pub final_net_worth: f64,
+    /// 此后净资产持续不低于基准（无基准时为0）的第一年
+    pub breakeven_year:  Option<u32>,
+}
+
+/// 单个参数上下扰动的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParamSensitivity {
+    pub param: SensitivityParam,
+    /// 参数下调后的结果
+    pub low:   SensitivityOutcome,
+    /// 参数上调后的结果
+    pub high:  SensitivityOutcome,
+}
+
+impl ParamSensitivity {
+    /// 最终净资产的波动幅度
+    pub fn net_worth_swing(&self) -> f64 {
+        (self.high.final_net_worth - self.low.final_net_worth).abs()
+    }
+
+    /// 回本年份的变化（年），任一方向未回本时记为分析期末之后一年
+    pub fn breakeven_swing(&self, total_years: u32) -> u32 {
+        let year = |outcome: &SensitivityOutcome| outcome.breakeven_year.unwrap_or(total_years + 1);
+        year(&self.high).abs_diff(year(&self.low))
+    }
+}
+
+/// 单个Profile的敏感性分析结果，按影响从大到小排列
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityAnalysis {
+    pub profile:     String,
+    pub baseline:    Option<String>,
+    /// 相对扰动幅度，如 0.1 表示 ±10%
+    pub delta:       f64,
+    pub total_years: u32,
+    /// 未扰动时的结果
+    pub base:        SensitivityOutcome,
+    pub params:      Vec<ParamSensitivity>,
+}
+
+impl SensitivityAnalysis {
+    /// 龙卷风图：各参数上下扰动后最终净资产相对未扰动结果的变化
+    pub fn generate_tornado_chart(&self, filename: &str) -> Result<()> {
+        // 离散坐标包含两端，共 count 个分段
+        let count = self.params.len() as u32;
+        let base = self.base.final_net_worth;
+        let (min_delta, max_delta) = self
+            .params
+            .iter()
+            .flat_map(|p| [p.low.final_net_worth - base, p.high.final_net_worth - base])
+            .fold((0.0f64, 0.0f64), |(min, max), d| (min.min(d), max.max(d)));
+        let margin = ((max_delta - min_delta) * 0.1).max(1.0);
+
+        let root = BitMapBackend::new(filename, (1200, 800)).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(
+                format!("{} 敏感性分析（±{:.0}%）", self.profile, self.delta * 100.0),
+                ("Arial", 30),
+            )
+            .margin(10)
+            .x_label_area_size(50)
+            .y_label_area_size(140)
+            .build_cartesian_2d(
+                (min_delta - margin)..(max_delta + margin),
+                (0..count.saturating_sub(1)).into_segmented(),
+            )?;
+
+        // 影响最大的参数画在最上方
+        let labels: Vec<&str> = self.params.iter().rev().map(|p| p.param.label()).collect();
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .y_labels(labels.len())
+            .y_label_formatter(&|value| match value {
+                SegmentValue::CenterOf(i) => {
+                    labels.get(*i as usize).copied().unwrap_or("").to_string()
+                }
+                _ => String::new(),
+            })
+            .x_desc("最终净资产变化 (美元)")
+            .draw()?;
+
+        let low_color = RED.mix(0.7);
+        let high_color = BLUE.mix(0.7);
+        for (series, label, color) in [
+            (0, format!("参数 -{:.0}%", self.delta * 100.0), low_color),
+            (1, format!("参数 +{:.0}%", self.delta * 100.0), high_color),
+        ] {
+            chart
+                .draw_series(
+                    Histogram::horizontal(&chart)
+                        .style(color.filled())
+                        .margin(8)
+                        .data(self.params.iter().rev().enumerate().map(|(i, p)| {
+                            let outcome = if series == 0 { &p.low } else { &p.high };
+                            (i as u32, outcome.final_net_worth - base)
+                        })),
+                )?
+                .label(label)
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled())
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+        root.present()?;
+
+        println!("图表已保存到: {}", filename);
+        Ok(())
+    }
+}
+
+impl ROICalculator {
+    /// 将每个参数分别上下扰动 `delta`（相对比例），按最终净资产的波动幅度排序
+    ///
+    /// 提供 `baseline` 时回本年份相对基准Profile计算，
+    /// 计算器层面的参数（投资回报率、投资比例）对基准同样生效
+    pub fn evaluate_sensitivity(
+        &self,
+        profile: &Profile,
+        baseline: Option<&Profile>,
+        delta: f64,
+    ) -> Result<SensitivityAnalysis> {
+        if !(delta > 0.0 && delta < 1.0) {
+            return Err(anyhow::anyhow!("扰动幅度必须在0到100%之间"));
+        }
+
+        let outcome = |scenario: &Scenario, param: Option<SensitivityParam>| {
+            let calculator = self.with_scenario(scenario);
+            let path = calculator.profile_path(&scenario.apply_to_profile(profile));
+            let baseline_path: Option<ProfilePath> = baseline.map(|baseline| {
+                if param.is_some_and(|p| p.is_calculator_setting()) {
+                    calculator.profile_path(baseline)
+                } else {
+                    self.profile_path(baseline)
+                }
+            });
+            SensitivityOutcome {
+                final_net_worth: path.final_net_worth(),
+                breakeven_year:  path.breakeven_year(baseline_path.as_ref()),
+            }
+        };
+
+        let mut params: Vec<ParamSensitivity> = SensitivityParam::ALL
+            .iter()
+            .map(|&param| ParamSensitivity {
+                param,
+                low: outcome(&param.scenario(1.0 - delta), Some(param)),
+                high: outcome(&param.scenario(1.0 + delta), Some(param)),
+            })
+            .collect();
+        params.sort_by(|a, b| {
+            b.net_worth_swing()
+                .total_cmp(&a.net_worth_swing())
+                .then_with(|| {
+                    b.breakeven_swing(self.total_years)
+                        .cmp(&a.breakeven_swing(self.total_years))
+                })
+        });
+
+        Ok(SensitivityAnalysis {
+            profile: profile.name.clone(),
+            baseline: baseline.map(|baseline| baseline.name.clone()),
+            delta,
+            total_years: self.total_years,
+            base: outcome(&Scenario::default(), None),
+            params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, ScenarioSet, test_profile};
+
+    #[test]
+    fn test_sensitivity_ranking() {
+        let profile = test_profile(ProfileType::Education, Currency::Usd, 120000.0, 40000.0);
+
+        let calculator = ROICalculator::default();
+        let analysis = calculator
+            .evaluate_sensitivity(&profile, None, 0.1)
+            .unwrap();
+        assert_eq!(analysis.params.len(), SensitivityParam::ALL.len());
+        assert_eq!(analysis.params[0].param, SensitivityParam::InitialSalary);
+        assert!(
+            analysis
+                .params
+                .windows(2)
+                .all(|w| w[0].net_worth_swing() >= w[1].net_worth_swing())
+        );
+
+        let salary = &analysis.params[0];
+        assert!(salary.low.final_net_worth < analysis.base.final_net_worth);
+        assert!(salary.high.final_net_worth > analysis.base.final_net_worth);
+        assert!(
+            calculator
+                .evaluate_sensitivity(&profile, None, 0.0)
+                .is_err()
+        );
+
+        // 按地区税表计税时，税率扰动缩放税表税负
+        let mut texas = profile.clone();
+        texas.location.region = Some("texas".to_string());
+        let analysis = calculator.evaluate_sensitivity(&texas, None, 0.1).unwrap();
+        let tax = analysis
+            .params
+            .iter()
+            .find(|p| p.param == SensitivityParam::TaxRate)
+            .unwrap();
+        assert!(tax.net_worth_swing() > 0.0);
+        assert!(tax.high.final_net_worth < tax.low.final_net_worth);
+
+        // 地区税表下不能直接设定税率
+        let mut scenario = Scenario::new("高税率", 1.0);
+        scenario.tax_rate = Some(ParamOverride::Set(0.3));
+        let scenarios = ScenarioSet {
+            scenarios: vec![scenario],
+        };
+        assert!(
+            calculator
+                .evaluate_scenarios(&texas, Some(&scenarios))
+                .is_err()
+        );
+        assert!(
+            calculator
+                .evaluate_scenarios(&profile, Some(&scenarios))
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::ROICalculator;
+use crate::{
+    config::{CostsConfig, Country, LivingCostEstimator, SchoolCatalog, SchoolEntry},
+    models::{
+        CostCategory, CostLevel, FinancialParams, Location, Profile, ProfileType, WorkParams,
+    },
+};
+
+/// 计算机会成本时作为对照的国内城市（regions.yaml 中的key）
+pub const BASELINE_CITY: &str = "shanghai";
+
+/// 学习时长
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StudyDuration {
+    pub years:  u32,
+    pub months: u32,
+}
+
+impl StudyDuration {
+    pub fn new(years: u32, months: u32) -> Self { Self { years, months } }
+
+    /// 总月数
+    pub fn total_months(&self) -> u32 { self.years * 12 + self.months }
+
+    /// 折算为年（可为小数）
+    pub fn as_years(&self) -> f64 { self.total_months() as f64 / 12.0 }
+}
+
+impl fmt::Display for StudyDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}年{}个月", self.years, self.months)
+    }
+}
+
+/// 单个费用类别的合计（USD）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CategoryCost {
+    pub category:   String,
+    pub amount_usd: f64,
+}
+
+/// 单所学校的留学成本估算（USD）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StudyCostEstimate {
+    pub school:               String,
+    pub short_name:           String,
+    pub country:              Country,
+    pub region:               String,
+    pub duration:             StudyDuration,
+    pub cost_level:           CostLevel,
+    pub tuition_usd:          f64,
+    pub living_cost_usd:      f64,
+    /// 学费和生活费以外的附加费用
+    pub additional_usd:       f64,
+    pub categories:           Vec<CategoryCost>,
+    /// 放弃国内工作的机会成本（学习期内国内路径积累的净资产）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opportunity_cost_usd: Option<f64>,
+}
+
+impl StudyCostEstimate {
+    /// 基础成本：学费 + 生活费
+    pub fn base_cost(&self) -> f64 { self.tuition_usd + self.living_cost_usd }
+
+    /// 总留学成本
+    pub fn total(&self) -> f64 { self.base_cost() + self.additional_usd }
+
+    /// 计入机会成本后的总经济成本
+    pub fn total_with_opportunity_cost(&self) -> f64 {
+        self.total() + self.opportunity_cost_usd.unwrap_or(0.0)
+    }
+}
+
+/// 基于YAML配置的留学成本计算器
+#[derive(Debug, Clone)]
+pub struct StudyCostCalculator {
+    catalog: SchoolCatalog,
+    costs:   CostsConfig,
+    living:  LivingCostEstimator,
+}
+
+impl StudyCostCalculator {
+    pub fn new(catalog: SchoolCatalog, costs: CostsConfig, living: LivingCostEstimator) -> Self {
+        Self {
+            catalog,
+            costs,
+            living,
+        }
+    }
+
+    /// 从 config/ 目录加载全部配置
+    pub fn load_default() -> Result<Self> {
+        Ok(Self::new(
+            SchoolCatalog::load_default()?,
+            CostsConfig::load_default()?,
+            LivingCostEstimator::load_default()?,
+        ))
+    }
+
+    pub fn catalog(&self) -> &SchoolCatalog { &self.catalog }
+
+    pub fn living_cost_estimator(&self) -> &LivingCostEstimator { &self.living }
+
+    /// 学校所在国家货币兑美元汇率
+    fn to_usd(&self, entry: &SchoolEntry) -> Result<f64> {
+        let currency = entry.country.currency();
+        self.living
+            .config()
+            .exchange_rates
+            .to_usd(currency)
+            .ok_or_else(|| anyhow::anyhow!("缺少汇率: {}", currency))
+    }
+
+    /// 估算指定学校在给定学习时长和成本档位下的留学成本
+    pub fn estimate(
+        &self,
+        entry: &SchoolEntry,
+        duration: StudyDuration,
+        level: CostLevel,
+    ) -> Result<StudyCostEstimate> {
+        let months = duration.total_months();
+        let factors = self.costs.cost_levels.factors(level);
+        let breakdown =
+            factors.adjust_breakdown(&self.costs.school_breakdown(entry, self.to_usd(entry)?));
+        let living = self
+            .living
+            .estimate(&entry.school.region)
+            .with_context(|| format!("无法估算 {} 的生活成本", entry.school.name))?;
+
+        let mut by_category: BTreeMap<CostCategory, f64> = BTreeMap::new();
+        for item in &breakdown.items {
+            *by_category.entry(item.category).or_default() += item.cost_for_months(months);
+        }
+
+        let tuition_usd = by_category.remove(&CostCategory::Tuition).unwrap_or(0.0);
+        let shares = &self.living.config().category_shares;
+        let living_cost_usd =
+            living.total() * factors.living_cost_factor(shares) * duration.as_years();
+        let additional_usd = by_category.values().sum();
+
+        let mut categories = vec![
+            CategoryCost {
+                category:   CostCategory::Tuition.to_string(),
+                amount_usd: tuition_usd,
+            },
+            CategoryCost {
+                category:   "生活费".to_string(),
+                amount_usd: living_cost_usd,
+            },
+        ];
+        categories.extend(
+            by_category
+                .into_iter()
+                .map(|(category, amount_usd)| CategoryCost {
+                    category: category.to_string(),
+                    amount_usd,
+                }),
+        );
+
+        Ok(StudyCostEstimate {
+            school: entry.school.name.clone(),
+            short_name: entry.school.short_name.clone(),
+            country: entry.country,
+            region: living.region_name,
+            duration,
+            cost_level: level,
+            tuition_usd,
+            living_cost_usd,
+            additional_usd,
+            categories,
+            opportunity_cost_usd: None,
+        })
+    }
+
+    /// 估算多所学校并按总成本从低到高排序
+    pub fn rank<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a SchoolEntry>,
+        duration: StudyDuration,
+        level: CostLevel,
+    ) -> Result<Vec<StudyCostEstimate>> {
+        let mut estimates = entries
+            .into_iter()
+            .map(|entry| self.estimate(entry, duration, level))
+            .collect::<Result<Vec<_>>>()?;
+        estimates.sort_by(|a, b| a.total().total_cmp(&b.total()));
+        Ok(estimates)
+    }
+
+    /// 以学校目录中的学校为基础的教育路径Profile
+    ///
+    /// 成本按 costs.yaml 分项计入（学费和附加费用在发生时支付），
+    /// 生活成本取学校所在地区的估算，`initial_salary_usd` 为毕业后的年薪，
+    /// 增长率和税率与 `profile add` 的默认值一致
+    pub fn school_profile(&self, name: &str, initial_salary_usd: f64) -> Result<Profile> {
+        let entry = self
+            .catalog
+            .find(name)
+            .ok_or_else(|| anyhow::anyhow!("未找到学校: '{}'", name))?;
+        let living = self
+            .living
+            .estimate(&entry.school.region)
+            .with_context(|| format!("无法估算 {} 的生活成本", entry.school.name))?;
+
+        Ok(entry.to_profile(
+            FinancialParams {
+                initial_salary_usd,
+                salary_growth_rate: 0.03,
+                living_cost_usd: living.total(),
+                living_cost_growth: 0.025,
+                tax_rate: 0.25,
+            },
+            self.costs.school_cost_params(entry, self.to_usd(entry)?),
+        ))
+    }
+
+    /// 国内对照工作路径的Profile
+    pub fn baseline_profile(&self) -> Result<Profile> {
+        let config = self.living.config();
+        let city = config
+            .china_city(BASELINE_CITY)
+            .ok_or_else(|| anyhow::anyhow!("缺少国内城市配置: '{}'", BASELINE_CITY))?;
+        let to_usd = config
+            .exchange_rates
+            .to_usd(&city.currency)
+            .ok_or_else(|| anyhow::anyhow!("缺少汇率: {}", city.currency))?;
+
+        Ok(Profile::new(
+            format!("{}工作", city.name),
+            ProfileType::Work,
+            Location {
+                country:  "China".to_string(),
+                city:     Some(city.name.clone()),
+                currency: city.currency.clone(),
+                region:   Some(BASELINE_CITY.to_string()),
+            },
+            WorkParams {
+                start_delay:    0,
+                duration_limit: None,
+            },
+            city.financial_params(to_usd),
+        ))
+    }
+
+    /// 学习期内放弃国内工作的机会成本
+    ///
+    /// 取国内路径在学习期结束时的净资产，不足整年的部分按月线性折算
+    pub fn opportunity_cost(
+        &self,
+        duration: StudyDuration,
+        calculator: &ROICalculator,
+    ) -> Result<f64> {
+        let total_years = duration.total_months().div_ceil(12);
+        if total_years == 0 {
+            return Ok(0.0);
+        }
+
+        let calculator = ROICalculator {
+            total_years,
+            ..calculator.clone()
+        };
+        let path = calculator.calculate_profile_path(&self.baseline_profile()?);
+        let net_worth_at = |year: u32| {
+            if year == 0 {
+                0.0
+            } else {
+                path[year as usize - 1].net_worth
+            }
+        };
+
+        let full_years = duration.total_months() / 12;
+        let remaining = (duration.total_months() % 12) as f64 / 12.0;
+        let base = net_worth_at(full_years);
+        Ok(if remaining > 0.0 {
+            base + (net_worth_at(full_years + 1) - base) * remaining
+        } else {
+            base
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BUNDLED_COSTS_YAML, BUNDLED_REGIONS_YAML, BUNDLED_SCHOOLS_YAML, RegionsConfig,
+    };
+
+    #[test]
+    fn test_school_profile() {
+        let calculator = StudyCostCalculator::new(
+            SchoolCatalog::from_yaml_str(BUNDLED_SCHOOLS_YAML).unwrap(),
+            CostsConfig::from_yaml_str(BUNDLED_COSTS_YAML).unwrap(),
+            LivingCostEstimator::new(RegionsConfig::from_yaml_str(BUNDLED_REGIONS_YAML).unwrap()),
+        );
+
+        let profile = calculator.school_profile("tamu", 90000.0).unwrap();
+        assert_eq!(profile.profile_type, ProfileType::Education);
+        assert_eq!(profile.location.region.as_deref(), Some("texas"));
+        assert_eq!(profile.work_params.start_delay, 2);
+        assert_eq!(profile.financial_params.initial_salary_usd, 90000.0);
+        assert_eq!(
+            profile.financial_params.living_cost_usd,
+            calculator.living.estimate("texas").unwrap().total()
+        );
+        let cost = profile.cost_params.unwrap();
+        assert!(cost.breakdown.is_some());
+        assert_eq!(
+            cost.total_cost_usd,
+            calculator
+                .costs
+                .school_cost_params(calculator.catalog.find("tamu").unwrap(), 1.0)
+                .total_cost_usd
+        );
+        assert!(calculator.school_profile("atlantis", 90000.0).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use super::ROICalculator;
+use crate::models::{Currency, PathCalculationParams, Profile, TaxBreakdown};
+
+impl ROICalculator {
+    /// 地区税表下年薪 `salary`（USD）的税负，地区未配置税表时返回None
+    ///
+    /// 加拿大、国内城市分别按加元、人民币计算后换算回美元，
+    /// `children` 为国内子女专项附加扣除的子女数
+    pub fn regional_tax(
+        &self,
+        salary: f64,
+        region: Option<&str>,
+        children: usize,
+    ) -> Option<TaxBreakdown> {
+        self.regional_tax_for_months(Earnings::new(salary, 12.0), region, children)
+    }
+
+    /// 当年累计收入 `earned` 的税负
+    ///
+    /// 国内社保公积金的缴费基数上下限按工作月数折算
+    fn regional_tax_for_months(
+        &self,
+        earned: Earnings,
+        region: Option<&str>,
+        children: usize,
+    ) -> Option<TaxBreakdown> {
+        let region = region?;
+        let salary = earned.income;
+        if let Some(state) = self.us_tax.state(region) {
+            return Some(self.us_tax.breakdown(salary, state));
+        }
+        if let Some(province) = self.canada_tax.province(region) {
+            let cad = self.rates.usd_per_unit(Currency::Cad);
+            return Some(
+                self.canada_tax
+                    .breakdown(salary / cad, province)
+                    .converted(cad),
+            );
+        }
+        let city = self.china_tax.city(region)?;
+        let cny = self.rates.usd_per_unit(Currency::Cny);
+        Some(
+            self.china_tax
+                .breakdown(salary / cny, earned.months, city, children)
+                .converted(cny),
+        )
+    }
+
+    /// 地区是否配置了税表
+    pub fn has_regional_tax(&self, region: Option<&str>) -> bool {
+        self.regional_tax(0.0, region, 0).is_some()
+    }
+
+    /// 税率说明：地区配置了税表时显示首年实际税率，否则显示统一税率
+    pub fn tax_label(&self, profile: &Profile) -> String {
+        let salary = profile.financial_params.initial_salary_usd;
+        match self.regional_tax(
+            salary,
+            profile.location.region.as_deref(),
+            profile.household.minor_children(1),
+        ) {
+            Some(tax) => format!(
+                "累进税表 (首年实际 {:.1}%)",
+                tax.effective_rate(salary) * self.regional_tax_scale * 100.0
+            ),
+            None => format!("{:.1}%", profile.financial_params.tax_rate * 100.0),
+        }
+    }
+
+    /// 第 `year` 年的实际税率：地区配置了税表时按累进税率计算，
+    /// 否则使用Profile的统一税率
+    pub fn effective_tax_rate(
+        &self,
+        salary: f64,
+        params: &PathCalculationParams,
+        year: u32,
+    ) -> f64 {
+        let children = params.household.minor_children(year);
+        self.regional_tax(salary, params.region.as_deref(), children)
+            .map_or(params.tax_rate, |tax| {
+                tax.effective_rate(salary) * self.regional_tax_scale
+            })
+    }
+
+    /// 第 `year` 年已取得税前收入 `earned` 后，再取得 `income` 应缴的税
+    ///
+    /// 地区配置了税表时按当年累计收入计算累进税额的增量（乘以税负缩放系数），
+    /// 非整年工作或求职、失业的年份按实际收入计税，否则使用Profile的统一税率
+    pub fn tax_on_earnings(
+        &self,
+        earned: Earnings,
+        income: Earnings,
+        params: &PathCalculationParams,
+        year: u32,
+    ) -> f64 {
+        if income.income <= 0.0 {
+            return 0.0;
+        }
+        let children = params.household.minor_children(year);
+        let tax = |earned: Earnings| {
+            self.regional_tax_for_months(earned, params.region.as_deref(), children)
+                .map(|tax| tax.total() * self.regional_tax_scale)
+        };
+        match tax(earned + income) {
+            Some(total) if earned.income > 0.0 => total - tax(earned).unwrap_or(0.0),
+            Some(total) => total,
+            None => income.income * params.tax_rate,
+        }
+    }
+}
+
+/// 纳税年度内的税前收入及取得收入的月数（在职概率折算的月数）
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Earnings {
+    pub income: f64,
+    pub months: f64,
+}
+
+impl Earnings {
+    pub fn new(income: f64, months: f64) -> Self { Self { income, months } }
+}
+
+impl std::ops::Add for Earnings {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.income + other.income, self.months + other.months)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculator::{PathYearlyData, RateShocks},
+        models::{EmploymentRisk, Money, Profile, ProfileType, Timeline, test_profile},
+    };
+
+    fn work_profile(region: Option<&str>) -> Profile {
+        let mut profile = test_profile(ProfileType::Work, Currency::Usd, 150000.0, 40000.0);
+        profile.location.region = region.map(str::to_string);
+        profile
+    }
+
+    #[test]
+    fn test_regional_tax() {
+        let calculator = ROICalculator::default();
+        let texas = calculator.calculate_profile_path(&work_profile(Some("texas")));
+        let california =
+            calculator.calculate_profile_path(&work_profile(Some("california_bay_area")));
+        let flat = calculator.calculate_profile_path(&work_profile(None));
+
+        // 未配置地区时沿用统一税率
+        assert!((flat[0].net_income_usd - 150000.0 * 0.75).abs() < 1e-6);
+        assert!(texas[0].net_income_usd > california[0].net_income_usd);
+
+        // 薪资增长后实际税率上升
+        let rate = |data: &PathYearlyData| 1.0 - data.net_income_usd / data.income_usd;
+        assert!(rate(&california[9]) > rate(&california[0]));
+
+        // 1月开始、求职6个月的第一年按实际收入计税，实际税率低于整年工作的第二年
+        let searching = calculator.calculate_profile_path(
+            &work_profile(Some("california_bay_area"))
+                .with_timeline(Timeline {
+                    start_month: 1,
+                    ..Timeline::default()
+                })
+                .with_employment(EmploymentRisk {
+                    job_search: vec!["6".parse().unwrap()],
+                    layoff:     None,
+                }),
+        );
+        assert!((searching[0].income_usd - 75000.0).abs() < 1e-6);
+        assert!(rate(&searching[0]) < rate(&california[0]));
+        let full_year = calculator
+            .regional_tax(searching[1].income_usd, Some("california_bay_area"), 0)
+            .unwrap();
+        assert!(
+            (searching[1].income_usd - searching[1].net_income_usd - full_year.total()).abs()
+                < 1e-6
+        );
+
+        // 9月开始工作：1月起重新累计纳税年度收入，与首月税负相同
+        let months = calculator.calculate_monthly_path(
+            &work_profile(Some("california_bay_area")),
+            &RateShocks::default(),
+        );
+        assert_eq!(months[4].net_income_usd, months[0].net_income_usd);
+        assert!(months[3].net_income_usd < months[0].net_income_usd);
+
+        // 同样的税前收入，卡尔加里的税后收入高于多伦多
+        let calgary = calculator
+            .regional_tax(150000.0, Some("alberta"), 0)
+            .unwrap();
+        let toronto = calculator
+            .regional_tax(150000.0, Some("ontario_major"), 0)
+            .unwrap();
+        assert!(calgary.total() < toronto.total());
+
+        // 上海年薪37.5万元：个税加社保公积金低于旧的28.2%综合税率
+        let salary = Money::new(375000.0, Currency::Cny).to_usd(&calculator.rates);
+        let shanghai = calculator
+            .regional_tax(salary, Some("shanghai"), 0)
+            .unwrap();
+        assert!(shanghai.effective_rate(salary) < 0.282);
+
+        // 上海年薪20万元求职9个月：第一年只工作3个月，社保缴费基数下限按3个月折算，
+        // 实际税率不高于整年工作
+        let salary = Money::new(200000.0, Currency::Cny).to_usd(&calculator.rates);
+        let mut profile = work_profile(Some("shanghai"));
+        profile.financial_params.initial_salary_usd = salary;
+        let full = calculator.calculate_profile_path(&profile);
+        let partial = calculator.calculate_profile_path(&profile.with_employment(EmploymentRisk {
+            job_search: vec!["9".parse().unwrap()],
+            layoff:     None,
+        }));
+        assert!((partial[0].income_usd - salary / 4.0).abs() < 1e-6);
+        assert!(rate(&partial[0]) <= rate(&full[0]) + 1e-9);
+        assert!(
+            calculator
+                .regional_tax(salary, Some("atlantis"), 0)
+                .is_none()
+        );
+    }
+}
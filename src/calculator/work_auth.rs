@@ -0,0 +1,101 @@
+use anyhow::Result;
+
+use super::{PathSwitch, ProfilePath, ROICalculator, RateShocks, ScenarioAnalysis, ScenarioResult};
+use crate::models::Profile;
+
+impl ROICalculator {
+    /// 按H-1B抽签结果分支计算：中签则一直留美工作，全部未中签则在OPT
+    /// （含STEM延期）到期后转入 `fallback`，期望值按各分支概率加权
+    pub fn evaluate_work_authorization(
+        &self,
+        profile: &Profile,
+        fallback: &Profile,
+    ) -> Result<ScenarioAnalysis> {
+        let auth = profile
+            .work_authorization
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Profile '{}' 未设置工作许可", profile.name))?;
+        auth.validate()?;
+
+        let shocks = RateShocks::default();
+        let switch = PathSwitch {
+            after_month: profile.to_path_params().work_delay_months + auth.authorized_months(),
+            profile:     fallback,
+        };
+        let stay = self.profile_path(profile);
+        let months = self.calculate_switched_path(profile, Some(switch), &shocks);
+        let fallback_path = ProfilePath {
+            name: format!("{} → {}", profile.name, fallback.name),
+            data: self.yearly_path(&months, &shocks),
+            ..stay.clone()
+        };
+        let wins: Vec<String> = auth
+            .win_probabilities()
+            .iter()
+            .enumerate()
+            .map(|(i, probability)| format!("第{}次 {:.1}%", i + 1, probability * 100.0))
+            .collect();
+
+        Ok(ScenarioAnalysis {
+            profile:   profile.name.clone(),
+            scenarios: vec![
+                ScenarioResult {
+                    name:        "H-1B中签".to_string(),
+                    probability: 1.0 - auth.failure_probability(),
+                    summary:     wins.join(" / "),
+                    path:        stay,
+                },
+                ScenarioResult {
+                    name:        format!("未中签，转入{}", fallback.name),
+                    probability: auth.failure_probability(),
+                    summary:     format!("工作许可 {} 个月到期后转入", auth.authorized_months()),
+                    path:        fallback_path,
+                },
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, test_profile};
+
+    #[test]
+    fn test_work_authorization_branches() {
+        let calculator = ROICalculator::default();
+        let shanghai = Profile {
+            name: "上海工作".to_string(),
+            ..test_profile(ProfileType::Work, Currency::Cny, 40000.0, 15000.0)
+        };
+        let us = Profile {
+            name: "MCS".to_string(),
+            ..test_profile(ProfileType::Education, Currency::Usd, 120000.0, 36000.0)
+        }
+        .with_work_authorization("上海工作:0.3,0.3,0.3".parse().unwrap());
+
+        let analysis = calculator
+            .evaluate_work_authorization(&us, &shanghai)
+            .unwrap();
+        let [win, lose] = analysis.scenarios.as_slice() else {
+            panic!("应有两个分支");
+        };
+        assert!((lose.probability - 0.343).abs() < 1e-9);
+        assert!((win.probability + lose.probability - 1.0).abs() < 1e-9);
+
+        // 学习2年加工作许可3年内两个分支相同，之后未中签分支转入上海的收入
+        assert_eq!(win.path.data[4].net_worth, lose.path.data[4].net_worth);
+        assert!((lose.path.data[5].income_usd - 40000.0).abs() < 1e-6);
+        assert!(lose.final_net_worth() < win.final_net_worth());
+
+        let expected =
+            win.probability * win.final_net_worth() + lose.probability * lose.final_net_worth();
+        assert!((analysis.expected_net_worth() - expected).abs() < 1e-6);
+
+        assert!(
+            calculator
+                .evaluate_work_authorization(&shanghai, &shanghai)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,891 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::Serialize;
+use tabled::{Table, Tabled, builder::Builder, settings::Style};
+
+use super::{OutputFormat, base_calculator, find_profile, print_structured};
+use crate::{
+    calculator::{
+        BaselineComparison, FxSensitivity, MonteCarloConfig, MonteCarloResult, MonteCarloSimulator,
+        PathYearlyData, ProfileComparisonData, ProfilePath, ProfileRanking, ProfileSimulation,
+        ROICalculator, RateDistribution, ScenarioAnalysis, ScenarioResult, SensitivityAnalysis,
+        SensitivityOutcome,
+    },
+    db::DatabaseManager,
+    models::{CostLevel, Currency, FundingMode, FxPath, Profile, ScenarioSet},
+};
+
+/// 多Profile分析的公共参数
+#[derive(Debug, Clone, Args)]
+pub struct ProfileSelection {
+    /// 参与分析的Profile（ID、ID前缀或名称）
+    #[arg(required = true)]
+    pub targets:       Vec<String>,
+    /// 基准Profile（如留在国内工作），其余Profile按相对它的增量报告
+    #[arg(short, long)]
+    pub baseline:      Option<String>,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:         u32,
+    /// 统一使用的成本档位，不指定时使用各Profile自带的档位
+    #[arg(short = 'l', long)]
+    pub cost_level:    Option<CostLevel>,
+    /// 计算NPV和折现回收期的年折现率
+    #[arg(long, default_value_t = 0.05)]
+    pub discount_rate: f64,
+    /// 报告货币 [USD|CNY|CAD]
+    #[arg(short = 'c', long, default_value_t)]
+    pub currency:      Currency,
+    /// 汇率走势 [constant|trend:<USD/CNY年变动>[,<CAD/CNY年变动>]]
+    #[arg(long, default_value_t)]
+    pub fx:            FxPath,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:          bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:       FundingMode,
+}
+
+impl ProfileSelection {
+    /// 按分析年限、成本档位、折现率、报告货币和金额口径配置的计算器
+    pub fn calculator(&self) -> Result<ROICalculator> {
+        Ok(ROICalculator {
+            total_years: self.years,
+            cost_level: self.cost_level,
+            discount_rate: self.discount_rate,
+            reporting_currency: self.currency,
+            fx_path: self.fx,
+            real_terms: self.real,
+            funding_mode: self.funding,
+            ..base_calculator()?
+        })
+    }
+
+    /// 查找全部Profile，指定基准时基准Profile排在第一位
+    pub fn resolve(&self, db: &mut DatabaseManager) -> Result<Vec<Profile>> {
+        let mut profiles = self
+            .targets
+            .iter()
+            .map(|target| find_profile(db, target))
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(target) = &self.baseline {
+            let baseline = find_profile(db, target)?;
+            profiles.retain(|profile| profile.id != baseline.id);
+            profiles.insert(0, baseline);
+        }
+        if profiles.len() < 2 {
+            return Err(anyhow::anyhow!("至少需要两个不同的Profile进行对比"));
+        }
+        Ok(profiles)
+    }
+
+    pub fn has_baseline(&self) -> bool { self.baseline.is_some() }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileCompareArgs {
+    #[command(flatten)]
+    pub selection: ProfileSelection,
+    /// 净资产对比图输出路径
+    #[arg(long)]
+    pub chart:     Option<String>,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:    OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileSimulateArgs {
+    #[command(flatten)]
+    pub selection:     ProfileSelection,
+    /// 模拟次数 [默认: 5000]
+    #[arg(short, long)]
+    pub runs:          Option<usize>,
+    /// 随机种子 [默认: 42]
+    #[arg(long)]
+    pub seed:          Option<u64>,
+    /// 年投资回报率分布，默认 normal:0.15
+    /// [fixed|normal:<标准差>|uniform:<半宽>]
+    #[arg(long)]
+    pub market_return: Option<RateDistribution>,
+    /// 年薪资增长率分布，默认 normal:0.02
+    #[arg(long)]
+    pub salary_growth: Option<RateDistribution>,
+    /// 年通胀分布，默认 normal:0.01
+    #[arg(long)]
+    pub inflation:     Option<RateDistribution>,
+    /// USD/CNY、CAD/CNY年变动分布，默认 fixed（汇率按 --fx 走势变化）
+    #[arg(long)]
+    pub fx_volatility: Option<RateDistribution>,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:        OutputFormat,
+}
+
+impl ProfileSimulateArgs {
+    fn config(&self) -> MonteCarloConfig {
+        let default = MonteCarloConfig::default();
+        MonteCarloConfig {
+            runs:          self.runs.unwrap_or(default.runs),
+            seed:          self.seed.unwrap_or(default.seed),
+            market_return: self.market_return.unwrap_or(default.market_return),
+            salary_growth: self.salary_growth.unwrap_or(default.salary_growth),
+            inflation:     self.inflation.unwrap_or(default.inflation),
+            fx:            self.fx_volatility.unwrap_or(default.fx),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileScenariosArgs {
+    /// 参与分析的Profile（ID、ID前缀或名称）
+    #[arg(required = true)]
+    pub targets:    Vec<String>,
+    /// 使用的情景集合 [standard|<JSON/YAML文件>]，默认使用各Profile附带的情景
+    #[arg(short, long, value_parser = parse_scenario_set)]
+    pub scenarios:  Option<ScenarioSet>,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:      u32,
+    /// 统一使用的成本档位，不指定时使用各Profile自带的档位
+    #[arg(short = 'l', long)]
+    pub cost_level: Option<CostLevel>,
+    /// 报告货币 [USD|CNY|CAD]
+    #[arg(short = 'c', long, default_value_t)]
+    pub currency:   Currency,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:       bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:    FundingMode,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:     OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileSensitivityArgs {
+    /// Profile ID、ID前缀或名称
+    pub target:     String,
+    /// 基准Profile，提供时回本年份相对基准计算，否则为净资产转正的年份
+    #[arg(short, long)]
+    pub baseline:   Option<String>,
+    /// 每个参数上下扰动的百分比
+    #[arg(short, long, default_value_t = 10.0)]
+    pub delta:      f64,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:      u32,
+    /// 统一使用的成本档位，不指定时使用Profile自带的档位
+    #[arg(short = 'l', long)]
+    pub cost_level: Option<CostLevel>,
+    /// 报告货币 [USD|CNY|CAD]
+    #[arg(short = 'c', long, default_value_t)]
+    pub currency:   Currency,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:       bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:    FundingMode,
+    /// 输出龙卷风图，不指定路径时为 <Profile名称>_sensitivity.png
+    #[arg(long, value_name = "PATH")]
+    pub chart:      Option<Option<String>>,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:     OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileLevelsArgs {
+    /// Profile ID、ID前缀或名称
+    pub target:   String,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:    u32,
+    /// 报告货币 [USD|CNY|CAD]
+    #[arg(short = 'c', long, default_value_t)]
+    pub currency: Currency,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:     bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:  FundingMode,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:   OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileFxArgs {
+    /// 参与分析的Profile（ID、ID前缀或名称）
+    #[arg(required = true)]
+    pub targets:    Vec<String>,
+    /// USD/CNY、CAD/CNY在分析期内上下变动的百分比
+    #[arg(short, long, default_value_t = 20.0)]
+    pub delta:      f64,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:      u32,
+    /// 统一使用的成本档位，不指定时使用各Profile自带的档位
+    #[arg(short = 'l', long)]
+    pub cost_level: Option<CostLevel>,
+    /// 报告货币，默认以人民币衡量结果
+    #[arg(short = 'c', long, default_value_t = Currency::Cny)]
+    pub currency:   Currency,
+    /// 基准汇率走势 [constant|trend:<USD/CNY年变动>[,<CAD/CNY年变动>]]
+    #[arg(long, default_value_t)]
+    pub fx:         FxPath,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:       bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:    FundingMode,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:     OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileVisaArgs {
+    /// 设置了工作许可的Profile（ID、ID前缀或名称）
+    pub target:     String,
+    /// 覆盖各次抽签的中签率，逗号分隔，如 0.3,0.3,0.3
+    #[arg(long, value_delimiter = ',')]
+    pub odds:       Option<Vec<f64>>,
+    /// 覆盖未中签时转入的Profile（ID、ID前缀或名称）
+    #[arg(long)]
+    pub fallback:   Option<String>,
+    /// 分析年限
+    #[arg(short, long, default_value_t = 10)]
+    pub years:      u32,
+    /// 统一使用的成本档位，不指定时使用各Profile自带的档位
+    #[arg(short = 'l', long)]
+    pub cost_level: Option<CostLevel>,
+    /// 报告货币 [USD|CNY|CAD]
+    #[arg(short = 'c', long, default_value_t)]
+    pub currency:   Currency,
+    /// 汇率走势 [constant|trend:<USD/CNY年变动>[,<CAD/CNY年变动>]]
+    #[arg(long, default_value_t)]
+    pub fx:         FxPath,
+    /// 以实际值（分析开始时的价格）报告，按报告货币所在国家的通胀率折算
+    #[arg(long)]
+    pub real:       bool,
+    /// 资助计入方式 [confirmed|expected|none]，expected 按获得概率计入期望值
+    #[arg(long, default_value_t)]
+    pub funding:    FundingMode,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:     OutputFormat,
+}
+
+/// 解析情景集合参数：`standard` 为标准情景，`none`
+/// 为清空，其余视为JSON/YAML文件路径
+pub fn parse_scenario_set(value: &str) -> Result<ScenarioSet> {
+    let scenarios = match value {
+        "standard" => ScenarioSet::standard(),
+        "none" => return Ok(ScenarioSet::default()),
+        path => {
+            let path = Path::new(path);
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("读取情景文件失败: {}", path.display()))?;
+            if path.extension().is_some_and(|ext| ext == "json") {
+                serde_json::from_str(&content)?
+            } else {
+                serde_yaml::from_str(&content)?
+            }
+        }
+    };
+    scenarios.validate()?;
+    Ok(scenarios)
+}
+
+/// 多Profile对比的结构化输出
+#[derive(Serialize)]
+struct ComparisonReport {
+    ranking:  Vec<ProfileRanking>,
+    yearly:   Vec<ProfileComparisonData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline: Option<Vec<BaselineComparison>>,
+    paths:    Vec<ProfilePath>,
+}
+
+/// 对比图默认文件名（不含扩展名）
+pub fn comparison_file_stem(profiles: &[Profile]) -> String {
+    if profiles.len() <= 3 {
+        profiles
+            .iter()
+            .map(|profile| profile.name.replace(' ', "_"))
+            .collect::<Vec<_>>()
+            .join("_vs_")
+    } else {
+        format!("{}_profiles", profiles.len())
+    }
+}
+
+/// profile compare 子命令
+pub fn run_profile_compare(db: &mut DatabaseManager, args: ProfileCompareArgs) -> Result<()> {
+    let profiles = args.selection.resolve(db)?;
+    let calculator = args.selection.calculator()?;
+    let baseline = args.selection.has_baseline();
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => {
+            calculator.analyze_profile_comparison(&profiles);
+            if baseline {
+                calculator.analyze_baseline_comparison(&profiles[0], &profiles[1..]);
+            }
+        }
+        format => {
+            let comparison = calculator.compare_profiles(&profiles);
+            print_structured(
+                &ComparisonReport {
+                    ranking:  comparison.ranking(),
+                    yearly:   comparison.yearly(),
+                    baseline: baseline.then(|| comparison.relative_to(0)),
+                    paths:    comparison.paths,
+                },
+                format,
+            )?;
+        }
+    }
+    if let Some(chart) = args.chart {
+        calculator.generate_profile_comparison_chart(&profiles, &chart)?;
+    }
+    Ok(())
+}
+
+/// 两个Profile之间的领先概率
+#[derive(Serialize)]
+struct ProbabilityAhead {
+    profile:     String,
+    other:       String,
+    probability: f64,
+}
+
+/// 蒙特卡洛模拟的结构化输出
+#[derive(Serialize)]
+struct SimulationReport {
+    config:        MonteCarloConfig,
+    profiles:      Vec<ProfileSimulation>,
+    probabilities: Vec<ProbabilityAhead>,
+}
+
+/// 需要报告的领先概率：指定基准时为各Profile跑赢基准，否则两两比较
+fn probability_pairs(result: &MonteCarloResult, baseline: bool) -> Vec<ProbabilityAhead> {
+    let count = result.profiles.len();
+    (0..count)
+        .flat_map(|a| (0..count).map(move |b| (a, b)))
+        .filter(|&(a, b)| if baseline { b == 0 && a != 0 } else { a != b })
+        .map(|(a, b)| ProbabilityAhead {
+            profile:     result.profiles[a].name.clone(),
+            other:       result.profiles[b].name.clone(),
+            probability: result.probability_ahead(a, b),
+        })
+        .collect()
+}
+
+/// profile simulate 子命令
+pub fn run_profile_simulate(db: &mut DatabaseManager, args: ProfileSimulateArgs) -> Result<()> {
+    let profiles = args.selection.resolve(db)?;
+    let simulator = MonteCarloSimulator::new(args.selection.calculator()?, args.config())?;
+    let result = simulator.run(&profiles);
+    let baseline = args.selection.has_baseline();
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => print_simulation(
+            simulator.calculator(),
+            simulator.config(),
+            &result,
+            baseline,
+        ),
+        format => print_structured(
+            &SimulationReport {
+                config:        simulator.config().clone(),
+                probabilities: probability_pairs(&result, baseline),
+                profiles:      result.profiles,
+            },
+            format,
+        )?,
+    }
+    Ok(())
+}
+
+fn print_simulation(
+    calculator: &ROICalculator,
+    config: &MonteCarloConfig,
+    result: &MonteCarloResult,
+    baseline: bool,
+) {
+    println!(
+        "=== 蒙特卡洛模拟：{} 次，种子 {}，{} ===",
+        result.runs,
+        result.seed,
+        calculator.value_label()
+    );
+    println!(
+        "投资回报: {}  薪资增长: {}  通胀: {}  汇率: {}\n",
+        config.market_return, config.salary_growth, config.inflation, config.fx
+    );
+
+    #[derive(Tabled)]
+    struct BandRow {
+        #[tabled(rename = "年份")]
+        year: u32,
+        #[tabled(rename = "P10")]
+        p10:  String,
+        #[tabled(rename = "P50")]
+        p50:  String,
+        #[tabled(rename = "P90")]
+        p90:  String,
+    }
+
+    for profile in &result.profiles {
+        let rows: Vec<BandRow> = profile
+            .bands
+            .iter()
+            .map(|band| BandRow {
+                year: band.year,
+                p10:  calculator.format_amount(band.p10),
+                p50:  calculator.format_amount(band.p50),
+                p90:  calculator.format_amount(band.p90),
+            })
+            .collect();
+        let mut table = Table::new(rows);
+        table.with(Style::modern());
+        println!("📈 {} 净资产分位数", profile.name);
+        println!("{}\n", table);
+    }
+
+    if baseline {
+        #[derive(Tabled)]
+        struct BaselineRow {
+            #[tabled(rename = "Profile")]
+            profile:     String,
+            #[tabled(rename = "最终P50")]
+            p50:         String,
+            #[tabled(rename = "跑赢基准概率")]
+            probability: String,
+        }
+
+        let rows: Vec<BaselineRow> = probability_pairs(result, true)
+            .into_iter()
+            .zip(&result.profiles[1..])
+            .map(|(pair, profile)| BaselineRow {
+                profile:     pair.profile,
+                p50:         profile
+                    .final_band()
+                    .map(|band| calculator.format_amount(band.p50))
+                    .unwrap_or_default(),
+                probability: format!("{:.1}%", pair.probability * 100.0),
+            })
+            .collect();
+        println!("🎯 相对基准「{}」", result.profiles[0].name);
+        println!("{}", Table::new(rows));
+    } else {
+        // 行Profile的最终净资产高于列Profile的概率
+        let mut builder = Builder::default();
+        builder.push_record(
+            std::iter::once("领先概率".to_string())
+                .chain(result.profiles.iter().map(|p| p.name.clone())),
+        );
+        for (a, profile) in result.profiles.iter().enumerate() {
+            builder.push_record(std::iter::once(profile.name.clone()).chain(
+                (0..result.profiles.len()).map(|b| {
+                    if a == b {
+                        "-".to_string()
+                    } else {
+                        format!("{:.1}%", result.probability_ahead(a, b) * 100.0)
+                    }
+                }),
+            ));
+        }
+        println!("🎯 行Profile最终净资产高于列Profile的概率");
+        println!("{}", builder.build());
+    }
+}
+
+/// 情景分析的结构化输出
+#[derive(Serialize)]
+struct ScenarioReport {
+    #[serde(flatten)]
+    analysis:           ScenarioAnalysis,
+    expected_net_worth: f64,
+    expected_roi:       Option<f64>,
+}
+
+/// profile scenarios 子命令
+pub fn run_profile_scenarios(db: &mut DatabaseManager, args: ProfileScenariosArgs) -> Result<()> {
+    let calculator = ROICalculator {
+        total_years: args.years,
+        cost_level: args.cost_level,
+        reporting_currency: args.currency,
+        real_terms: args.real,
+        funding_mode: args.funding,
+        ..base_calculator()?
+    };
+    let analyses = args
+        .targets
+        .iter()
+        .map(|target| {
+            let profile = find_profile(db, target)?;
+            calculator.evaluate_scenarios(&profile, args.scenarios.as_ref())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => analyses
+            .iter()
+            .for_each(|analysis| print_scenarios(&calculator, analysis, "情景分析")),
+        format => print_structured(
+            &analyses
+                .into_iter()
+                .map(|analysis| ScenarioReport {
+                    expected_net_worth: analysis.expected_net_worth(),
+                    expected_roi: analysis.expected_roi(),
+                    analysis,
+                })
+                .collect::<Vec<_>>(),
+            format,
+        )?,
+    }
+    Ok(())
+}
+
+fn print_scenarios(calculator: &ROICalculator, analysis: &ScenarioAnalysis, title: &str) {
+    #[derive(Tabled)]
+    struct ScenarioRow {
+        #[tabled(rename = "情景")]
+        name:        String,
+        #[tabled(rename = "概率")]
+        probability: String,
+        #[tabled(rename = "参数调整")]
+        summary:     String,
+        #[tabled(rename = "最终净资产")]
+        net_worth:   String,
+        #[tabled(rename = "ROI")]
+        roi:         String,
+    }
+
+    let format_roi = |roi: Option<f64>| {
+        roi.map(|roi| format!("{:.2}%", roi * 100.0))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let mut rows: Vec<ScenarioRow> = analysis
+        .scenarios
+        .iter()
+        .map(|scenario| ScenarioRow {
+            name:        scenario.name.clone(),
+            probability: format!("{:.0}%", scenario.probability * 100.0),
+            summary:     scenario.summary.clone(),
+            net_worth:   calculator.format_amount(scenario.final_net_worth()),
+            roi:         format_roi(scenario.roi()),
+        })
+        .collect();
+    rows.push(ScenarioRow {
+        name:        "期望值".to_string(),
+        probability: "100%".to_string(),
+        summary:     "概率加权".to_string(),
+        net_worth:   calculator.format_amount(analysis.expected_net_worth()),
+        roi:         format_roi(analysis.expected_roi()),
+    });
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!(
+        "🎲 {} {}（{}）",
+        analysis.profile,
+        title,
+        calculator.value_label()
+    );
+    println!("{}\n", table);
+}
+
+/// profile sensitivity 子命令
+pub fn run_profile_sensitivity(
+    db: &mut DatabaseManager,
+    args: ProfileSensitivityArgs,
+) -> Result<()> {
+    let profile = find_profile(db, &args.target)?;
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(|target| find_profile(db, target))
+        .transpose()?;
+    let calculator = ROICalculator {
+        total_years: args.years,
+        cost_level: args.cost_level,
+        reporting_currency: args.currency,
+        real_terms: args.real,
+        funding_mode: args.funding,
+        ..base_calculator()?
+    };
+    let analysis =
+        calculator.evaluate_sensitivity(&profile, baseline.as_ref(), args.delta / 100.0)?;
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => print_sensitivity(&calculator, &analysis),
+        format => print_structured(&analysis, format)?,
+    }
+    if let Some(chart) = args.chart {
+        let chart = chart.unwrap_or_else(|| {
+            format!(
+                "{}_sensitivity.png",
+                comparison_file_stem(std::slice::from_ref(&profile))
+            )
+        });
+        analysis.generate_tornado_chart(&chart)?;
+    }
+    Ok(())
+}
+
+fn print_sensitivity(calculator: &ROICalculator, analysis: &SensitivityAnalysis) {
+    #[derive(Tabled)]
+    struct SensitivityRow {
+        #[tabled(rename = "排名")]
+        rank:      usize,
+        #[tabled(rename = "参数")]
+        param:     &'static str,
+        #[tabled(rename = "下调后净资产")]
+        low:       String,
+        #[tabled(rename = "上调后净资产")]
+        high:      String,
+        #[tabled(rename = "波动幅度")]
+        swing:     String,
+        #[tabled(rename = "回本年份(下调/上调)")]
+        breakeven: String,
+    }
+
+    let breakeven = |outcome: &SensitivityOutcome| {
+        outcome
+            .breakeven_year
+            .map(|year| format!("第{}年", year))
+            .unwrap_or_else(|| "未回本".to_string())
+    };
+    let rows: Vec<SensitivityRow> = analysis
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| SensitivityRow {
+            rank:      i + 1,
+            param:     param.param.label(),
+            low:       calculator.format_amount(param.low.final_net_worth),
+            high:      calculator.format_amount(param.high.final_net_worth),
+            swing:     calculator.format_amount(param.net_worth_swing()),
+            breakeven: format!("{} / {}", breakeven(&param.low), breakeven(&param.high)),
+        })
+        .collect();
+
+    println!(
+        "=== {} 敏感性分析：各参数 ±{:.0}%，{} ===",
+        analysis.profile,
+        analysis.delta * 100.0,
+        calculator.value_label()
+    );
+    println!(
+        "未扰动: 最终净资产 {}，回本年份 {}{}\n",
+        calculator.format_amount(analysis.base.final_net_worth),
+        breakeven(&analysis.base),
+        analysis
+            .baseline
+            .as_ref()
+            .map(|name| format!("（相对基准「{}」）", name))
+            .unwrap_or_default()
+    );
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 单个成本档位下的逐年结果
+#[derive(Serialize)]
+struct CostLevelPath {
+    level:  CostLevel,
+    yearly: Vec<PathYearlyData>,
+}
+
+/// profile levels 子命令
+pub fn run_profile_levels(db: &mut DatabaseManager, args: ProfileLevelsArgs) -> Result<()> {
+    let profile = find_profile(db, &args.target)?;
+    let calculator = ROICalculator {
+        total_years: args.years,
+        reporting_currency: args.currency,
+        real_terms: args.real,
+        funding_mode: args.funding,
+        ..base_calculator()?
+    };
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => calculator.analyze_cost_levels(&profile),
+        format => {
+            let levels: Vec<CostLevelPath> = calculator
+                .compare_cost_levels(&profile)
+                .into_iter()
+                .map(|(level, yearly)| CostLevelPath { level, yearly })
+                .collect();
+            print_structured(&levels, format)?;
+        }
+    }
+    Ok(())
+}
+
+/// profile fx 子命令
+pub fn run_profile_fx(db: &mut DatabaseManager, args: ProfileFxArgs) -> Result<()> {
+    let calculator = ROICalculator {
+        total_years: args.years,
+        cost_level: args.cost_level,
+        reporting_currency: args.currency,
+        fx_path: args.fx,
+        real_terms: args.real,
+        funding_mode: args.funding,
+        ..base_calculator()?
+    };
+    let analyses = args
+        .targets
+        .iter()
+        .map(|target| {
+            let profile = find_profile(db, target)?;
+            Ok(calculator.evaluate_fx_moves(&profile, args.delta / 100.0))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => print_fx(&calculator, &analyses),
+        format => print_structured(&analyses, format)?,
+    }
+    Ok(())
+}
+
+fn print_fx(calculator: &ROICalculator, analyses: &[FxSensitivity]) {
+    println!(
+        "=== 汇率敏感性：以{}计，汇率走势 {} ===",
+        calculator.value_label(),
+        calculator.fx_path
+    );
+
+    let Some(first) = analyses.first() else {
+        return;
+    };
+    let mut builder = Builder::default();
+    builder.push_record(
+        ["Profile", "计价货币", "当前走势"]
+            .into_iter()
+            .map(str::to_string)
+            .chain(
+                first
+                    .moves
+                    .iter()
+                    .map(|outcome| format!("{} {:+.0}%", outcome.pair, outcome.change * 100.0)),
+            ),
+    );
+    for analysis in analyses {
+        builder.push_record(
+            [
+                analysis.profile.clone(),
+                analysis.currency.to_string(),
+                calculator.format_amount(analysis.base),
+            ]
+            .into_iter()
+            .chain(analysis.deltas().map(|(outcome, delta)| {
+                format!(
+                    "{} ({}{})",
+                    calculator.format_amount(outcome.final_net_worth),
+                    if delta >= 0.0 { "+" } else { "" },
+                    calculator.format_amount(delta)
+                )
+            })),
+        );
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+    println!("各列为期末净资产（括号内为相对当前走势的变化），汇率在分析期内逐年变动至期末幅度");
+}
+
+/// profile visa 子命令
+pub fn run_profile_visa(db: &mut DatabaseManager, args: ProfileVisaArgs) -> Result<()> {
+    let mut profile = find_profile(db, &args.target)?;
+    let auth = profile.work_authorization.as_mut().ok_or_else(|| {
+        anyhow::anyhow!("Profile '{}' 未设置工作许可（--work-auth）", profile.name)
+    })?;
+    if let Some(odds) = args.odds {
+        auth.lottery_odds = odds;
+    }
+    if let Some(fallback) = args.fallback {
+        auth.fallback = fallback;
+    }
+    auth.validate()?;
+    let fallback = find_profile(db, &auth.fallback)?;
+
+    let calculator = ROICalculator {
+        total_years: args.years,
+        cost_level: args.cost_level,
+        reporting_currency: args.currency,
+        fx_path: args.fx,
+        real_terms: args.real,
+        funding_mode: args.funding,
+        ..base_calculator()?
+    };
+    let analysis = calculator.evaluate_work_authorization(&profile, &fallback)?;
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => {
+            print_scenarios(&calculator, &analysis, "H-1B抽签分支");
+            print_branch_trajectories(&calculator, &analysis);
+        }
+        format => print_structured(
+            &ScenarioReport {
+                expected_net_worth: analysis.expected_net_worth(),
+                expected_roi: analysis.expected_roi(),
+                analysis,
+            },
+            format,
+        )?,
+    }
+    Ok(())
+}
+
+/// 各分支逐年净资产及概率加权期望
+fn print_branch_trajectories(calculator: &ROICalculator, analysis: &ScenarioAnalysis) {
+    let mut builder = Builder::default();
+    builder.push_record(
+        std::iter::once("年份".to_string())
+            .chain(analysis.scenarios.iter().map(|branch| branch.name.clone()))
+            .chain(std::iter::once("期望值".to_string())),
+    );
+    let years = analysis
+        .scenarios
+        .first()
+        .map_or(0, |branch| branch.path.data.len());
+    for i in 0..years {
+        let net_worth = |branch: &ScenarioResult| branch.path.data[i].net_worth;
+        builder.push_record(
+            std::iter::once(analysis.scenarios[0].path.data[i].year.to_string())
+                .chain(
+                    analysis
+                        .scenarios
+                        .iter()
+                        .map(|branch| calculator.format_amount(net_worth(branch))),
+                )
+                .chain(std::iter::once(
+                    calculator.format_amount(
+                        analysis
+                            .scenarios
+                            .iter()
+                            .map(|branch| branch.probability * net_worth(branch))
+                            .sum(),
+                    ),
+                )),
+        );
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("📈 各分支逐年净资产");
+    println!("{}", table);
+}
@@ -0,0 +1,147 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use super::ProfileCommand;
+use crate::{
+    calculator::StudyDuration,
+    config::{Country, SchoolTier},
+    models::CostLevel,
+};
+
+/// 留学成本与ROI计算器
+#[derive(Debug, Parser)]
+#[command(name = "roi", version, about = "留学成本与职业路径ROI计算器")]
+pub struct Cli {
+    /// 不指定子命令时进入交互式菜单
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// 计算单所学校的留学成本
+    Calculate(CalculateArgs),
+    /// 比较多所学校的留学成本
+    Compare(CompareArgs),
+    /// 列出可选学校
+    List(ListArgs),
+    /// 配置文件管理
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Profile管理（非交互）
+    #[command(subcommand)]
+    Profile(Box<ProfileCommand>),
+    /// 启动全屏仪表盘
+    Tui,
+}
+
+/// 目标国家
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CountryArg {
+    Us,
+    Canada,
+    Both,
+}
+
+impl CountryArg {
+    /// 对应的国家列表
+    pub fn countries(&self) -> Vec<Country> {
+        match self {
+            CountryArg::Us => vec![Country::Us],
+            CountryArg::Canada => vec![Country::Canada],
+            CountryArg::Both => vec![Country::Us, Country::Canada],
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CountryArg::Us => "美国",
+            CountryArg::Canada => "加拿大",
+            CountryArg::Both => "美国 + 加拿大",
+        }
+    }
+}
+
+/// 输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+    Report,
+}
+
+/// 学习时长与成本档位参数
+#[derive(Debug, Clone, Args)]
+pub struct StudyArgs {
+    /// 学习年数
+    #[arg(short, long, default_value_t = 2)]
+    pub years:      u32,
+    /// 额外月数
+    #[arg(short, long, default_value_t = 0)]
+    pub months:     u32,
+    /// 成本档位 [budget|standard|comfortable]
+    #[arg(short = 'l', long, default_value = "standard")]
+    pub cost_level: CostLevel,
+}
+
+impl StudyArgs {
+    pub fn duration(&self) -> StudyDuration { StudyDuration::new(self.years, self.months) }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CalculateArgs {
+    /// 目标国家
+    #[arg(short, long, value_enum)]
+    pub country:                  Option<CountryArg>,
+    /// 学校名称或简称
+    #[arg(short, long)]
+    pub school:                   String,
+    #[command(flatten)]
+    pub study:                    StudyArgs,
+    /// 包含机会成本分析
+    #[arg(long)]
+    pub include_opportunity_cost: bool,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:                   OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CompareArgs {
+    /// 目标国家
+    #[arg(short, long, value_enum, default_value_t = CountryArg::Both)]
+    pub country: CountryArg,
+    #[command(flatten)]
+    pub study:   StudyArgs,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:  OutputFormat,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ListArgs {
+    /// 目标国家
+    #[arg(short, long, value_enum, default_value_t = CountryArg::Both)]
+    pub country:     CountryArg,
+    /// 学校类型过滤 [target|safety|reach|basic|major_cities]
+    #[arg(short = 't', long)]
+    pub school_type: Option<SchoolTier>,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:      OutputFormat,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ConfigCommand {
+    /// 显示当前配置
+    Show,
+    /// 生成默认配置文件
+    Generate {
+        /// 覆盖已存在的配置文件
+        #[arg(long)]
+        force: bool,
+    },
+    /// 验证配置文件
+    Validate,
+}
@@ -0,0 +1,448 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tabled::{Table, Tabled, settings::Style};
+
+use super::{CalculateArgs, CompareArgs, ConfigCommand, ListArgs, OutputFormat};
+use crate::{
+    calculator::{ROICalculator, StudyCostCalculator, StudyCostEstimate},
+    config::{
+        BUNDLED_COSTS_YAML, BUNDLED_REGIONS_YAML, BUNDLED_SCHOOLS_YAML, BUNDLED_TAXES_YAML,
+        CostsConfig, Country, DEFAULT_COSTS_PATH, DEFAULT_REGIONS_PATH, DEFAULT_SCHOOLS_PATH,
+        DEFAULT_TAXES_PATH, RegionsConfig, SchoolCatalog, SchoolEntry, TaxConfig,
+    },
+    models::{CostLevel, CurrencyRates, Money},
+};
+
+/// 格式化美元金额（千分位）
+pub fn format_usd(amount: f64) -> String { Money::usd(amount).to_string() }
+
+/// 使用 regions.yaml 汇率和通胀假设、taxes.yaml 税表和 costs.yaml
+/// 成本档位及随行家属费用的计算器，配置文件不存在时使用内置默认值
+pub fn base_calculator() -> Result<ROICalculator> {
+    let mut calculator = ROICalculator::default();
+    if Path::new(DEFAULT_REGIONS_PATH).exists() {
+        let regions = RegionsConfig::load_default()?;
+        calculator.rates = regions.exchange_rates.rates();
+        calculator.inflation = regions.inflation;
+        calculator.category_shares = regions.category_shares;
+    }
+    if Path::new(DEFAULT_TAXES_PATH).exists() {
+        let taxes = TaxConfig::load_default()?;
+        calculator.us_tax = taxes.us;
+        calculator.canada_tax = taxes.canada;
+        calculator.china_tax = taxes.china;
+    }
+    if Path::new(DEFAULT_COSTS_PATH).exists() {
+        let costs = CostsConfig::load_default()?;
+        calculator.cost_levels = costs.cost_levels;
+        calculator.special_costs = costs.special_costs;
+    }
+    Ok(calculator)
+}
+
+/// 货币换算表：优先使用 regions.yaml 的汇率，配置文件不存在时使用内置汇率
+pub fn currency_rates() -> Result<CurrencyRates> { Ok(base_calculator()?.rates) }
+
+/// 以JSON或YAML输出结构化数据
+pub fn print_structured<T: Serialize>(value: &T, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(value)?),
+        OutputFormat::Table | OutputFormat::Report => {
+            return Err(anyhow::anyhow!("{:?} 不是结构化输出格式", format));
+        }
+    }
+    Ok(())
+}
+
+/// calculate 子命令
+pub fn run_calculate(args: CalculateArgs) -> Result<()> {
+    let calculator = StudyCostCalculator::load_default()?;
+    let entry = calculator
+        .catalog()
+        .find(&args.school)
+        .ok_or_else(|| anyhow::anyhow!("未找到学校: '{}'", args.school))?;
+
+    if let Some(country) = args.country
+        && !country.countries().contains(&entry.country)
+    {
+        return Err(anyhow::anyhow!(
+            "{} ({}) 不在所选国家范围内 (--country: {})",
+            entry.school.name,
+            entry.country.display_name(),
+            country.label()
+        ));
+    }
+
+    let duration = args.study.duration();
+    let mut estimate = calculator.estimate(entry, duration, args.study.cost_level)?;
+    if args.include_opportunity_cost {
+        estimate.opportunity_cost_usd =
+            Some(calculator.opportunity_cost(duration, &base_calculator()?)?);
+    }
+
+    match args.format {
+        OutputFormat::Table => print_estimate_table(&estimate),
+        OutputFormat::Report => print_estimate_report(&estimate),
+        format => print_structured(&estimate, format)?,
+    }
+
+    Ok(())
+}
+
+fn print_estimate_table(estimate: &StudyCostEstimate) {
+    #[derive(Tabled)]
+    struct CostRow {
+        #[tabled(rename = "费用类别")]
+        category: String,
+        #[tabled(rename = "金额 (USD)")]
+        amount:   String,
+    }
+
+    let mut rows: Vec<CostRow> = estimate
+        .categories
+        .iter()
+        .map(|cost| CostRow {
+            category: cost.category.clone(),
+            amount:   format_usd(cost.amount_usd),
+        })
+        .collect();
+    rows.push(CostRow {
+        category: "总留学成本".to_string(),
+        amount:   format_usd(estimate.total()),
+    });
+    if let Some(opportunity_cost) = estimate.opportunity_cost_usd {
+        rows.push(CostRow {
+            category: "机会成本".to_string(),
+            amount:   format_usd(opportunity_cost),
+        });
+        rows.push(CostRow {
+            category: "总经济成本".to_string(),
+            amount:   format_usd(estimate.total_with_opportunity_cost()),
+        });
+    }
+
+    println!(
+        "🏫 {} ({}) - {} - {}",
+        estimate.school,
+        estimate.region,
+        estimate.duration,
+        estimate.cost_level.label()
+    );
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+fn print_estimate_report(estimate: &StudyCostEstimate) {
+    println!("📊 详细成本报告");
+    println!("================");
+    println!("🏫 学校: {}", estimate.school);
+    println!("📍 地区: {}", estimate.region);
+    println!("📅 学习时长: {}", estimate.duration);
+    println!("💡 成本档位: {}", estimate.cost_level.label());
+    println!();
+    println!("💰 成本分解:");
+    println!(
+        "• 基础成本 (学费+生活费): {}",
+        format_usd(estimate.base_cost())
+    );
+    println!("• 附加费用: {}", format_usd(estimate.additional_usd));
+    println!("• 总留学成本: {}", format_usd(estimate.total()));
+
+    if let Some(opportunity_cost) = estimate.opportunity_cost_usd {
+        println!();
+        println!("🎯 机会成本:");
+        println!(
+            "• 放弃国内工作的净资产积累: {}",
+            format_usd(opportunity_cost)
+        );
+        println!(
+            "• 总经济成本: {}",
+            format_usd(estimate.total_with_opportunity_cost())
+        );
+    }
+}
+
+/// compare 子命令
+pub fn run_compare(args: CompareArgs) -> Result<()> {
+    let calculator = StudyCostCalculator::load_default()?;
+    let countries = args.country.countries();
+    let entries = calculator
+        .catalog()
+        .entries()
+        .iter()
+        .filter(|entry| countries.contains(&entry.country));
+    let estimates = calculator.rank(entries, args.study.duration(), args.study.cost_level)?;
+
+    match args.format {
+        OutputFormat::Table => print_ranking_table(&estimates),
+        OutputFormat::Report => {
+            println!(
+                "📊 学校成本对比 ({}, {})",
+                args.study.duration(),
+                args.study.cost_level.label()
+            );
+            println!("================");
+            for (i, estimate) in estimates.iter().enumerate() {
+                println!(
+                    "{}. {} ({}): 学费 {} + 生活费 {} + 附加费用 {} = {}",
+                    i + 1,
+                    estimate.school,
+                    estimate.region,
+                    format_usd(estimate.tuition_usd),
+                    format_usd(estimate.living_cost_usd),
+                    format_usd(estimate.additional_usd),
+                    format_usd(estimate.total())
+                );
+            }
+        }
+        format => print_structured(&estimates, format)?,
+    }
+
+    Ok(())
+}
+
+fn print_ranking_table(estimates: &[StudyCostEstimate]) {
+    #[derive(Tabled)]
+    struct RankingRow {
+        #[tabled(rename = "排名")]
+        rank:   usize,
+        #[tabled(rename = "学校名称")]
+        school: String,
+        #[tabled(rename = "地区")]
+        region: String,
+        #[tabled(rename = "估算成本")]
+        total:  String,
+    }
+
+    let rows: Vec<RankingRow> = estimates
+        .iter()
+        .enumerate()
+        .map(|(i, estimate)| RankingRow {
+            rank:   i + 1,
+            school: estimate.school.clone(),
+            region: estimate.region.clone(),
+            total:  format_usd(estimate.total()),
+        })
+        .collect();
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// list 子命令
+pub fn run_list(args: ListArgs) -> Result<()> {
+    #[derive(Tabled, Serialize)]
+    struct SchoolRow {
+        #[tabled(rename = "学校")]
+        name:          String,
+        #[tabled(rename = "简称")]
+        short_name:    String,
+        #[tabled(rename = "国家")]
+        country:       String,
+        #[tabled(rename = "类型")]
+        tier:          String,
+        #[tabled(rename = "年学费")]
+        tuition:       String,
+        #[tabled(rename = "地区")]
+        region:        String,
+        #[tabled(rename = "最少学期")]
+        min_semesters: u32,
+    }
+
+    let catalog = SchoolCatalog::load_default()?;
+    let countries = args.country.countries();
+    let entries: Vec<&SchoolEntry> = match args.school_type {
+        Some(tier) => catalog.by_tier(tier).collect(),
+        None => catalog.entries().iter().collect(),
+    };
+    let rows: Vec<SchoolRow> = entries
+        .into_iter()
+        .filter(|entry| countries.contains(&entry.country))
+        .map(|entry| SchoolRow {
+            name:          entry.school.name.clone(),
+            short_name:    entry.school.short_name.clone(),
+            country:       entry.country.display_name().to_string(),
+            tier:          entry.tier.to_string(),
+            tuition:       format!(
+                "{:.0} {}",
+                entry.school.tuition_per_year,
+                entry.country.currency()
+            ),
+            region:        entry.school.region.clone(),
+            min_semesters: entry.school.program_details.min_semesters,
+        })
+        .collect();
+
+    match args.format {
+        OutputFormat::Table | OutputFormat::Report => {
+            let mut table = Table::new(&rows);
+            table.with(Style::modern());
+            println!("{}", table);
+            println!("共 {} 所学校", rows.len());
+        }
+        format => print_structured(&rows, format)?,
+    }
+
+    Ok(())
+}
+
+/// config 子命令
+pub fn run_config(command: ConfigCommand) -> Result<()> {
+    match command {
+        ConfigCommand::Show => show_config(),
+        ConfigCommand::Generate { force } => generate_config(force),
+        ConfigCommand::Validate => validate_config(),
+    }
+}
+
+fn show_config() -> Result<()> {
+    let catalog = SchoolCatalog::load_default()?;
+    let regions = RegionsConfig::load_default()?;
+    let costs = CostsConfig::load_default()?;
+    let taxes = TaxConfig::load_default()?;
+
+    println!("⚙️ 当前配置");
+    println!("================");
+    println!(
+        "🏫 {}: 美国 {} 所, 加拿大 {} 所",
+        DEFAULT_SCHOOLS_PATH,
+        catalog.by_country(Country::Us).count(),
+        catalog.by_country(Country::Canada).count()
+    );
+    println!(
+        "📍 {}: 美国 {} 个地区, 加拿大 {} 个地区, 国内 {} 个城市",
+        DEFAULT_REGIONS_PATH,
+        regions.regions.us.len(),
+        regions.regions.canada.len(),
+        regions.china.len()
+    );
+    let rates = &regions.exchange_rates;
+    println!(
+        "💱 汇率 ({}): USD/CNY {:.2}, CAD/USD {:.2}, CAD/CNY {:.2}",
+        rates.last_updated, rates.usd_to_cny, rates.cad_to_usd, rates.cad_to_cny
+    );
+    let inflation = &regions.inflation;
+    println!(
+        "📈 通胀: 美国 {:.1}%, 加拿大 {:.1}%, 国内 {:.1}%",
+        inflation.us * 100.0,
+        inflation.canada * 100.0,
+        inflation.china * 100.0
+    );
+    println!("💸 {}:", DEFAULT_COSTS_PATH);
+    for level in CostLevel::ALL {
+        println!(
+            "  • {}: 生活成本系数 {:.2}",
+            level.label(),
+            costs
+                .cost_levels
+                .factors(level)
+                .living_cost_factor(&regions.category_shares)
+        );
+    }
+    println!(
+        "🧾 {}: 美国{}年税表 {} 个州, 加拿大{}年税表 {} 个省, 国内{}年个税 {} 个城市",
+        DEFAULT_TAXES_PATH,
+        taxes.us.tax_year,
+        taxes.us.states.len(),
+        taxes.canada.tax_year,
+        taxes.canada.provinces.len(),
+        taxes.china.tax_year,
+        taxes.china.cities.len()
+    );
+
+    Ok(())
+}
+
+fn generate_config(force: bool) -> Result<()> {
+    let files = [
+        (DEFAULT_SCHOOLS_PATH, BUNDLED_SCHOOLS_YAML),
+        (DEFAULT_REGIONS_PATH, BUNDLED_REGIONS_YAML),
+        (DEFAULT_COSTS_PATH, BUNDLED_COSTS_YAML),
+        (DEFAULT_TAXES_PATH, BUNDLED_TAXES_YAML),
+    ];
+
+    for (path, content) in files {
+        let path = Path::new(path);
+        if path.exists() && !force {
+            println!("⏭️ 已存在，跳过: {} (使用 --force 覆盖)", path.display());
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("创建配置目录失败: {}", parent.display()))?;
+        }
+        fs::write(path, content).with_context(|| format!("写入配置失败: {}", path.display()))?;
+        println!("✅ 已生成: {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn validate_config() -> Result<()> {
+    let catalog = SchoolCatalog::load_default()?;
+    let regions = RegionsConfig::load_default()?;
+    CostsConfig::load_default()?;
+    let taxes = TaxConfig::load_default()?;
+
+    let mut problems = Vec::new();
+    for entry in catalog.entries() {
+        if regions.region(&entry.school.region).is_none() {
+            problems.push(format!(
+                "{}: 地区 '{}' 不存在于 {}",
+                entry.school.name, entry.school.region, DEFAULT_REGIONS_PATH
+            ));
+        }
+        for award in &entry.school.funding {
+            if let Err(e) = award.validate() {
+                problems.push(format!("{}: 资助配置无效: {}", entry.school.name, e));
+            }
+        }
+    }
+    for key in regions.regions.us.keys() {
+        if taxes.us.state(key).is_none() {
+            problems.push(format!(
+                "地区 '{}' 缺少州税配置 ({})",
+                key, DEFAULT_TAXES_PATH
+            ));
+        }
+    }
+    for key in regions.regions.canada.keys() {
+        if taxes.canada.province(key).is_none() {
+            problems.push(format!(
+                "地区 '{}' 缺少省税配置 ({})",
+                key, DEFAULT_TAXES_PATH
+            ));
+        }
+    }
+    for key in regions.china.keys() {
+        if taxes.china.city(key).is_none() {
+            problems.push(format!(
+                "城市 '{}' 缺少社保配置 ({})",
+                key, DEFAULT_TAXES_PATH
+            ));
+        }
+    }
+    for country in [Country::Us, Country::Canada] {
+        if regions.exchange_rates.to_usd(country.currency()).is_none() {
+            problems.push(format!("缺少汇率: {}", country.currency()));
+        }
+    }
+
+    if problems.is_empty() {
+        println!("✅ 配置文件验证通过 ({} 所学校)", catalog.entries().len());
+        Ok(())
+    } else {
+        for problem in &problems {
+            println!("❌ {}", problem);
+        }
+        Err(anyhow::anyhow!(
+            "配置文件验证失败: {} 个问题",
+            problems.len()
+        ))
+    }
+}
@@ -0,0 +1,8 @@
+pub mod analysis;
+pub mod args;
+pub mod commands;
+pub mod profile;
+pub use analysis::*;
+pub use args::*;
+pub use commands::*;
+pub use profile::*;
@@ -0,0 +1,909 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use serde::Deserialize;
+use tabled::{Table, Tabled, settings::Style};
+use uuid::Uuid;
+
+use super::{
+    OutputFormat, ProfileCompareArgs, ProfileFxArgs, ProfileLevelsArgs, ProfileScenariosArgs,
+    ProfileSensitivityArgs, ProfileSimulateArgs, ProfileVisaArgs, base_calculator, currency_rates,
+    format_usd, parse_scenario_set, run_profile_compare, run_profile_fx, run_profile_levels,
+    run_profile_scenarios, run_profile_sensitivity, run_profile_simulate, run_profile_visa,
+};
+use crate::{
+    calculator::{ROICalculator, StudyCostCalculator},
+    config::LivingCostEstimator,
+    db::DatabaseManager,
+    models::{
+        Child, CostLevel, CostParams, Currency, CurrencyRates, FinancialParams, FundingAward,
+        Household, LayoffRisk, Loan, Location, Money, Profile, ProfileType, ScenarioSet,
+        SearchOutcome, TermSystem, WorkAuthorization, WorkParams,
+    },
+};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ProfileCommand {
+    /// 新建Profile
+    Add(ProfileAddArgs),
+    /// 查看Profile详情
+    Show {
+        /// Profile ID、ID前缀或名称
+        target: String,
+        /// 输出格式
+        #[arg(short, long, value_enum, default_value_t)]
+        format: OutputFormat,
+    },
+    /// 修改Profile
+    Edit(ProfileEditArgs),
+    /// 删除Profile
+    Rm {
+        /// Profile ID、ID前缀或名称
+        target: String,
+    },
+    /// 列出Profile
+    Ls(ProfileListArgs),
+    /// 同时对比多个Profile
+    Compare(ProfileCompareArgs),
+    /// 节约型/标准型/舒适型三种成本档位并排对比
+    Levels(ProfileLevelsArgs),
+    /// 蒙特卡洛模拟净资产分布
+    Simulate(ProfileSimulateArgs),
+    /// 乐观/基准/悲观等加权情景分析
+    Scenarios(ProfileScenariosArgs),
+    /// 参数敏感性分析及龙卷风图
+    Sensitivity(ProfileSensitivityArgs),
+    /// USD/CNY、CAD/CNY 汇率变动对结果的影响
+    Fx(ProfileFxArgs),
+    /// OPT、STEM OPT延期与H-1B抽签的工作许可风险分支
+    Visa(ProfileVisaArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileAddArgs {
+    /// 从JSON/YAML文件读取字段（命令行参数优先）
+    #[arg(long)]
+    pub file:   Option<String>,
+    /// 以学校目录（config/schools.yaml）中的学校为基础创建教育路径，
+    /// 按全称或简称查找，只需提供 --initial-salary-usd，其余字段可覆盖
+    #[arg(long)]
+    pub school: Option<String>,
+    #[command(flatten)]
+    pub fields: ProfileFields,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileEditArgs {
+    /// Profile ID、ID前缀或名称
+    pub target: String,
+    /// 从JSON/YAML文件读取字段（命令行参数优先）
+    #[arg(long)]
+    pub file:   Option<String>,
+    #[command(flatten)]
+    pub fields: ProfileFields,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ProfileListArgs {
+    /// 按路径类型筛选 [education|work]
+    #[arg(short = 't', long = "type")]
+    pub profile_type: Option<ProfileType>,
+    /// 按名称模糊搜索
+    #[arg(short, long)]
+    pub name:         Option<String>,
+    /// 输出格式
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format:       OutputFormat,
+}
+
+/// 可通过命令行或文件提供的Profile字段，
+/// 未提供的字段使用默认值（新建）或保持原值（修改）
+#[derive(Debug, Clone, Default, Args, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileFields {
+    /// Profile名称
+    #[arg(long)]
+    pub name: Option<String>,
+    /// 路径类型 [education|work]
+    #[arg(long = "type")]
+    pub profile_type: Option<ProfileType>,
+    /// 国家
+    #[arg(long)]
+    pub country: Option<String>,
+    /// 城市
+    #[arg(long)]
+    pub city: Option<String>,
+    /// 当地货币 [USD|CNY|CAD]，用于显示当地货币金额 [默认: USD]
+    #[arg(long)]
+    pub currency: Option<Currency>,
+    /// 地区key（见 config/regions.yaml，如
+    /// texas、shanghai），用于地区税表和生活成本估算，空字符串表示清除
+    #[arg(long)]
+    pub region: Option<String>,
+    /// 开始工作前的延迟年数 [默认: 0]
+    #[arg(long)]
+    pub start_delay: Option<u32>,
+    /// 工作年限限制，0表示无限制
+    #[arg(long)]
+    pub duration_limit: Option<u32>,
+    /// 初始年薪，如 150000 或 "375000 CNY"（未写货币时为USD）
+    #[arg(long, visible_alias = "initial-salary")]
+    #[serde(alias = "initial_salary")]
+    pub initial_salary_usd: Option<Money>,
+    /// 年薪增长率 [默认: 0.03]
+    #[arg(long)]
+    pub salary_growth_rate: Option<f64>,
+    /// 初始年生活成本，可带货币（同上），新建时未提供则按 --region 估算
+    #[arg(long, visible_alias = "living-cost")]
+    #[serde(alias = "living_cost")]
+    pub living_cost_usd: Option<Money>,
+    /// 生活成本年增长率 [默认: 0.025]
+    #[arg(long)]
+    pub living_cost_growth: Option<f64>,
+    /// 税率 [默认: 0.25]
+    #[arg(long)]
+    pub tax_rate: Option<f64>,
+    /// 初期总成本，可带货币，0表示无成本
+    #[arg(long, visible_alias = "total-cost")]
+    #[serde(alias = "total_cost")]
+    pub total_cost_usd: Option<Money>,
+    /// 成本分摊年数 [默认: 工作延迟年数，至少1年]
+    #[arg(long)]
+    pub cost_duration: Option<u32>,
+    /// 第一年机会成本投资，可带货币，0表示无
+    #[arg(long)]
+    pub first_year_opportunity_cost: Option<Money>,
+    /// 成本档位 [budget|standard|comfortable]
+    #[arg(long)]
+    pub cost_level: Option<CostLevel>,
+    /// 是否有配偶随行
+    #[arg(long)]
+    pub spouse: Option<bool>,
+    /// 随行子女第1年的年龄（可重复）
+    #[arg(long = "child-age")]
+    pub child_ages: Option<Vec<u32>>,
+    /// 附加的情景集合 [standard|none|<JSON/YAML文件>]，文件中可直接写情景列表
+    #[arg(long, value_parser = parse_scenario_set)]
+    pub scenarios: Option<ScenarioSet>,
+    /// 路径开始的月份（1-12） [默认: 9，即秋季入学]
+    #[arg(long)]
+    pub start_month: Option<u32>,
+    /// 学习期月数（如16个月的项目），0表示按工作延迟年数计算
+    #[arg(long)]
+    pub study_months: Option<u32>,
+    /// 学制 [semester|trimester|quarter]
+    #[arg(long)]
+    pub term_system: Option<TermSystem>,
+    /// 学生贷款（可重复），格式
+    /// <bank|private|family>:<本金>:<年利率>[:<宽限期月数>[:<还款年数>]]，
+    /// 如 private:60000:0.115:6:10
+    #[arg(long = "loan")]
+    pub loans: Option<Vec<Loan>>,
+    /// 资助（可重复），格式
+    /// <scholarship|waiver|stipend>:<金额或比例>[:<获得概率>]，
+    /// 如 waiver:0.5 或 stipend:2200:0.4
+    #[arg(long = "award")]
+    pub funding: Option<Vec<FundingAward>>,
+    /// 毕业后的美国工作许可及H-1B抽签，格式
+    /// <后备Profile>:<中签率>[,<中签率>...][:<OPT月数>[:<STEM延期月数>]]，
+    /// 如 上海工作:0.3,0.3,0.3
+    #[arg(long = "work-auth")]
+    pub work_authorization: Option<WorkAuthorization>,
+    /// 开始工作前求职月数的分布，逗号分隔的 <月数>[:<概率>]，
+    /// 如 0:0.3,3:0.4,6:0.3，0表示立即入职
+    #[arg(long, value_delimiter = ',')]
+    pub job_search: Option<Vec<SearchOutcome>>,
+    /// 工作期间的裁员风险，格式 <年裁员率>[:<再就业月数>]，如
+    /// 0.05:6，0表示不考虑
+    #[arg(long)]
+    pub layoff: Option<LayoffRisk>,
+    /// 描述
+    #[arg(long)]
+    pub description: Option<String>,
+}
+
+impl ProfileFields {
+    /// 从JSON/YAML文件读取字段
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("读取Profile文件失败: {}", path.display()))?;
+        let fields = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content)?
+        } else {
+            serde_yaml::from_str(&content)?
+        };
+        Ok(fields)
+    }
+
+    /// 用 `overrides` 中已提供的字段覆盖当前字段
+    pub fn merge(self, overrides: ProfileFields) -> Self {
+        Self {
+            name: overrides.name.or(self.name),
+            profile_type: overrides.profile_type.or(self.profile_type),
+            country: overrides.country.or(self.country),
+            city: overrides.city.or(self.city),
+            currency: overrides.currency.or(self.currency),
+            region: overrides.region.or(self.region),
+            start_delay: overrides.start_delay.or(self.start_delay),
+            duration_limit: overrides.duration_limit.or(self.duration_limit),
+            initial_salary_usd: overrides.initial_salary_usd.or(self.initial_salary_usd),
+            salary_growth_rate: overrides.salary_growth_rate.or(self.salary_growth_rate),
+            living_cost_usd: overrides.living_cost_usd.or(self.living_cost_usd),
+            living_cost_growth: overrides.living_cost_growth.or(self.living_cost_growth),
+            tax_rate: overrides.tax_rate.or(self.tax_rate),
+            total_cost_usd: overrides.total_cost_usd.or(self.total_cost_usd),
+            cost_duration: overrides.cost_duration.or(self.cost_duration),
+            first_year_opportunity_cost: overrides
+                .first_year_opportunity_cost
+                .or(self.first_year_opportunity_cost),
+            cost_level: overrides.cost_level.or(self.cost_level),
+            spouse: overrides.spouse.or(self.spouse),
+            child_ages: overrides.child_ages.or(self.child_ages),
+            scenarios: overrides.scenarios.or(self.scenarios),
+            start_month: overrides.start_month.or(self.start_month),
+            study_months: overrides.study_months.or(self.study_months),
+            term_system: overrides.term_system.or(self.term_system),
+            loans: overrides.loans.or(self.loans),
+            funding: overrides.funding.or(self.funding),
+            work_authorization: overrides.work_authorization.or(self.work_authorization),
+            job_search: overrides.job_search.or(self.job_search),
+            layoff: overrides.layoff.or(self.layoff),
+            description: overrides.description.or(self.description),
+        }
+    }
+
+    /// 读取 `--file`（如有）并与命令行参数合并
+    fn resolve(file: Option<&str>, flags: ProfileFields) -> Result<Self> {
+        match file {
+            Some(path) => Ok(Self::load(path)?.merge(flags)),
+            None => Ok(flags),
+        }
+    }
+
+    /// 新建Profile，缺少的可选字段与交互式创建的默认值一致，
+    /// 未提供生活成本时按地区配置估算，带货币的金额按 `rates` 换算为美元
+    pub fn build(self, rates: &CurrencyRates) -> Result<Profile> {
+        let missing = |field: &str| anyhow::anyhow!("缺少必填字段: --{}", field);
+
+        let name = self.name.clone().ok_or_else(|| missing("name"))?;
+        let profile_type = self.profile_type.ok_or_else(|| missing("type"))?;
+        let country = self.country.clone().ok_or_else(|| missing("country"))?;
+        let initial_salary_usd = self
+            .initial_salary_usd
+            .ok_or_else(|| missing("initial-salary-usd"))?
+            .to_usd(rates);
+        let living_cost_usd = match (self.living_cost_usd, self.region.as_deref()) {
+            (Some(living_cost), _) => living_cost.to_usd(rates),
+            (None, Some(region)) if !region.is_empty() => LivingCostEstimator::load_default()?
+                .estimate(region)?
+                .total(),
+            (None, _) => return Err(missing("living-cost-usd")),
+        };
+
+        let mut profile = Profile::new(
+            name,
+            profile_type,
+            Location {
+                country,
+                city: None,
+                currency: "USD".to_string(),
+                region: None,
+            },
+            WorkParams {
+                start_delay:    0,
+                duration_limit: None,
+            },
+            FinancialParams {
+                initial_salary_usd,
+                salary_growth_rate: 0.03,
+                living_cost_usd,
+                living_cost_growth: 0.025,
+                tax_rate: 0.25,
+            },
+        );
+        self.apply_to(&mut profile, rates)?;
+        Ok(profile)
+    }
+
+    /// 以学校目录中的学校为基础新建Profile，已提供的字段覆盖学校的默认值
+    pub fn build_for_school(self, school: &str, rates: &CurrencyRates) -> Result<Profile> {
+        let initial_salary_usd = self
+            .initial_salary_usd
+            .ok_or_else(|| anyhow::anyhow!("缺少必填字段: --initial-salary-usd"))?
+            .to_usd(rates);
+        let mut profile =
+            StudyCostCalculator::load_default()?.school_profile(school, initial_salary_usd)?;
+        self.apply_to(&mut profile, rates)?;
+        Ok(profile)
+    }
+
+    /// 将已提供的字段写入现有Profile，带货币的金额按 `rates` 换算为美元
+    pub fn apply_to(self, profile: &mut Profile, rates: &CurrencyRates) -> Result<()> {
+        if let Some(name) = self.name {
+            profile.name = name;
+        }
+        if let Some(profile_type) = self.profile_type {
+            profile.profile_type = profile_type;
+        }
+        if let Some(country) = self.country {
+            profile.location.country = country;
+        }
+        if let Some(city) = self.city {
+            profile.location.city = (!city.is_empty()).then_some(city);
+        }
+        if let Some(currency) = self.currency {
+            profile.location.currency = currency.code().to_string();
+        }
+        if let Some(region) = self.region {
+            profile.location.region = (!region.is_empty()).then_some(region);
+        }
+        if let Some(start_delay) = self.start_delay {
+            profile.work_params.start_delay = start_delay;
+        }
+        if let Some(limit) = self.duration_limit {
+            profile.work_params.duration_limit = (limit > 0).then_some(limit);
+        }
+
+        let financial = &mut profile.financial_params;
+        if let Some(salary) = self.initial_salary_usd {
+            financial.initial_salary_usd = salary.to_usd(rates);
+        }
+        if let Some(growth) = self.salary_growth_rate {
+            financial.salary_growth_rate = growth;
+        }
+        if let Some(living_cost) = self.living_cost_usd {
+            financial.living_cost_usd = living_cost.to_usd(rates);
+        }
+        if let Some(growth) = self.living_cost_growth {
+            financial.living_cost_growth = growth;
+        }
+        if let Some(tax_rate) = self.tax_rate {
+            financial.tax_rate = tax_rate;
+        }
+
+        let total_cost_usd = self.total_cost_usd.map(|cost| cost.to_usd(rates));
+        match (total_cost_usd, self.cost_duration) {
+            (Some(0.0), _) => profile.cost_params = None,
+            (Some(total), duration) => {
+                let duration = duration
+                    .or(profile.cost_params.as_ref().map(|cost| cost.cost_duration))
+                    .unwrap_or(profile.work_params.start_delay.max(1));
+                profile.cost_params = Some(CostParams::new(total, duration)?);
+            }
+            (None, Some(duration)) => match profile.cost_params.as_mut() {
+                Some(cost) if duration > 0 => cost.cost_duration = duration,
+                Some(_) => return Err(anyhow::anyhow!("成本分摊年数必须大于0")),
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "未设置初期成本，请同时提供 --total-cost-usd"
+                    ));
+                }
+            },
+            (None, None) => {}
+        }
+
+        if let Some(opportunity_cost) = self.first_year_opportunity_cost {
+            let opportunity_cost = opportunity_cost.to_usd(rates);
+            profile.first_year_opportunity_cost =
+                (opportunity_cost > 0.0).then_some(opportunity_cost);
+        }
+        if let Some(cost_level) = self.cost_level {
+            profile.cost_level = cost_level;
+        }
+        if let Some(spouse) = self.spouse {
+            profile.household.spouse = spouse;
+        }
+        if let Some(ages) = self.child_ages {
+            profile.household.children = ages.into_iter().map(|age| Child { age }).collect();
+        }
+        if let Some(scenarios) = self.scenarios {
+            if !scenarios.is_empty() {
+                scenarios.validate()?;
+            }
+            profile.scenarios = scenarios;
+        }
+
+        let timeline = &mut profile.timeline;
+        if let Some(start_month) = self.start_month {
+            timeline.start_month = start_month;
+        }
+        if let Some(months) = self.study_months {
+            timeline.study_months = (months > 0).then_some(months);
+        }
+        if let Some(term_system) = self.term_system {
+            timeline.term_system = term_system;
+        }
+        timeline.validate()?;
+
+        if let Some(loans) = self.loans {
+            for loan in &loans {
+                loan.validate()?;
+            }
+            profile.loans = loans;
+        }
+        if let Some(funding) = self.funding {
+            for award in &funding {
+                award.validate()?;
+            }
+            profile.funding = funding;
+        }
+        if let Some(auth) = self.work_authorization {
+            auth.validate()?;
+            profile.work_authorization = Some(auth);
+        }
+        if let Some(job_search) = self.job_search {
+            profile.employment.job_search = match job_search.as_slice() {
+                [outcome] if outcome.months == 0 => Vec::new(),
+                _ => job_search,
+            };
+        }
+        if let Some(layoff) = self.layoff {
+            profile.employment.layoff = (layoff.annual_rate > 0.0).then_some(layoff);
+        }
+        profile.employment.validate()?;
+        if let Some(description) = self.description {
+            profile.description = (!description.is_empty()).then_some(description);
+        }
+
+        Ok(())
+    }
+}
+
+/// profile 子命令
+pub fn run_profile(command: ProfileCommand) -> Result<()> {
+    let mut db = DatabaseManager::new()?;
+
+    match command {
+        ProfileCommand::Add(args) => {
+            let fields = ProfileFields::resolve(args.file.as_deref(), args.fields)?;
+            let rates = currency_rates()?;
+            let profile = match args.school.as_deref() {
+                Some(school) => fields.build_for_school(school, &rates)?,
+                None => fields.build(&rates)?,
+            };
+            db.save_profile(&profile)?;
+            println!("✅ 已创建Profile: {} ({})", profile.name, profile.id);
+        }
+        ProfileCommand::Show { target, format } => {
+            let profile = find_profile(&mut db, &target)?;
+            match format {
+                OutputFormat::Table | OutputFormat::Report => {
+                    print_profile_details(&profile, &base_calculator()?)
+                }
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&profile)?),
+                OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&profile)?),
+            }
+        }
+        ProfileCommand::Edit(args) => {
+            let mut profile = find_profile(&mut db, &args.target)?;
+            ProfileFields::resolve(args.file.as_deref(), args.fields)?
+                .apply_to(&mut profile, &currency_rates()?)?;
+            profile.updated_at = chrono::Utc::now();
+            db.update_profile(&profile)?;
+            println!("✅ 已更新Profile: {} ({})", profile.name, profile.id);
+        }
+        ProfileCommand::Rm { target } => {
+            let profile = find_profile(&mut db, &target)?;
+            db.delete_profile(&profile.id)?;
+            println!("🗑️ 已删除Profile: {} ({})", profile.name, profile.id);
+        }
+        ProfileCommand::Ls(args) => {
+            let mut profiles = match (&args.name, args.profile_type) {
+                (Some(pattern), _) => db.search_profiles_by_name(pattern)?,
+                (None, Some(profile_type)) => db.get_profiles_by_type(profile_type)?,
+                (None, None) => db.get_profiles()?,
+            };
+            if let Some(profile_type) = args.profile_type {
+                profiles.retain(|profile| profile.profile_type == profile_type);
+            }
+
+            match args.format {
+                OutputFormat::Table | OutputFormat::Report => print_profile_list(&profiles),
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&profiles)?),
+                OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&profiles)?),
+            }
+        }
+        ProfileCommand::Compare(args) => run_profile_compare(&mut db, args)?,
+        ProfileCommand::Levels(args) => run_profile_levels(&mut db, args)?,
+        ProfileCommand::Simulate(args) => run_profile_simulate(&mut db, args)?,
+        ProfileCommand::Scenarios(args) => run_profile_scenarios(&mut db, args)?,
+        ProfileCommand::Sensitivity(args) => run_profile_sensitivity(&mut db, args)?,
+        ProfileCommand::Fx(args) => run_profile_fx(&mut db, args)?,
+        ProfileCommand::Visa(args) => run_profile_visa(&mut db, args)?,
+    }
+
+    Ok(())
+}
+
+/// 按ID、唯一ID前缀或名称查找Profile
+pub(super) fn find_profile(db: &mut DatabaseManager, target: &str) -> Result<Profile> {
+    if let Ok(id) = Uuid::parse_str(target) {
+        return db
+            .get_profile(&id)?
+            .ok_or_else(|| anyhow::anyhow!("未找到Profile: '{}'", target));
+    }
+
+    let mut by_name: Vec<Profile> = db
+        .search_profiles_by_name(target)?
+        .into_iter()
+        .filter(|profile| profile.name == target)
+        .collect();
+    if by_name.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "存在多个名为 '{}' 的Profile，请使用ID",
+            target
+        ));
+    }
+    if let Some(profile) = by_name.pop() {
+        return Ok(profile);
+    }
+
+    let mut by_prefix: Vec<Profile> = db
+        .get_profiles()?
+        .into_iter()
+        .filter(|profile| profile.id.to_string().starts_with(target))
+        .collect();
+    match by_prefix.len() {
+        0 => Err(anyhow::anyhow!("未找到Profile: '{}'", target)),
+        1 => Ok(by_prefix.remove(0)),
+        _ => Err(anyhow::anyhow!("ID前缀 '{}' 匹配多个Profile", target)),
+    }
+}
+
+fn print_profile_list(profiles: &[Profile]) {
+    #[derive(Tabled)]
+    struct ProfileRow {
+        #[tabled(rename = "ID")]
+        id:         String,
+        #[tabled(rename = "名称")]
+        name:       String,
+        #[tabled(rename = "类型")]
+        kind:       String,
+        #[tabled(rename = "地点")]
+        location:   String,
+        #[tabled(rename = "初始年薪")]
+        salary:     String,
+        #[tabled(rename = "成本档位")]
+        cost_level: String,
+    }
+
+    let rows: Vec<ProfileRow> = profiles
+        .iter()
+        .map(|profile| ProfileRow {
+            id:         profile.id.to_string()[..8].to_string(),
+            name:       profile.name.clone(),
+            kind:       profile.profile_type.to_string(),
+            location:   location_label(&profile.location),
+            salary:     format_usd(profile.financial_params.initial_salary_usd),
+            cost_level: profile.cost_level.label().to_string(),
+        })
+        .collect();
+
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{}", table);
+    println!("共 {} 个Profile", profiles.len());
+}
+
+fn print_profile_details(profile: &Profile, calculator: &ROICalculator) {
+    #[derive(Tabled)]
+    struct FieldRow {
+        #[tabled(rename = "字段")]
+        field: &'static str,
+        #[tabled(rename = "值")]
+        value: String,
+    }
+
+    let financial = &profile.financial_params;
+    let amount = |usd: f64| native_amount(usd, &profile.location, &calculator.rates);
+    let mut rows = vec![
+        ("ID", profile.id.to_string()),
+        ("名称", profile.name.clone()),
+        ("类型", profile.profile_type.to_string()),
+        ("地点", location_label(&profile.location)),
+        ("货币", profile.location.currency.clone()),
+        (
+            "地区",
+            profile
+                .location
+                .region
+                .clone()
+                .unwrap_or_else(|| "未设置".to_string()),
+        ),
+        (
+            "工作延迟",
+            format!("{} 年", profile.work_params.start_delay),
+        ),
+        (
+            "工作年限",
+            profile
+                .work_params
+                .duration_limit
+                .map(|years| format!("{} 年", years))
+                .unwrap_or_else(|| "无限制".to_string()),
+        ),
+        ("初始年薪", amount(financial.initial_salary_usd)),
+        (
+            "年薪增长率",
+            format!("{:.1}%", financial.salary_growth_rate * 100.0),
+        ),
+        (
+            "生活成本",
+            format!("{}/年", amount(financial.living_cost_usd)),
+        ),
+        (
+            "生活成本增长率",
+            format!("{:.1}%", financial.living_cost_growth * 100.0),
+        ),
+        ("税率", calculator.tax_label(profile)),
+        ("成本档位", profile.cost_level.label().to_string()),
+        (
+            "初期成本",
+            match &profile.cost_params {
+                Some(cost) => format!(
+                    "{} (分摊{}年)",
+                    amount(cost.total_cost_usd),
+                    cost.cost_duration
+                ),
+                None => "无".to_string(),
+            },
+        ),
+        (
+            "机会成本",
+            profile
+                .first_year_opportunity_cost
+                .map(amount)
+                .unwrap_or_else(|| "无".to_string()),
+        ),
+        ("随行家属", household_label(&profile.household)),
+        ("情景", scenarios_label(&profile.scenarios)),
+        ("时间安排", profile.timeline.summary()),
+        ("学生贷款", loans_label(&profile.loans)),
+        ("资助", funding_label(&profile.funding)),
+        ("求职与裁员", profile.employment.summary()),
+        (
+            "工作许可",
+            profile
+                .work_authorization
+                .as_ref()
+                .map(WorkAuthorization::summary)
+                .unwrap_or_else(|| "未设置".to_string()),
+        ),
+    ];
+    if let Some(description) = &profile.description {
+        rows.push(("描述", description.clone()));
+    }
+
+    let rows: Vec<FieldRow> = rows
+        .into_iter()
+        .map(|(field, value)| FieldRow { field, value })
+        .collect();
+    let mut table = Table::new(rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+fn location_label(location: &Location) -> String {
+    match &location.city {
+        Some(city) => format!("{}, {}", city, location.country),
+        None => location.country.clone(),
+    }
+}
+
+/// 美元金额，当地货币不是美元时附上当地货币金额
+fn native_amount(amount_usd: f64, location: &Location, rates: &CurrencyRates) -> String {
+    let usd = Money::usd(amount_usd);
+    match location.native_currency() {
+        Some(currency) if currency != Currency::Usd => {
+            format!("{} ({})", usd, usd.to(currency, rates))
+        }
+        _ => usd.to_string(),
+    }
+}
+
+fn household_label(household: &Household) -> String {
+    if household.children.is_empty() {
+        return household.summary();
+    }
+    let ages: Vec<String> = household
+        .children
+        .iter()
+        .map(|child| child.age.to_string())
+        .collect();
+    format!("{} (子女年龄: {})", household.summary(), ages.join(", "))
+}
+
+fn loans_label(loans: &[Loan]) -> String {
+    if loans.is_empty() {
+        return "无".to_string();
+    }
+    loans
+        .iter()
+        .map(Loan::summary)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn funding_label(funding: &[FundingAward]) -> String {
+    if funding.is_empty() {
+        return "无".to_string();
+    }
+    funding
+        .iter()
+        .map(FundingAward::summary)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn scenarios_label(scenarios: &ScenarioSet) -> String {
+    if scenarios.is_empty() {
+        return "未设置（使用标准情景）".to_string();
+    }
+    scenarios
+        .scenarios
+        .iter()
+        .map(|scenario| format!("{} {:.0}%", scenario.name, scenario.probability * 100.0))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(region: &str) -> ProfileFields {
+        ProfileFields {
+            name: Some("Test".to_string()),
+            profile_type: Some(ProfileType::Work),
+            country: Some("USA".to_string()),
+            region: Some(region.to_string()),
+            initial_salary_usd: Some(Money::usd(100000.0)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_living_cost_from_region() {
+        let rates = CurrencyRates::default();
+        let estimator = LivingCostEstimator::load_default().unwrap();
+
+        for region in ["texas", "shanghai"] {
+            let profile = fields(region).build(&rates).unwrap();
+            assert_eq!(profile.location.region.as_deref(), Some(region));
+            assert_eq!(
+                profile.financial_params.living_cost_usd,
+                estimator.estimate(region).unwrap().total()
+            );
+        }
+
+        // 显式提供的生活成本优先于地区估算
+        let profile = ProfileFields {
+            living_cost_usd: Some(Money::usd(30000.0)),
+            ..fields("texas")
+        }
+        .build(&rates)
+        .unwrap();
+        assert_eq!(profile.financial_params.living_cost_usd, 30000.0);
+
+        assert!(fields("").build(&rates).is_err());
+        assert!(fields("atlantis").build(&rates).is_err());
+    }
+
+    #[test]
+    fn test_load_and_merge() {
+        let path = std::env::temp_dir().join(format!("{}.yaml", Uuid::new_v4()));
+        fs::write(
+            &path,
+            "name: 文件\ninitial_salary: 375000 CNY\ntax_rate: 0.2\nchild_ages: [3]\n",
+        )
+        .unwrap();
+        let file = ProfileFields::load(&path).unwrap();
+        assert_eq!(
+            file.initial_salary_usd,
+            Some(Money::new(375000.0, Currency::Cny))
+        );
+
+        // 命令行参数优先，未提供的字段保留文件中的值
+        let merged = file.merge(ProfileFields {
+            name: Some("命令行".to_string()),
+            child_ages: Some(Vec::new()),
+            ..Default::default()
+        });
+        assert_eq!(merged.name.as_deref(), Some("命令行"));
+        assert_eq!(merged.tax_rate, Some(0.2));
+        assert_eq!(merged.child_ages, Some(Vec::new()));
+
+        // 未知字段报错而不是被忽略
+        fs::write(&path, "name: 文件\nsalary: 100000\n").unwrap();
+        assert!(ProfileFields::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_build_requires_fields() {
+        let rates = CurrencyRates::default();
+        let error = |fields: ProfileFields| fields.build(&rates).unwrap_err().to_string();
+
+        assert!(error(ProfileFields::default()).contains("--name"));
+        assert!(
+            error(ProfileFields {
+                initial_salary_usd: None,
+                ..fields("texas")
+            })
+            .contains("--initial-salary-usd")
+        );
+        assert!(
+            error(ProfileFields {
+                region: None,
+                ..fields("texas")
+            })
+            .contains("--living-cost-usd")
+        );
+
+        // 修改时单独提供分摊年数需要已有初期成本
+        let mut profile = fields("texas").build(&rates).unwrap();
+        let duration_only = ProfileFields {
+            cost_duration: Some(2),
+            ..Default::default()
+        };
+        assert!(
+            duration_only
+                .clone()
+                .apply_to(&mut profile, &rates)
+                .is_err()
+        );
+        ProfileFields {
+            total_cost_usd: Some(Money::usd(50000.0)),
+            ..duration_only
+        }
+        .apply_to(&mut profile, &rates)
+        .unwrap();
+        let cost = profile.cost_params.as_ref().unwrap();
+        assert_eq!((cost.total_cost_usd, cost.cost_duration), (50000.0, 2));
+
+        // 0 清除初期成本
+        ProfileFields {
+            total_cost_usd: Some(Money::usd(0.0)),
+            ..Default::default()
+        }
+        .apply_to(&mut profile, &rates)
+        .unwrap();
+        assert!(profile.cost_params.is_none());
+    }
+
+    #[test]
+    fn test_find_profile() {
+        let mut db = DatabaseManager::in_memory().unwrap();
+        let rates = CurrencyRates::default();
+        let ids = [
+            "aaaa1111-0000-0000-0000-000000000000",
+            "aaaa2222-0000-0000-0000-000000000000",
+            "bbbb1111-0000-0000-0000-000000000000",
+        ];
+        for (id, name) in ids.iter().zip(["甲", "乙", "乙"]) {
+            let mut profile = ProfileFields {
+                name: Some(name.to_string()),
+                ..fields("texas")
+            }
+            .build(&rates)
+            .unwrap();
+            profile.id = Uuid::parse_str(id).unwrap();
+            db.save_profile(&profile).unwrap();
+        }
+
+        assert_eq!(find_profile(&mut db, ids[1]).unwrap().name, "乙");
+        assert_eq!(find_profile(&mut db, "甲").unwrap().id.to_string(), ids[0]);
+        assert_eq!(
+            find_profile(&mut db, "bbbb").unwrap().id.to_string(),
+            ids[2]
+        );
+
+        // 重名、前缀不唯一和找不到时报错
+        assert!(find_profile(&mut db, "乙").is_err());
+        assert!(find_profile(&mut db, "aaaa").is_err());
+        assert!(find_profile(&mut db, "cccc").is_err());
+        assert!(find_profile(&mut db, "cccc1111-0000-0000-0000-000000000000").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{Country, SchoolEntry};
+use crate::models::{
+    CostBreakdown, CostCategory, CostFrequency, CostItem, CostLevels, CostParams, SpecialCosts,
+    Timeline,
+};
+
+/// 默认费用配置文件路径
+pub const DEFAULT_COSTS_PATH: &str = "config/costs.yaml";
+
+/// 内置的默认费用配置（用于生成配置文件）
+pub const BUNDLED_COSTS_YAML: &str = include_str!("../../config/costs.yaml");
+
+/// 某一类别下的费用项（项目名 -> 本币金额）
+pub type CostItems = BTreeMap<String, f64>;
+
+/// 单个国家的默认附加费用（金额为当地货币）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CountryCosts {
+    #[serde(default)]
+    pub application:        CostItems,
+    #[serde(default)]
+    pub visa_legal:         CostItems,
+    #[serde(default)]
+    pub insurance_medical:  CostItems,
+    #[serde(default)]
+    pub transportation:     CostItems,
+    #[serde(default)]
+    pub accommodation:      CostItems,
+    #[serde(default)]
+    pub financial_services: CostItems,
+    #[serde(default)]
+    pub communication:      CostItems,
+    #[serde(default)]
+    pub study:              CostItems,
+    #[serde(default)]
+    pub job_search:         CostItems,
+    #[serde(default)]
+    pub emergency:          CostItems,
+}
+
+impl CountryCosts {
+    /// 按类别遍历所有费用项
+    pub fn categories(&self) -> [(CostCategory, &CostItems); 10] {
+        [
+            (CostCategory::Application, &self.application),
+            (CostCategory::VisaLegal, &self.visa_legal),
+            (CostCategory::InsuranceMedical, &self.insurance_medical),
+            (CostCategory::Transportation, &self.transportation),
+            (CostCategory::Accommodation, &self.accommodation),
+            (CostCategory::FinancialServices, &self.financial_services),
+            (CostCategory::Communication, &self.communication),
+            (CostCategory::Study, &self.study),
+            (CostCategory::JobSearch, &self.job_search),
+            (CostCategory::Emergency, &self.emergency),
+        ]
+    }
+
+    /// 转换为分项成本明细，`to_usd` 为本币兑美元汇率
+    pub fn to_breakdown(&self, to_usd: f64) -> CostBreakdown {
+        let items = self
+            .categories()
+            .into_iter()
+            .flat_map(|(category, items)| {
+                items.iter().map(move |(name, amount)| {
+                    CostItem::new(
+                        category,
+                        name.clone(),
+                        amount * to_usd,
+                        CostFrequency::from_item_name(name),
+                    )
+                })
+            })
+            .collect();
+
+        CostBreakdown { items }
+    }
+}
+
+/// 按国家分组的默认费用
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DefaultCosts {
+    #[serde(default)]
+    pub us:     CountryCosts,
+    #[serde(default)]
+    pub canada: CountryCosts,
+}
+
+/// costs.yaml 顶层结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CostsConfig {
+    pub default_costs: DefaultCosts,
+    #[serde(default)]
+    pub cost_levels:   CostLevels,
+    #[serde(default)]
+    pub special_costs: SpecialCosts,
+}
+
+impl CostsConfig {
+    /// 从YAML文件加载费用配置
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("读取费用配置失败: {}", path.display()))?;
+        Self::from_yaml_str(&content)
+            .with_context(|| format!("解析费用配置失败: {}", path.display()))
+    }
+
+    /// 从默认路径加载费用配置
+    pub fn load_default() -> Result<Self> { Self::load(DEFAULT_COSTS_PATH) }
+
+    /// 从YAML字符串解析费用配置
+    pub fn from_yaml_str(content: &str) -> Result<Self> { Ok(serde_yaml::from_str(content)?) }
+
+    /// 指定国家的默认附加费用
+    pub fn country_costs(&self, country: Country) -> &CountryCosts {
+        match country {
+            Country::Us => &self.default_costs.us,
+            Country::Canada => &self.default_costs.canada,
+        }
+    }
+
+    /// 学校的完整分项成本：年学费 + 该国默认附加费用
+    pub fn school_breakdown(&self, entry: &SchoolEntry, to_usd: f64) -> CostBreakdown {
+        self.country_costs(entry.country)
+            .to_breakdown(to_usd)
+            .with_item(CostItem::new(
+                CostCategory::Tuition,
+                "tuition_per_year",
+                entry.school.tuition_per_year * to_usd,
+                CostFrequency::PerYear,
+            ))
+    }
+
+    /// 学校的分项成本参数（生活费由Profile的生活成本单独计算）
+    pub fn school_cost_params(&self, entry: &SchoolEntry, to_usd: f64) -> CostParams {
+        let study_months = entry.study_years() * 12;
+        let breakdown = self.school_breakdown(entry, to_usd);
+        CostParams::itemized(
+            breakdown,
+            entry.study_years(),
+            study_months,
+            &Timeline::default().term_schedule(study_months),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SchoolCatalog;
+
+    #[test]
+    fn test_school_breakdown_from_bundled_config() {
+        let costs = CostsConfig::from_yaml_str(include_str!("../../config/costs.yaml")).unwrap();
+        let catalog =
+            SchoolCatalog::from_yaml_str(include_str!("../../config/schools.yaml")).unwrap();
+        let tamu = catalog.find("TAMU").unwrap();
+
+        let breakdown = costs.school_breakdown(tamu, 1.0);
+        let one_time = breakdown.total_by_frequency(CostFrequency::OneTime);
+        let per_year = breakdown.total_by_frequency(CostFrequency::PerYear);
+        let per_month = breakdown.total_by_frequency(CostFrequency::PerMonth);
+
+        // 学费 + 健康保险 + 牙科保险 + 信用卡年费
+        assert_eq!(per_year, 26000.0 + 3000.0 + 500.0 + 100.0);
+        // 手机 + 网络
+        assert_eq!(per_month, 55.0 + 50.0);
+        assert!(one_time > 0.0);
+
+        let params = costs.school_cost_params(tamu, 1.0);
+        assert_eq!(params.cost_duration, 2);
+        assert!(
+            (params.total_cost_usd - (one_time + 2.0 * per_year + 24.0 * per_month)).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_bundled_cost_levels() {
+        let costs = CostsConfig::from_yaml_str(include_str!("../../config/costs.yaml")).unwrap();
+        assert!(costs.special_costs.spouse.living_cost_increase > 0.0);
+
+        let shares = crate::models::CategoryShares::default();
+        let factor = |level| costs.cost_levels.factors(level).living_cost_factor(&shares);
+        assert!(factor(crate::models::CostLevel::Budget) < 1.0);
+        assert!((factor(crate::models::CostLevel::Standard) - 1.0).abs() < 1e-9);
+        assert!(factor(crate::models::CostLevel::Comfortable) > 1.0);
+    }
+}
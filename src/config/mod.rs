@@ -0,0 +1,8 @@
+pub mod costs;
+pub mod regions;
+pub mod schools;
+pub mod taxes;
+pub use costs::*;
+pub use regions::*;
+pub use schools::*;
+pub use taxes::*;
@@ -0,0 +1,310 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::Country;
+use crate::models::{CategoryShares, Currency, CurrencyRates, FinancialParams, InflationRates};
+
+/// 默认地区配置文件路径
+pub const DEFAULT_REGIONS_PATH: &str = "config/regions.yaml";
+
+/// 内置的默认地区配置（用于生成配置文件）
+pub const BUNDLED_REGIONS_YAML: &str = include_str!("../../config/regions.yaml");
+
+/// 生活成本分项系数
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CostFactors {
+    pub housing:   f64,
+    pub food:      f64,
+    pub transport: f64,
+    pub utilities: f64,
+}
+
+/// 海外地区配置（金额为当地货币）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Region {
+    pub name:                  String,
+    pub cost_level:            String,
+    pub living_cost_per_month: f64,
+    pub cost_factors:          CostFactors,
+    #[serde(default)]
+    pub advantages:            Vec<String>,
+    #[serde(default)]
+    pub disadvantages:         Vec<String>,
+}
+
+/// 按国家分组的地区
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RegionsByCountry {
+    #[serde(default)]
+    pub us:     BTreeMap<String, Region>,
+    #[serde(default)]
+    pub canada: BTreeMap<String, Region>,
+}
+
+/// 国内参考薪资（CNY/年）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReferenceSalary {
+    pub entry_level:  f64,
+    pub mid_level:    f64,
+    pub senior_level: f64,
+    pub management:   f64,
+}
+
+/// 国内月度生活成本（CNY）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChinaLivingCosts {
+    pub housing_rent:  f64,
+    pub food:          f64,
+    pub transport:     f64,
+    pub utilities:     f64,
+    pub entertainment: f64,
+    pub insurance:     f64,
+    pub total_monthly: f64,
+    pub total_yearly:  f64,
+}
+
+/// 国内税负参考
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChinaTaxInfo {
+    pub income_tax_rate:  f64,
+    pub social_insurance: f64,
+    pub total_tax_rate:   f64,
+}
+
+/// 国内城市配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChinaCity {
+    pub name:             String,
+    pub currency:         String,
+    pub reference_salary: ReferenceSalary,
+    pub living_costs:     ChinaLivingCosts,
+    pub tax_info:         ChinaTaxInfo,
+}
+
+impl ChinaCity {
+    /// 以中级年薪生成国内工作路径的财务参数（增长率与交互式创建的默认值一致）
+    pub fn financial_params(&self, to_usd: f64) -> FinancialParams {
+        FinancialParams {
+            initial_salary_usd: self.reference_salary.mid_level * to_usd,
+            salary_growth_rate: 0.03,
+            living_cost_usd:    self.living_costs.total_yearly * to_usd,
+            living_cost_growth: 0.025,
+            tax_rate:           self.tax_info.total_tax_rate,
+        }
+    }
+}
+
+/// 汇率配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangeRates {
+    pub usd_to_cny:   f64,
+    pub cad_to_usd:   f64,
+    pub cad_to_cny:   f64,
+    pub last_updated: String,
+}
+
+impl ExchangeRates {
+    /// 计算使用的货币换算表
+    pub fn rates(&self) -> CurrencyRates {
+        CurrencyRates {
+            usd_to_cny: self.usd_to_cny,
+            cad_to_usd: self.cad_to_usd,
+        }
+    }
+
+    /// 1单位指定货币折合多少美元
+    pub fn to_usd(&self, currency: &str) -> Option<f64> {
+        let currency: Currency = currency.parse().ok()?;
+        Some(self.rates().usd_per_unit(currency))
+    }
+}
+
+/// regions.yaml 顶层结构
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RegionsConfig {
+    pub regions:         RegionsByCountry,
+    #[serde(default)]
+    pub category_shares: CategoryShares,
+    #[serde(default)]
+    pub china:           BTreeMap<String, ChinaCity>,
+    pub exchange_rates:  ExchangeRates,
+    #[serde(default)]
+    pub inflation:       InflationRates,
+}
+
+impl RegionsConfig {
+    /// 从YAML文件加载地区配置
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("读取地区配置失败: {}", path.display()))?;
+        Self::from_yaml_str(&content)
+            .with_context(|| format!("解析地区配置失败: {}", path.display()))
+    }
+
+    /// 从默认路径加载地区配置
+    pub fn load_default() -> Result<Self> { Self::load(DEFAULT_REGIONS_PATH) }
+
+    /// 从YAML字符串解析地区配置
+    pub fn from_yaml_str(content: &str) -> Result<Self> { Ok(serde_yaml::from_str(content)?) }
+
+    /// 按key查找海外地区
+    pub fn region(&self, key: &str) -> Option<(Country, &Region)> {
+        self.regions
+            .us
+            .get(key)
+            .map(|region| (Country::Us, region))
+            .or_else(|| {
+                self.regions
+                    .canada
+                    .get(key)
+                    .map(|region| (Country::Canada, region))
+            })
+    }
+
+    /// 按key查找国内城市
+    pub fn china_city(&self, key: &str) -> Option<&ChinaCity> { self.china.get(key) }
+
+    /// 所有可估算的地区key
+    pub fn region_keys(&self) -> Vec<&str> {
+        self.regions
+            .us
+            .keys()
+            .chain(self.regions.canada.keys())
+            .chain(self.china.keys())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// 年度生活成本分解（USD）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LivingCostBreakdown {
+    pub region_key:  String,
+    pub region_name: String,
+    /// 原始配置使用的货币
+    pub currency:    String,
+    pub housing:     f64,
+    pub food:        f64,
+    pub transport:   f64,
+    pub utilities:   f64,
+    /// 其他支出（娱乐，国内城市还包括保险）
+    pub other:       f64,
+}
+
+impl LivingCostBreakdown {
+    /// 年度生活成本合计（USD）
+    pub fn total(&self) -> f64 {
+        self.housing + self.food + self.transport + self.utilities + self.other
+    }
+}
+
+/// 地区生活成本估算器
+#[derive(Debug, Clone)]
+pub struct LivingCostEstimator {
+    config: RegionsConfig,
+}
+
+impl LivingCostEstimator {
+    pub fn new(config: RegionsConfig) -> Self { Self { config } }
+
+    /// 从默认路径加载
+    pub fn load_default() -> Result<Self> { Ok(Self::new(RegionsConfig::load_default()?)) }
+
+    pub fn config(&self) -> &RegionsConfig { &self.config }
+
+    /// 估算指定地区的年度生活成本（已应用成本系数并换算为USD）
+    pub fn estimate(&self, region_key: &str) -> Result<LivingCostBreakdown> {
+        let rates = &self.config.exchange_rates;
+
+        if let Some((country, region)) = self.config.region(region_key) {
+            let currency = country.currency();
+            let to_usd = rates
+                .to_usd(currency)
+                .ok_or_else(|| anyhow::anyhow!("缺少汇率: {}", currency))?;
+            let yearly_base = region.living_cost_per_month * 12.0 * to_usd;
+            let factors = &region.cost_factors;
+            let shares = &self.config.category_shares;
+
+            return Ok(LivingCostBreakdown {
+                region_key:  region_key.to_string(),
+                region_name: region.name.clone(),
+                currency:    currency.to_string(),
+                housing:     yearly_base * shares.housing * factors.housing,
+                food:        yearly_base * shares.food * factors.food,
+                transport:   yearly_base * shares.transport * factors.transport,
+                utilities:   yearly_base * shares.utilities * factors.utilities,
+                other:       yearly_base * shares.entertainment,
+            });
+        }
+
+        if let Some(city) = self.config.china_city(region_key) {
+            let to_usd = rates
+                .to_usd(&city.currency)
+                .ok_or_else(|| anyhow::anyhow!("缺少汇率: {}", city.currency))?;
+            let costs = &city.living_costs;
+            let yearly = |monthly: f64| monthly * 12.0 * to_usd;
+
+            return Ok(LivingCostBreakdown {
+                region_key:  region_key.to_string(),
+                region_name: city.name.clone(),
+                currency:    city.currency.clone(),
+                housing:     yearly(costs.housing_rent),
+                food:        yearly(costs.food),
+                transport:   yearly(costs.transport),
+                utilities:   yearly(costs.utilities),
+                other:       yearly(costs.entertainment + costs.insurance),
+            });
+        }
+
+        Err(anyhow::anyhow!(
+            "未知地区: '{}'（可选: {}）",
+            region_key,
+            self.config.region_keys().join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimator() -> LivingCostEstimator {
+        LivingCostEstimator::new(
+            RegionsConfig::from_yaml_str(include_str!("../../config/regions.yaml")).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_estimate_us_region() {
+        let breakdown = estimator().estimate("texas").unwrap();
+
+        // 1800/月 * 12 = 21600，住房占50%且系数1.0
+        assert!((breakdown.housing - 10800.0).abs() < 1e-6);
+        assert!((breakdown.food - 21600.0 * 0.25 * 0.9).abs() < 1e-6);
+        assert!((breakdown.other - 21600.0 * 0.10).abs() < 1e-6);
+        assert_eq!(breakdown.currency, "USD");
+    }
+
+    #[test]
+    fn test_estimate_converts_to_usd() {
+        let estimator = estimator();
+
+        let toronto = estimator.estimate("ontario_major").unwrap();
+        let expected_housing = 2200.0 * 12.0 * 0.73 * 0.50 * 1.8;
+        assert!((toronto.housing - expected_housing).abs() < 1e-6);
+
+        let shanghai = estimator.estimate("shanghai").unwrap();
+        assert!((shanghai.total() - 97200.0 / 7.2).abs() < 1e-6);
+
+        assert!(estimator.estimate("atlantis").is_err());
+
+        // 分项占比合计为100%
+        let shares = CategoryShares::default();
+        let total =
+            shares.housing + shares.food + shares.transport + shares.utilities + shares.entertainment;
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
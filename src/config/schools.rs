@@ -0,0 +1,401 @@
+use std::{fmt, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    CostParams, FinancialParams, FundingAward, Location, Profile, ProfileType, WorkParams,
+};
+
+/// 默认学校配置文件路径
+pub const DEFAULT_SCHOOLS_PATH: &str = "config/schools.yaml";
+
+/// 内置的默认学校配置（用于生成配置文件）
+pub const BUNDLED_SCHOOLS_YAML: &str = include_str!("../../config/schools.yaml");
+
+/// 目标国家
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Country {
+    Us,
+    Canada,
+}
+
+impl Country {
+    /// 学校配置中金额使用的货币
+    pub fn currency(&self) -> &'static str {
+        match self {
+            Country::Us => "USD",
+            Country::Canada => "CAD",
+        }
+    }
+
+    /// 写入Profile位置信息时使用的国家名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Country::Us => "United States",
+            Country::Canada => "Canada",
+        }
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Country::Us => write!(f, "us"),
+            Country::Canada => write!(f, "canada"),
+        }
+    }
+}
+
+impl std::str::FromStr for Country {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "us" | "usa" | "united states" => Ok(Country::Us),
+            "canada" | "ca" => Ok(Country::Canada),
+            _ => Err(anyhow::anyhow!("Invalid country: '{}'", s)),
+        }
+    }
+}
+
+/// 学校分档
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SchoolTier {
+    /// 美国匹配院校
+    Target,
+    /// 美国保底院校
+    Safety,
+    /// 美国冲刺院校
+    Reach,
+    /// 加拿大基础院校
+    Basic,
+    /// 加拿大大城市院校
+    MajorCities,
+}
+
+impl SchoolTier {
+    /// 分档所属国家
+    pub fn country(&self) -> Country {
+        match self {
+            SchoolTier::Target | SchoolTier::Safety | SchoolTier::Reach => Country::Us,
+            SchoolTier::Basic | SchoolTier::MajorCities => Country::Canada,
+        }
+    }
+}
+
+impl fmt::Display for SchoolTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchoolTier::Target => write!(f, "target"),
+            SchoolTier::Safety => write!(f, "safety"),
+            SchoolTier::Reach => write!(f, "reach"),
+            SchoolTier::Basic => write!(f, "basic"),
+            SchoolTier::MajorCities => write!(f, "major_cities"),
+        }
+    }
+}
+
+impl std::str::FromStr for SchoolTier {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "target" => Ok(SchoolTier::Target),
+            "safety" => Ok(SchoolTier::Safety),
+            "reach" => Ok(SchoolTier::Reach),
+            "basic" => Ok(SchoolTier::Basic),
+            "major_cities" | "major-cities" => Ok(SchoolTier::MajorCities),
+            _ => Err(anyhow::anyhow!("Invalid school tier: '{}'", s)),
+        }
+    }
+}
+
+/// 排名信息
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Ranking {
+    /// CSRankings排名（美国学校）
+    #[serde(default)]
+    pub csrankings: Option<u32>,
+    /// 国内排名描述（加拿大学校）
+    #[serde(default)]
+    pub national:   Option<String>,
+    pub category:   String,
+}
+
+/// 录取要求
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AcademicRequirements {
+    pub gpa_min:                 f64,
+    pub gre_required:            bool,
+    #[serde(default)]
+    pub toefl_min:               Option<u32>,
+    #[serde(default)]
+    pub ielts_min:               Option<f64>,
+    #[serde(default)]
+    pub ielts_section_min:       Option<f64>,
+    #[serde(default)]
+    pub competition_level:       Option<String>,
+    #[serde(default)]
+    pub accepts_work_experience: bool,
+    #[serde(default)]
+    pub work_experience_bonus:   bool,
+    #[serde(default)]
+    pub dual_admission:          bool,
+}
+
+/// 项目详情
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgramDetails {
+    #[serde(default)]
+    pub total_credits:        Option<u32>,
+    #[serde(default)]
+    pub courses_required:     Option<u32>,
+    #[serde(default)]
+    pub program_type:         Option<String>,
+    pub min_semesters:        u32,
+    pub fast_track_semesters: u32,
+    pub thesis_required:      bool,
+    #[serde(default)]
+    pub coop_available:       bool,
+}
+
+/// 学校给出的总成本估算（本币）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EstimatedCosts {
+    pub three_semester_total: f64,
+    pub two_year_total:       f64,
+}
+
+/// 学校项目配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct School {
+    pub name:                  String,
+    pub short_name:            String,
+    pub website:               String,
+    pub program:               String,
+    /// 年学费（本币：美国USD，加拿大CAD）
+    pub tuition_per_year:      f64,
+    /// 对应 regions.yaml 中的地区key
+    pub region:                String,
+    pub ranking:               Ranking,
+    pub academic_requirements: AcademicRequirements,
+    pub program_details:       ProgramDetails,
+    #[serde(default)]
+    pub benefits:              Vec<String>,
+    /// 常见的奖学金、学费减免和TA/RA资助
+    #[serde(default)]
+    pub funding:               Vec<FundingAward>,
+    pub estimated_costs:       EstimatedCosts,
+}
+
+/// 美国学校分档
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UsSchools {
+    #[serde(default)]
+    pub target: Vec<School>,
+    #[serde(default)]
+    pub safety: Vec<School>,
+    #[serde(default)]
+    pub reach:  Vec<School>,
+}
+
+/// 加拿大学校分档
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CanadaSchools {
+    #[serde(default)]
+    pub basic:        Vec<School>,
+    #[serde(default)]
+    pub major_cities: Vec<School>,
+}
+
+/// 按国家分组的学校
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SchoolsByCountry {
+    #[serde(default)]
+    pub us:     UsSchools,
+    #[serde(default)]
+    pub canada: CanadaSchools,
+}
+
+/// schools.yaml 顶层结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SchoolsConfig {
+    pub schools: SchoolsByCountry,
+}
+
+/// 目录中的一条学校记录（附带国家和分档）
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchoolEntry {
+    pub country: Country,
+    pub tier:    SchoolTier,
+    pub school:  School,
+}
+
+impl SchoolEntry {
+    /// 按学期数推算的学习年数（向上取整）
+    pub fn study_years(&self) -> u32 { self.school.program_details.min_semesters.div_ceil(2) }
+
+    /// 学校对应的Profile位置信息
+    pub fn location(&self) -> Location {
+        Location {
+            country:  self.country.display_name().to_string(),
+            city:     None,
+            currency: self.country.currency().to_string(),
+            region:   Some(self.school.region.clone()),
+        }
+    }
+
+    /// 直接生成教育路径Profile，免去手动录入学校数据
+    pub fn to_profile(
+        &self,
+        financial_params: FinancialParams,
+        cost_params: CostParams,
+    ) -> Profile {
+        Profile::new(
+            format!("{} {}", self.school.short_name, self.school.program),
+            ProfileType::Education,
+            self.location(),
+            WorkParams {
+                start_delay:    self.study_years(),
+                duration_limit: None,
+            },
+            financial_params,
+        )
+        .with_cost_params(cost_params)
+        .with_funding(self.school.funding.clone())
+        .with_description(format!("{} - {}", self.school.name, self.school.program))
+    }
+}
+
+/// 学校目录
+#[derive(Debug, Clone, Default)]
+pub struct SchoolCatalog {
+    entries: Vec<SchoolEntry>,
+}
+
+impl SchoolCatalog {
+    /// 从YAML文件加载学校目录
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("读取学校配置失败: {}", path.display()))?;
+        Self::from_yaml_str(&content)
+            .with_context(|| format!("解析学校配置失败: {}", path.display()))
+    }
+
+    /// 从默认路径加载学校目录
+    pub fn load_default() -> Result<Self> { Self::load(DEFAULT_SCHOOLS_PATH) }
+
+    /// 从YAML字符串解析学校目录
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        let config: SchoolsConfig = serde_yaml::from_str(content)?;
+        Ok(Self::from(config))
+    }
+
+    /// 所有学校
+    pub fn entries(&self) -> &[SchoolEntry] { &self.entries }
+
+    /// 按全称或简称查找学校（不区分大小写）
+    pub fn find(&self, name: &str) -> Option<&SchoolEntry> {
+        let name = name.trim();
+        self.entries.iter().find(|entry| {
+            entry.school.name.eq_ignore_ascii_case(name)
+                || entry.school.short_name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    /// 按国家筛选
+    pub fn by_country(&self, country: Country) -> impl Iterator<Item = &SchoolEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.country == country)
+    }
+
+    /// 按分档筛选
+    pub fn by_tier(&self, tier: SchoolTier) -> impl Iterator<Item = &SchoolEntry> {
+        self.entries.iter().filter(move |entry| entry.tier == tier)
+    }
+}
+
+impl From<SchoolsConfig> for SchoolCatalog {
+    fn from(config: SchoolsConfig) -> Self {
+        let SchoolsByCountry { us, canada } = config.schools;
+        let tiers = [
+            (SchoolTier::Target, us.target),
+            (SchoolTier::Safety, us.safety),
+            (SchoolTier::Reach, us.reach),
+            (SchoolTier::Basic, canada.basic),
+            (SchoolTier::MajorCities, canada.major_cities),
+        ];
+
+        let entries = tiers
+            .into_iter()
+            .flat_map(|(tier, schools)| {
+                schools.into_iter().map(move |school| SchoolEntry {
+                    country: tier.country(),
+                    tier,
+                    school,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_bundled_schools() {
+        let catalog =
+            SchoolCatalog::from_yaml_str(include_str!("../../config/schools.yaml")).unwrap();
+
+        let tamu = catalog.find("tamu").unwrap();
+        assert_eq!(tamu.school.name, "Texas A&M University");
+        assert_eq!(tamu.tier, SchoolTier::Target);
+        assert_eq!(tamu.school.region, "texas");
+
+        let calgary = catalog.find("University of Calgary").unwrap();
+        assert_eq!(calgary.country, Country::Canada);
+        assert_eq!(calgary.school.ranking.csrankings, None);
+
+        assert_eq!(catalog.by_tier(SchoolTier::MajorCities).count(), 4);
+        assert!(
+            catalog
+                .by_country(Country::Us)
+                .all(|e| e.country == Country::Us)
+        );
+
+        let profile = tamu.to_profile(
+            FinancialParams {
+                initial_salary_usd: 90000.0,
+                salary_growth_rate: 0.04,
+                living_cost_usd:    24000.0,
+                living_cost_growth: 0.03,
+                tax_rate:           0.22,
+            },
+            CostParams::new(
+                tamu.school.estimated_costs.two_year_total,
+                tamu.study_years(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(profile.work_params.start_delay, 2);
+        assert_eq!(profile.cost_params.unwrap().total_cost_usd, 95200.0);
+
+        let austin = catalog.find("UT Austin").unwrap();
+        assert_eq!(austin.school.funding.len(), 2);
+        assert!(
+            austin
+                .school
+                .funding
+                .iter()
+                .all(|award| award.validate().is_ok())
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CanadaTaxRules, ChinaTaxRules, UsTaxRules};
+
+/// 默认税务配置文件路径
+pub const DEFAULT_TAXES_PATH: &str = "config/taxes.yaml";
+
+/// 内置的默认税务配置（用于生成配置文件）
+pub const BUNDLED_TAXES_YAML: &str = include_str!("../../config/taxes.yaml");
+
+/// taxes.yaml 顶层结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TaxConfig {
+    #[serde(default)]
+    pub us:     UsTaxRules,
+    #[serde(default)]
+    pub canada: CanadaTaxRules,
+    #[serde(default)]
+    pub china:  ChinaTaxRules,
+}
+
+impl TaxConfig {
+    /// 从YAML文件加载税务配置
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("读取税务配置失败: {}", path.display()))?;
+        Self::from_yaml_str(&content)
+            .with_context(|| format!("解析税务配置失败: {}", path.display()))
+    }
+
+    /// 从默认路径加载税务配置
+    pub fn load_default() -> Result<Self> { Self::load(DEFAULT_TAXES_PATH) }
+
+    /// 从YAML字符串解析税务配置
+    pub fn from_yaml_str(content: &str) -> Result<Self> { Ok(serde_yaml::from_str(content)?) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RegionsConfig;
+
+    #[test]
+    fn test_taxes_cover_all_regions() {
+        let taxes = TaxConfig::from_yaml_str(BUNDLED_TAXES_YAML).unwrap();
+        assert!(taxes.us.tax_year > 0 && !taxes.china.cities.is_empty());
+
+        // 每个地区都有州税、省税或城市社保配置
+        let regions =
+            RegionsConfig::from_yaml_str(include_str!("../../config/regions.yaml")).unwrap();
+        assert!(
+            regions
+                .regions
+                .us
+                .keys()
+                .all(|key| taxes.us.state(key).is_some())
+        );
+        assert!(
+            regions
+                .regions
+                .canada
+                .keys()
+                .all(|key| taxes.canada.province(key).is_some())
+        );
+        assert!(
+            regions
+                .china
+                .keys()
+                .all(|key| taxes.china.city(key).is_some())
+        );
+    }
+}
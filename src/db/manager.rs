@@ -4,7 +4,10 @@ use anyhow::Result;
 use diesel::{prelude::*, sqlite::SqliteConnection};
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 use dotenvy::dotenv;
-use crate::models::{Profile, ProfileDb, ProfileType, profiles};
+
+use crate::models::{
+    DieselProfileRepository, Profile, ProfileDbRecord, ProfileRepository, ProfileType, profiles,
+};
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
@@ -21,6 +24,10 @@ pub enum DatabaseError {
     ProfileNotFound,
     #[error("UUID parse error: {0}")]
     UuidParse(#[from] uuid::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Repository error: {0}")]
+    Repository(#[from] anyhow::Error),
 }
 
 /// 数据库连接管理器
@@ -43,13 +50,18 @@ impl DatabaseConnection {
             "data/profiles.db".to_string()
         });
 
-        let mut conn = SqliteConnection::establish(&database_url)?;
+        Self::establish_at(&database_url)
+    }
+
+    /// 连接指定的数据库（如 `:memory:`）并运行迁移
+    pub fn establish_at(database_url: &str) -> Result<Self, DatabaseError> {
+        let mut conn = SqliteConnection::establish(database_url)?;
 
         // 运行迁移
         MigrationHarness::run_pending_migrations(&mut conn, MIGRATIONS)
             .map_err(|e| DatabaseError::Migration(e.to_string()))?;
 
-        println!("✅ 数据库连接成功: {}", database_url);
+        eprintln!("✅ 数据库连接成功: {}", database_url);
 
         Ok(Self { conn })
     }
@@ -70,50 +82,41 @@ impl DatabaseManager {
         Ok(Self { db_conn })
     }
 
+    /// 测试用的内存数据库
+    #[cfg(test)]
+    pub(crate) fn in_memory() -> Result<Self, DatabaseError> {
+        let db_conn = DatabaseConnection::establish_at(":memory:")?;
+        Ok(Self { db_conn })
+    }
+
+    /// 基于当前连接的Profile仓库
+    fn repository(&mut self) -> DieselProfileRepository<'_> {
+        DieselProfileRepository::new(self.db_conn.connection())
+    }
+
     /// 保存Profile到数据库
     pub fn save_profile(&mut self, profile: &Profile) -> Result<(), DatabaseError> {
-        let db_profile = profile.to_db();
-        diesel::insert_into(profiles::table)
-            .values(&db_profile)
-            .execute(self.db_conn.connection())?;
-        Ok(())
+        Ok(self.repository().save(profile)?)
     }
 
     /// 获取所有Profile
     pub fn get_profiles(&mut self) -> Result<Vec<Profile>, DatabaseError> {
-        let db_profiles: Vec<ProfileDb> = profiles::table.load(self.db_conn.connection())?;
-
-        let mut profiles = Vec::new();
-        for db_profile in db_profiles {
-            match Profile::from_db(db_profile) {
-                Ok(profile) => profiles.push(profile),
-                Err(e) => eprintln!("⚠️ 解析Profile失败: {}", e),
-            }
-        }
-
-        Ok(profiles)
+        Ok(self.repository().find_all()?)
     }
 
-    /// 根据ID获取Profile
+    /// 根据ID获取Profile，记录无法解析时视为未找到
     pub fn get_profile(&mut self, id: &uuid::Uuid) -> Result<Option<Profile>, DatabaseError> {
-        let db_profile: Option<ProfileDb> = profiles::table
-            .find(id.to_string())
-            .first(self.db_conn.connection())
-            .optional()?;
-
-        match db_profile {
-            Some(db_prof) => {
-                let profile =
-                    Profile::from_db(db_prof).map_err(|_| DatabaseError::ProfileNotFound)?;
-                Ok(Some(profile))
-            }
-            None => Ok(None),
-        }
+        self.repository()
+            .find_by_id(*id)
+            .map_err(|e| match e.downcast::<diesel::result::Error>() {
+                Ok(e) => DatabaseError::Query(e),
+                Err(_) => DatabaseError::ProfileNotFound,
+            })
     }
 
     /// 更新Profile
     pub fn update_profile(&mut self, profile: &Profile) -> Result<(), DatabaseError> {
-        let db_profile = profile.to_db();
+        let db_profile = ProfileDbRecord::try_from(profile.clone())?;
         let profile_id = profile.id.to_string();
         let target = profiles::table.find(&profile_id);
         diesel::update(target)
@@ -124,9 +127,7 @@ impl DatabaseManager {
 
     /// 删除Profile
     pub fn delete_profile(&mut self, id: &uuid::Uuid) -> Result<(), DatabaseError> {
-        let target = profiles::table.find(id.to_string());
-        diesel::delete(target).execute(self.db_conn.connection())?;
-        Ok(())
+        Ok(self.repository().delete(*id)?)
     }
 
     /// 按名称搜索Profile
@@ -134,13 +135,13 @@ impl DatabaseManager {
         &mut self,
         name_pattern: &str,
     ) -> Result<Vec<Profile>, DatabaseError> {
-        let db_profiles: Vec<ProfileDb> = profiles::table
+        let db_profiles: Vec<ProfileDbRecord> = profiles::table
             .filter(profiles::name.like(format!("%{}%", name_pattern)))
             .load(self.db_conn.connection())?;
 
         let mut profiles = Vec::new();
         for db_profile in db_profiles {
-            match Profile::from_db(db_profile) {
+            match Profile::try_from(db_profile) {
                 Ok(profile) => profiles.push(profile),
                 Err(e) => eprintln!("⚠️ 解析Profile失败: {}", e),
             }
@@ -155,13 +156,13 @@ impl DatabaseManager {
         profile_type: ProfileType,
     ) -> Result<Vec<Profile>, DatabaseError> {
         let type_str = profile_type.to_string();
-        let db_profiles: Vec<ProfileDb> = profiles::table
+        let db_profiles: Vec<ProfileDbRecord> = profiles::table
             .filter(profiles::profile_type.eq(type_str))
             .load(self.db_conn.connection())?;
 
         let mut profiles = Vec::new();
         for db_profile in db_profiles {
-            match Profile::from_db(db_profile) {
+            match Profile::try_from(db_profile) {
                 Ok(profile) => profiles.push(profile),
                 Err(e) => eprintln!("⚠️ 解析Profile失败: {}", e),
             }
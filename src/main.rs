@@ -13,23 +13,42 @@
 // limitations under the License.
 
 use anyhow::Result;
+use clap::Parser;
 use dialoguer::Select;
 
 mod models;
 mod db;
 mod calculator;
+mod config;
 mod ui;
+mod cli;
+mod tui;
 
 use db::DatabaseManager;
-use calculator::ROICalculator;
-use ui::ProfileCreator;
+use ui::ProfileManager;
+use cli::{Cli, Commands};
 
 fn main() -> Result<()> {
-    let calculator = ROICalculator::default();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Commands::Calculate(args)) => cli::run_calculate(args),
+        Some(Commands::Compare(args)) => cli::run_compare(args),
+        Some(Commands::List(args)) => cli::run_list(args),
+        Some(Commands::Config(command)) => cli::run_config(command),
+        Some(Commands::Profile(command)) => cli::run_profile(*command),
+        Some(Commands::Tui) => tui::run_tui(),
+        None => run_interactive(),
+    }
+}
+
+/// 交互式菜单
+fn run_interactive() -> Result<()> {
+    let calculator = cli::base_calculator()?;
 
     // 初始化数据库
-    let db = DatabaseManager::new().map_err(|e| anyhow::Error::new(e))?;
-    let mut profile_creator = ProfileCreator::new(db);
+    let db = DatabaseManager::new()?;
+    let mut profile_manager = ProfileManager::new(db);
 
     println!("🎯 ROI Calculator - Profile版");
     println!("===========================");
@@ -37,8 +56,10 @@ fn main() -> Result<()> {
     loop {
         let actions = vec![
             "创建新Profile",
+            "编辑Profile",
             "查看已有Profile",
             "比较Profile",
+            "管理Profile（详情/删除）",
             "退出",
         ];
 
@@ -50,40 +71,58 @@ fn main() -> Result<()> {
         match action {
             0 => {
                 // 创建新Profile
-                profile_creator.create_profile()?;
+                profile_manager.create_profile()?;
             }
             1 => {
-                // 查看已有Profile
-                profile_creator.list_profiles()?;
+                // 编辑Profile
+                profile_manager.edit_profile()?;
             }
             2 => {
+                // 查看已有Profile
+                profile_manager.list_profiles()?;
+            }
+            3 => {
                 // 比较Profile
-                let profiles = profile_creator.select_profiles_for_comparison()?;
-                if profiles.len() == 2 {
-                    let profile1 = &profiles[0];
-                    let profile2 = &profiles[1];
-
+                let profiles = profile_manager.select_profiles_for_comparison()?;
+                if profiles.len() < 2 {
+                    println!("⚠️ 至少需要选择2个Profile");
+                } else {
                     println!("\n🔄 开始分析比较...");
 
                     // 进行Profile比较分析
-                    calculator.analyze_profile_comparison(profile1, profile2);
+                    calculator.analyze_profile_comparison(&profiles);
+
+                    // 可选基准Profile，其余Profile按相对它的增量报告
+                    let mut options = vec!["不设基准"];
+                    options.extend(profiles.iter().map(|p| p.name.as_str()));
+                    let choice = Select::new()
+                        .with_prompt("选择对比基准（如留在国内工作）")
+                        .items(&options)
+                        .default(0)
+                        .interact()?;
+                    if choice > 0 {
+                        let baseline = &profiles[choice - 1];
+                        let others: Vec<_> = profiles
+                            .iter()
+                            .filter(|p| p.id != baseline.id)
+                            .cloned()
+                            .collect();
+                        calculator.analyze_baseline_comparison(baseline, &others);
+                    }
 
                     // 生成比较图表
-                    let chart_filename = format!(
-                        "{}_vs_{}_comparison.png",
-                        profile1.name.replace(" ", "_"),
-                        profile2.name.replace(" ", "_")
-                    );
-                    calculator.generate_profile_comparison_chart(
-                        profile1,
-                        profile2,
-                        &chart_filename,
-                    )?;
+                    let chart_filename =
+                        format!("{}_comparison.png", cli::comparison_file_stem(&profiles));
+                    calculator.generate_profile_comparison_chart(&profiles, &chart_filename)?;
 
                     println!("\n✅ 比较分析完成！");
                 }
             }
-            3 => {
+            4 => {
+                // 管理Profile
+                profile_manager.run()?;
+            }
+            5 => {
                 // 退出
                 println!("👋 再见！");
                 break;
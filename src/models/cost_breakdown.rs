@@ -0,0 +1,341 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// 费用类别（对应 costs.yaml 中的分类）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum CostCategory {
+    Tuition,
+    Application,
+    VisaLegal,
+    InsuranceMedical,
+    Transportation,
+    Accommodation,
+    FinancialServices,
+    Communication,
+    Study,
+    JobSearch,
+    Emergency,
+}
+
+impl CostCategory {
+    /// 一次性费用是否在最后一个学习年发生（其余在第一年发生）
+    pub fn charged_at_graduation(&self) -> bool { matches!(self, CostCategory::JobSearch) }
+}
+
+impl fmt::Display for CostCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            CostCategory::Tuition => "学费",
+            CostCategory::Application => "申请阶段",
+            CostCategory::VisaLegal => "签证法律",
+            CostCategory::InsuranceMedical => "保险医疗",
+            CostCategory::Transportation => "交通搬迁",
+            CostCategory::Accommodation => "住宿安置",
+            CostCategory::FinancialServices => "金融服务",
+            CostCategory::Communication => "通讯网络",
+            CostCategory::Study => "学习相关",
+            CostCategory::JobSearch => "求职费用",
+            CostCategory::Emergency => "应急储备",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// 费用发生频率
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CostFrequency {
+    OneTime,
+    PerYear,
+    PerMonth,
+}
+
+impl CostFrequency {
+    /// 根据 costs.yaml 的字段名推断频率：`_per_month` 按月，
+    /// `_per_year` 及 `_annual_fee` 等年费按年，其余为一次性费用
+    pub fn from_item_name(name: &str) -> Self {
+        if name.ends_with("_per_month") {
+            CostFrequency::PerMonth
+        } else if name.ends_with("_per_year") || name.contains("_annual_") {
+            CostFrequency::PerYear
+        } else {
+            CostFrequency::OneTime
+        }
+    }
+}
+
+/// 单个费用项
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CostItem {
+    pub category:   CostCategory,
+    pub name:       String,
+    pub amount_usd: f64,
+    pub frequency:  CostFrequency,
+}
+
+impl CostItem {
+    pub fn new(
+        category: CostCategory,
+        name: impl Into<String>,
+        amount_usd: f64,
+        frequency: CostFrequency,
+    ) -> Self {
+        Self {
+            category,
+            name: name.into(),
+            amount_usd,
+            frequency,
+        }
+    }
+
+    /// 该费用项在第 `year` 年（从1开始）的支出，学习期共 `study_years` 年
+    pub fn cost_for_year(&self, year: u32, study_years: u32) -> f64 {
+        if year == 0 || year > study_years {
+            return 0.0;
+        }
+
+        match self.frequency {
+            CostFrequency::OneTime => {
+                let charge_year = if self.category.charged_at_graduation() {
+                    study_years
+                } else {
+                    1
+                };
+                if year == charge_year {
+                    self.amount_usd
+                } else {
+                    0.0
+                }
+            }
+            CostFrequency::PerYear => self.amount_usd,
+            CostFrequency::PerMonth => self.amount_usd * 12.0,
+        }
+    }
+
+    /// 该费用项在第 `month` 个月（从1开始）的支出，学习期共 `study_months` 个月
+    ///
+    /// 年度学费在每学期开学时按学期数平分支付，其余年度费用按月平摊
+    pub fn cost_for_month(&self, month: u32, study_months: u32, term: &TermSchedule) -> f64 {
+        if month == 0 || month > study_months {
+            return 0.0;
+        }
+
+        match self.frequency {
+            CostFrequency::OneTime => {
+                let charge_month = if self.category.charged_at_graduation() {
+                    study_months
+                } else {
+                    1
+                };
+                if month == charge_month {
+                    self.amount_usd
+                } else {
+                    0.0
+                }
+            }
+            CostFrequency::PerYear if self.category == CostCategory::Tuition => {
+                if term.starts.contains(&month) {
+                    self.amount_usd / term.terms_per_year as f64
+                } else {
+                    0.0
+                }
+            }
+            CostFrequency::PerYear => self.amount_usd / 12.0,
+            CostFrequency::PerMonth => self.amount_usd,
+        }
+    }
+
+    /// 按学习月数计算的总支出（一次性费用计一次，年度费用按月折算）
+    pub fn cost_for_months(&self, months: u32) -> f64 {
+        if months == 0 {
+            return 0.0;
+        }
+
+        match self.frequency {
+            CostFrequency::OneTime => self.amount_usd,
+            CostFrequency::PerYear => self.amount_usd * months as f64 / 12.0,
+            CostFrequency::PerMonth => self.amount_usd * months as f64,
+        }
+    }
+}
+
+/// 学习期内的学期安排
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermSchedule {
+    /// 各学期开学的月份（从1开始）
+    pub starts:         Vec<u32>,
+    pub terms_per_year: u32,
+}
+
+/// 分项成本明细
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CostBreakdown {
+    pub items: Vec<CostItem>,
+}
+
+impl CostBreakdown {
+    pub fn new() -> Self { Self::default() }
+
+    /// 流式添加费用项
+    pub fn with_item(mut self, item: CostItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// 第 `year` 年应支付的费用合计
+    pub fn cost_for_year(&self, year: u32, study_years: u32) -> f64 {
+        self.items
+            .iter()
+            .map(|item| item.cost_for_year(year, study_years))
+            .sum()
+    }
+
+    /// 第 `month` 个月应支付的费用合计
+    pub fn cost_for_month(&self, month: u32, study_months: u32, term: &TermSchedule) -> f64 {
+        self.items
+            .iter()
+            .map(|item| item.cost_for_month(month, study_months, term))
+            .sum()
+    }
+
+    /// 指定类别在第 `month` 个月应支付的费用
+    pub fn category_cost_for_month(
+        &self,
+        category: CostCategory,
+        month: u32,
+        study_months: u32,
+        term: &TermSchedule,
+    ) -> f64 {
+        self.items
+            .iter()
+            .filter(|item| item.category == category)
+            .map(|item| item.cost_for_month(month, study_months, term))
+            .sum()
+    }
+
+    /// 整个学习期的费用合计
+    pub fn total(&self, study_years: u32) -> f64 {
+        (1..=study_years)
+            .map(|year| self.cost_for_year(year, study_years))
+            .sum()
+    }
+
+    /// 按学期安排逐月计入的费用合计，与按月模拟实际扣除的成本一致
+    pub fn total_for_schedule(&self, study_months: u32, term: &TermSchedule) -> f64 {
+        (1..=study_months)
+            .map(|month| self.cost_for_month(month, study_months, term))
+            .sum()
+    }
+
+    /// 按学习月数计算的费用合计，支持非整年的学习期
+    pub fn total_for_months(&self, months: u32) -> f64 {
+        self.items
+            .iter()
+            .map(|item| item.cost_for_months(months))
+            .sum()
+    }
+
+    /// 指定频率的费用项合计（按单次金额）
+    pub fn total_by_frequency(&self, frequency: CostFrequency) -> f64 {
+        self.items
+            .iter()
+            .filter(|item| item.frequency == frequency)
+            .map(|item| item.amount_usd)
+            .sum()
+    }
+
+    /// 指定类别在整个学习期的费用合计
+    pub fn category_total(&self, category: CostCategory, study_years: u32) -> f64 {
+        self.items
+            .iter()
+            .filter(|item| item.category == category)
+            .map(|item| {
+                (1..=study_years)
+                    .map(|year| item.cost_for_year(year, study_years))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_charged_when_they_occur() {
+        let breakdown = CostBreakdown::new()
+            .with_item(CostItem::new(
+                CostCategory::Tuition,
+                "tuition_per_year",
+                26000.0,
+                CostFrequency::PerYear,
+            ))
+            .with_item(CostItem::new(
+                CostCategory::VisaLegal,
+                "sevis_fee",
+                350.0,
+                CostFrequency::OneTime,
+            ))
+            .with_item(CostItem::new(
+                CostCategory::Communication,
+                "phone_plan_per_month",
+                55.0,
+                CostFrequency::PerMonth,
+            ))
+            .with_item(CostItem::new(
+                CostCategory::JobSearch,
+                "interview_travel",
+                1250.0,
+                CostFrequency::OneTime,
+            ));
+
+        assert_eq!(breakdown.cost_for_year(1, 2), 26000.0 + 350.0 + 660.0);
+        assert_eq!(breakdown.cost_for_year(2, 2), 26000.0 + 660.0 + 1250.0);
+        assert_eq!(breakdown.cost_for_year(3, 2), 0.0);
+        assert_eq!(
+            breakdown.total(2),
+            breakdown.cost_for_year(1, 2) + breakdown.cost_for_year(2, 2)
+        );
+        assert_eq!(breakdown.total_for_months(24), breakdown.total(2));
+        assert_eq!(
+            breakdown.total_for_months(18),
+            26000.0 * 1.5 + 350.0 + 55.0 * 18.0 + 1250.0
+        );
+
+        // 两学期制：学费在开学月按半年支付
+        let term = TermSchedule {
+            starts:         vec![1, 5, 13, 17],
+            terms_per_year: 2,
+        };
+        assert_eq!(
+            breakdown.cost_for_month(1, 24, &term),
+            13000.0 + 350.0 + 55.0
+        );
+        assert_eq!(breakdown.cost_for_month(2, 24, &term), 55.0);
+        assert_eq!(breakdown.cost_for_month(24, 24, &term), 55.0 + 1250.0);
+        assert_eq!(breakdown.cost_for_month(25, 24, &term), 0.0);
+        assert!((breakdown.total_for_schedule(24, &term) - breakdown.total(2)).abs() < 1e-6);
+
+        assert_eq!(
+            CostFrequency::from_item_name("credit_card_annual_fee"),
+            CostFrequency::PerYear
+        );
+        assert_eq!(
+            CostFrequency::from_item_name("sevis_fee"),
+            CostFrequency::OneTime
+        );
+
+        // 16个月三个学期：学费按学期支付，多于按月折算的金额
+        let term = TermSchedule {
+            starts:         vec![1, 5, 13],
+            terms_per_year: 2,
+        };
+        assert_eq!(
+            breakdown.total_for_schedule(16, &term),
+            13000.0 * 3.0 + 350.0 + 55.0 * 16.0 + 1250.0
+        );
+    }
+}
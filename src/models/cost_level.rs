@@ -0,0 +1,152 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{CostBreakdown, CostCategory};
+use crate::config::{BUNDLED_COSTS_YAML, BUNDLED_REGIONS_YAML, CostsConfig, RegionsConfig};
+
+/// 基础生活费在各分项上的占比（regions.yaml 的 category_shares）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CategoryShares {
+    pub housing:       f64,
+    pub food:          f64,
+    pub transport:     f64,
+    pub utilities:     f64,
+    pub entertainment: f64,
+}
+
+impl Default for CategoryShares {
+    /// 内置 regions.yaml 中的占比
+    fn default() -> Self {
+        RegionsConfig::from_yaml_str(BUNDLED_REGIONS_YAML)
+            .expect("内置地区配置无效")
+            .category_shares
+    }
+}
+
+/// 成本档位
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CostLevel {
+    /// 节约型
+    Budget,
+    /// 标准型
+    #[default]
+    Standard,
+    /// 舒适型
+    Comfortable,
+}
+
+impl CostLevel {
+    pub const ALL: [CostLevel; 3] = [
+        CostLevel::Budget,
+        CostLevel::Standard,
+        CostLevel::Comfortable,
+    ];
+
+    /// 中文名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            CostLevel::Budget => "节约型",
+            CostLevel::Standard => "标准型",
+            CostLevel::Comfortable => "舒适型",
+        }
+    }
+}
+
+impl fmt::Display for CostLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostLevel::Budget => write!(f, "Budget"),
+            CostLevel::Standard => write!(f, "Standard"),
+            CostLevel::Comfortable => write!(f, "Comfortable"),
+        }
+    }
+}
+
+impl std::str::FromStr for CostLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "budget" => Ok(CostLevel::Budget),
+            "standard" => Ok(CostLevel::Standard),
+            "comfortable" => Ok(CostLevel::Comfortable),
+            _ => Err(anyhow::anyhow!("Invalid cost level: '{}'", s)),
+        }
+    }
+}
+
+/// 档位调整系数（对应 costs.yaml 中的 adjustment_factors）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct AdjustmentFactors {
+    pub accommodation: f64,
+    pub food:          f64,
+    pub entertainment: f64,
+    pub emergency:     f64,
+    pub job_search:    f64,
+}
+
+impl AdjustmentFactors {
+    /// 年生活成本的综合调整系数：按分项占比加权，交通和公用事业不随档位变化
+    pub fn living_cost_factor(&self, shares: &CategoryShares) -> f64 {
+        shares.housing * self.accommodation
+            + shares.food * self.food
+            + shares.entertainment * self.entertainment
+            + shares.transport
+            + shares.utilities
+    }
+
+    /// 分项成本中对应类别的调整系数
+    pub fn category_factor(&self, category: CostCategory) -> f64 {
+        match category {
+            CostCategory::Accommodation => self.accommodation,
+            CostCategory::Emergency => self.emergency,
+            CostCategory::JobSearch => self.job_search,
+            _ => 1.0,
+        }
+    }
+
+    /// 按档位调整分项成本明细
+    pub fn adjust_breakdown(&self, breakdown: &CostBreakdown) -> CostBreakdown {
+        let mut adjusted = breakdown.clone();
+        for item in &mut adjusted.items {
+            item.amount_usd *= self.category_factor(item.category);
+        }
+        adjusted
+    }
+}
+
+/// 单个档位的配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CostLevelSettings {
+    pub adjustment_factors: AdjustmentFactors,
+}
+
+/// 三个档位的调整系数表
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CostLevels {
+    pub budget:      CostLevelSettings,
+    pub standard:    CostLevelSettings,
+    pub comfortable: CostLevelSettings,
+}
+
+impl CostLevels {
+    /// 指定档位的调整系数
+    pub fn factors(&self, level: CostLevel) -> &AdjustmentFactors {
+        match level {
+            CostLevel::Budget => &self.budget.adjustment_factors,
+            CostLevel::Standard => &self.standard.adjustment_factors,
+            CostLevel::Comfortable => &self.comfortable.adjustment_factors,
+        }
+    }
+}
+
+impl Default for CostLevels {
+    /// 内置 costs.yaml 中的档位
+    fn default() -> Self {
+        CostsConfig::from_yaml_str(BUNDLED_COSTS_YAML)
+            .expect("内置费用配置无效")
+            .cost_levels
+    }
+}
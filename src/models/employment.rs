@@ -0,0 +1,221 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::scenario::PROBABILITY_TOLERANCE;
+
+/// 开始工作前求职 `months` 个月的概率
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct SearchOutcome {
+    pub months:      u32,
+    pub probability: f64,
+}
+
+/// 解析 "<月数>[:<概率>]"，如 "3:0.4"，省略概率时为1
+impl std::str::FromStr for SearchOutcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (months, probability) = match s.trim().split_once(':') {
+            Some((months, probability)) => (months, Some(probability)),
+            None => (s.trim(), None),
+        };
+        Ok(SearchOutcome {
+            months:      months
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("无效的求职月数: '{}'", months))?,
+            probability: probability.map_or(Ok(1.0), |p| {
+                p.trim()
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("无效的求职概率: '{}'", p))
+            })?,
+        })
+    }
+}
+
+/// 工作期间的裁员风险
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct LayoffRisk {
+    /// 每年被裁员的概率
+    pub annual_rate:         f64,
+    /// 被裁员后重新就业所需月数
+    #[serde(default = "default_reemployment_months")]
+    pub reemployment_months: u32,
+}
+
+fn default_reemployment_months() -> u32 { 4 }
+
+impl LayoffRisk {
+    /// 按年裁员率折算的每月裁员概率
+    pub fn monthly_rate(&self) -> f64 { 1.0 - (1.0 - self.annual_rate).powf(1.0 / 12.0) }
+}
+
+/// 解析 "<年裁员率>[:<再就业月数>]"，如 "0.05:6"，再就业月数默认4个月
+impl std::str::FromStr for LayoffRisk {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rate, months) = match s.trim().split_once(':') {
+            Some((rate, months)) => (rate, Some(months)),
+            None => (s.trim(), None),
+        };
+        Ok(LayoffRisk {
+            annual_rate:         rate
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("无效的年裁员率: '{}'", rate))?,
+            reemployment_months: months.map_or(Ok(default_reemployment_months()), |m| {
+                m.trim()
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("无效的再就业月数: '{}'", m))
+            })?,
+        })
+    }
+}
+
+/// 求职期和失业风险：求职和失业期间照常支出生活成本，没有收入
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct EmploymentRisk {
+    /// 开始工作前求职月数的分布，为空表示立即入职
+    pub job_search: Vec<SearchOutcome>,
+    pub layoff:     Option<LayoffRisk>,
+}
+
+impl EmploymentRisk {
+    pub fn is_default(&self) -> bool { *self == Self::default() }
+
+    pub fn validate(&self) -> Result<()> {
+        if !self.job_search.is_empty() {
+            if self
+                .job_search
+                .iter()
+                .any(|outcome| !(0.0..=1.0).contains(&outcome.probability))
+            {
+                return Err(anyhow::anyhow!("求职概率必须在0到1之间"));
+            }
+            let total: f64 = self.job_search.iter().map(|o| o.probability).sum();
+            if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+                return Err(anyhow::anyhow!(
+                    "求职月数的概率之和必须为100%，当前为 {:.1}%",
+                    total * 100.0
+                ));
+            }
+        }
+        if let Some(layoff) = &self.layoff
+            && !(0.0..1.0).contains(&layoff.annual_rate)
+        {
+            return Err(anyhow::anyhow!("年裁员率必须在0到1之间"));
+        }
+        Ok(())
+    }
+
+    /// 期望求职月数
+    pub fn expected_search_months(&self) -> f64 {
+        self.job_search
+            .iter()
+            .map(|outcome| outcome.months as f64 * outcome.probability)
+            .sum()
+    }
+
+    /// 从开始工作起前 `months` 个月各月在职的概率
+    ///
+    /// 求职者按求职月数分布入职，在职者每月按裁员率失业，
+    /// 失业后经过再就业月数重新入职
+    pub fn employment_probabilities(&self, months: u32) -> Vec<f64> {
+        let reemployment = self.layoff.map_or(0, |layoff| layoff.reemployment_months);
+        let longest = self
+            .job_search
+            .iter()
+            .map(|outcome| outcome.months)
+            .max()
+            .unwrap_or(0)
+            .max(reemployment) as usize;
+        // searching[r]：本月起还需 r 个月才能入职的概率
+        let mut searching = vec![0.0; longest + 1];
+        let mut employed = if self.job_search.is_empty() { 1.0 } else { 0.0 };
+        for outcome in &self.job_search {
+            match outcome.months {
+                0 => employed += outcome.probability,
+                months => searching[months as usize] += outcome.probability,
+            }
+        }
+        let layoff_rate = self.layoff.map_or(0.0, |layoff| layoff.monthly_rate());
+
+        (0..months)
+            .map(|_| {
+                if reemployment > 0 {
+                    let laid_off = employed * layoff_rate;
+                    employed -= laid_off;
+                    searching[reemployment as usize] += laid_off;
+                }
+                let probability = employed;
+                if longest > 0 {
+                    employed += searching.remove(1);
+                    searching.push(0.0);
+                }
+                probability
+            })
+            .collect()
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        let search = if self.job_search.is_empty() {
+            "毕业即入职".to_string()
+        } else {
+            format!("求职期望 {:.1} 个月", self.expected_search_months())
+        };
+        match &self.layoff {
+            Some(layoff) => format!(
+                "{}，年裁员率 {:.1}%，再就业 {} 个月",
+                search,
+                layoff.annual_rate * 100.0,
+                layoff.reemployment_months
+            ),
+            None => search,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_employment_probabilities() {
+        assert!(
+            EmploymentRisk::default()
+                .employment_probabilities(12)
+                .iter()
+                .all(|p| *p == 1.0)
+        );
+
+        // 30%立即入职，70%求职3个月
+        let search = EmploymentRisk {
+            job_search: vec!["0:0.3".parse().unwrap(), "3:0.7".parse().unwrap()],
+            layoff:     None,
+        };
+        search.validate().unwrap();
+        assert!((search.expected_search_months() - 2.1).abs() < 1e-9);
+        let probabilities = search.employment_probabilities(6);
+        assert!((probabilities[2] - 0.3).abs() < 1e-9);
+        assert!((probabilities[3] - 1.0).abs() < 1e-9);
+
+        // 稳态下失业时间约为 年裁员率 × 再就业月数 / 12
+        let layoff = EmploymentRisk {
+            job_search: Vec::new(),
+            layoff:     Some("0.12:6".parse().unwrap()),
+        };
+        let probabilities = layoff.employment_probabilities(240);
+        assert!(probabilities[0] < 1.0);
+        assert!((probabilities[239] - (1.0 - 0.06)).abs() < 0.01);
+
+        let invalid = EmploymentRisk {
+            job_search: vec!["3:0.5".parse().unwrap()],
+            layoff:     None,
+        };
+        assert!(invalid.validate().is_err());
+        assert!("0.05:x".parse::<LayoffRisk>().is_err());
+    }
+}
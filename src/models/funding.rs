@@ -0,0 +1,234 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::Money;
+
+/// 资助形式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AwardKind {
+    /// 固定金额奖学金，在各学期开学时平均抵扣
+    Scholarship { amount: Money },
+    /// 按比例减免学费
+    TuitionWaiver { fraction: f64 },
+    /// 助教/助研（TA/RA）月津贴，学习期内每月发放并计税
+    Stipend { monthly: Money },
+}
+
+impl AwardKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AwardKind::Scholarship { .. } => "奖学金",
+            AwardKind::TuitionWaiver { .. } => "学费减免",
+            AwardKind::Stipend { .. } => "助教/助研津贴",
+        }
+    }
+}
+
+/// 教育路径的资助，附获得概率
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct FundingAward {
+    #[serde(flatten)]
+    pub kind:        AwardKind,
+    /// 获得概率，1表示已确认
+    #[serde(default = "certain")]
+    pub probability: f64,
+}
+
+fn certain() -> f64 { 1.0 }
+
+impl FundingAward {
+    pub fn is_confirmed(&self) -> bool { self.probability >= 1.0 }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.probability <= 0.0 || self.probability > 1.0 {
+            return Err(anyhow::anyhow!("资助的获得概率必须在0到1之间"));
+        }
+        match self.kind {
+            AwardKind::TuitionWaiver { fraction } if !(0.0..=1.0).contains(&fraction) => {
+                Err(anyhow::anyhow!("学费减免比例必须在0到1之间"))
+            }
+            AwardKind::Scholarship { amount: money } | AwardKind::Stipend { monthly: money }
+                if money.amount <= 0.0 =>
+            {
+                Err(anyhow::anyhow!("资助金额必须大于0"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        let amount = match self.kind {
+            AwardKind::Scholarship { amount } => amount.to_string(),
+            AwardKind::TuitionWaiver { fraction } => format!("{:.0}%学费", fraction * 100.0),
+            AwardKind::Stipend { monthly } => format!("{}/月", monthly),
+        };
+        format!(
+            "{} {}（概率 {:.0}%）",
+            self.kind.label(),
+            amount,
+            self.probability * 100.0
+        )
+    }
+}
+
+/// 解析 "<scholarship|waiver|stipend>:<金额或比例>[:<概率>]"，
+/// 如 "scholarship:10000"、"waiver:0.5:0.3"、"stipend:2200:0.4"
+impl std::str::FromStr for FundingAward {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(':').map(str::trim).collect();
+        let (kind, value, probability) = match parts.as_slice() {
+            [kind, value] => (*kind, *value, None),
+            [kind, value, probability] => (*kind, *value, Some(*probability)),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "无效的资助: '{}'，格式为 <scholarship|waiver|stipend>:<金额或比例>[:<概率>]",
+                    s
+                ));
+            }
+        };
+        let fraction = || {
+            value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("无效的减免比例: '{}'", value))
+        };
+        let kind = match kind.to_ascii_lowercase().as_str() {
+            "scholarship" => AwardKind::Scholarship {
+                amount: value.parse()?,
+            },
+            "waiver" | "tuition_waiver" => AwardKind::TuitionWaiver {
+                fraction: fraction()?,
+            },
+            "stipend" => AwardKind::Stipend {
+                monthly: value.parse()?,
+            },
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "未知的资助类型: '{}'，可选 scholarship | waiver | stipend",
+                    kind
+                ));
+            }
+        };
+        let award = FundingAward {
+            kind,
+            probability: probability.map_or(Ok(1.0), |p| {
+                p.parse()
+                    .map_err(|_| anyhow::anyhow!("无效的获得概率: '{}'", p))
+            })?,
+        };
+        award.validate()?;
+        Ok(award)
+    }
+}
+
+/// 资助计入计算的方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FundingMode {
+    /// 只计入已确认（概率为1）的资助
+    #[default]
+    Confirmed,
+    /// 按获得概率计入期望值
+    Expected,
+    /// 不计入资助
+    None,
+}
+
+impl FundingMode {
+    /// 资助金额计入的权重
+    pub fn weight(&self, award: &FundingAward) -> f64 {
+        match self {
+            FundingMode::Confirmed if award.is_confirmed() => 1.0,
+            FundingMode::Expected => award.probability,
+            _ => 0.0,
+        }
+    }
+
+    /// 按 已确认 → 期望值 → 不计入 循环切换
+    pub fn next(&self) -> Self {
+        match self {
+            FundingMode::Confirmed => FundingMode::Expected,
+            FundingMode::Expected => FundingMode::None,
+            FundingMode::None => FundingMode::Confirmed,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FundingMode::Confirmed => "仅计已确认资助",
+            FundingMode::Expected => "资助按概率期望值",
+            FundingMode::None => "不计资助",
+        }
+    }
+}
+
+impl fmt::Display for FundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FundingMode::Confirmed => "confirmed",
+            FundingMode::Expected => "expected",
+            FundingMode::None => "none",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl std::str::FromStr for FundingMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "confirmed" => Ok(FundingMode::Confirmed),
+            "expected" => Ok(FundingMode::Expected),
+            "none" => Ok(FundingMode::None),
+            _ => Err(anyhow::anyhow!(
+                "未知的资助计入方式: '{}'，可选 confirmed | expected | none",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Currency;
+
+    #[test]
+    fn test_funding_award() {
+        let stipend: FundingAward = "stipend:2200:0.4".parse().unwrap();
+        assert_eq!(
+            stipend.kind,
+            AwardKind::Stipend {
+                monthly: Money::usd(2200.0),
+            }
+        );
+        let waiver: FundingAward = "waiver:0.5".parse().unwrap();
+        assert!(waiver.is_confirmed());
+
+        // 已确认的资助在两种方式下都全额计入，未确认的只计期望值
+        assert_eq!(FundingMode::Confirmed.weight(&waiver), 1.0);
+        assert_eq!(FundingMode::Confirmed.weight(&stipend), 0.0);
+        assert_eq!(FundingMode::Expected.weight(&stipend), 0.4);
+        assert_eq!(FundingMode::None.weight(&waiver), 0.0);
+
+        // 配置文件中的写法
+        let award: FundingAward =
+            serde_yaml::from_str("type: scholarship\namount: 5000 CAD\nprobability: 0.5").unwrap();
+        assert_eq!(
+            award.kind,
+            AwardKind::Scholarship {
+                amount: Money::new(5000.0, Currency::Cad),
+            }
+        );
+
+        assert!("waiver:1.5".parse::<FundingAward>().is_err());
+        assert!("stipend:2200:0".parse::<FundingAward>().is_err());
+        assert!("grant:100".parse::<FundingAward>().is_err());
+    }
+}
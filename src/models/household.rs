@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{BUNDLED_COSTS_YAML, CostsConfig};
+
+/// 学龄前（需托管）的年龄上限
+const PRESCHOOL_AGE: u32 = 6;
+/// 成年年龄，之后不再计入子女费用
+const ADULT_AGE: u32 = 18;
+
+/// 配偶相关费用（对应 costs.yaml 中的 special_costs.spouse）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpouseCosts {
+    pub visa_fee:             f64,
+    pub insurance_per_year:   f64,
+    pub living_cost_increase: f64,
+}
+
+/// 子女相关费用（对应 costs.yaml 中的 special_costs.children）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChildrenCosts {
+    pub k12_tuition_per_year: f64,
+    pub childcare_per_month:  f64,
+    pub living_cost_increase: f64,
+}
+
+/// 随行家属费用表（USD）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpecialCosts {
+    pub spouse:   SpouseCosts,
+    pub children: ChildrenCosts,
+}
+
+impl Default for SpecialCosts {
+    /// 内置 costs.yaml 中的费用表
+    fn default() -> Self {
+        CostsConfig::from_yaml_str(BUNDLED_COSTS_YAML)
+            .expect("内置费用配置无效")
+            .special_costs
+    }
+}
+
+/// 子女信息
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Child {
+    /// 第1年年初的年龄
+    pub age: u32,
+}
+
+impl Child {
+    /// 第 `year` 年（从1开始）的年龄
+    pub fn age_in_year(&self, year: u32) -> u32 { self.age + year.saturating_sub(1) }
+}
+
+/// 家庭构成
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Household {
+    #[serde(default)]
+    pub spouse:   bool,
+    #[serde(default)]
+    pub children: Vec<Child>,
+}
+
+impl Household {
+    /// 是否为单身（无随行家属）
+    pub fn is_single(&self) -> bool { !self.spouse && self.children.is_empty() }
+
+    /// 第 `year` 年的生活成本倍数
+    pub fn living_cost_multiplier(&self, year: u32, costs: &SpecialCosts) -> f64 {
+        let spouse = if self.spouse {
+            costs.spouse.living_cost_increase
+        } else {
+            0.0
+        };
+        let minors = self.minor_children(year) as f64;
+
+        1.0 + spouse + minors * costs.children.living_cost_increase
+    }
+
+    /// 第 `year` 年未成年子女的数量
+    pub fn minor_children(&self, year: u32) -> usize {
+        self.children
+            .iter()
+            .filter(|child| child.age_in_year(year) < ADULT_AGE)
+            .count()
+    }
+
+    /// 第 `year` 年的家属额外费用（签证、保险、学费、托管）
+    ///
+    /// `studying`
+    /// 为真时计入留学期间才发生的费用（陪读签证、配偶保险、国际学生K-12学费）
+    pub fn dependent_costs_for_year(&self, year: u32, studying: bool, costs: &SpecialCosts) -> f64 {
+        let mut total = 0.0;
+
+        if self.spouse && studying {
+            if year == 1 {
+                total += costs.spouse.visa_fee;
+            }
+            total += costs.spouse.insurance_per_year;
+        }
+
+        for child in &self.children {
+            let age = child.age_in_year(year);
+            if age < PRESCHOOL_AGE {
+                total += costs.children.childcare_per_month * 12.0;
+            } else if age < ADULT_AGE && studying {
+                total += costs.children.k12_tuition_per_year;
+            }
+        }
+
+        total
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        match (self.spouse, self.children.len()) {
+            (false, 0) => "单身".to_string(),
+            (true, 0) => "配偶随行".to_string(),
+            (false, n) => format!("{}个子女", n),
+            (true, n) => format!("配偶 + {}个子女", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_household_costs() {
+        let costs = SpecialCosts::default();
+        let household = Household {
+            spouse:   true,
+            children: vec![Child { age: 4 }, Child { age: 17 }],
+        };
+
+        assert_eq!(household.living_cost_multiplier(1, &costs), 1.0 + 0.6 + 1.6);
+        // 第2年老二满18岁
+        assert_eq!(household.living_cost_multiplier(2, &costs), 1.0 + 0.6 + 0.8);
+
+        // 第1年: 配偶签证 + 保险 + 托管 + K-12学费
+        assert_eq!(
+            household.dependent_costs_for_year(1, true, &costs),
+            185.0 + 3000.0 + 18000.0 + 15000.0
+        );
+        // 工作期间只剩托管费用
+        assert_eq!(
+            household.dependent_costs_for_year(1, false, &costs),
+            18000.0
+        );
+        assert_eq!(Household::default().living_cost_multiplier(1, &costs), 1.0);
+    }
+}
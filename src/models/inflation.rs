@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::Currency;
+use crate::config::{BUNDLED_REGIONS_YAML, RegionsConfig};
+
+/// 各国年通胀率假设（默认与 regions.yaml 一致），用于折算实际值
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InflationRates {
+    pub us:     f64,
+    pub canada: f64,
+    pub china:  f64,
+}
+
+impl Default for InflationRates {
+    /// 内置 regions.yaml 中的通胀假设
+    fn default() -> Self {
+        RegionsConfig::from_yaml_str(BUNDLED_REGIONS_YAML)
+            .expect("内置地区配置无效")
+            .inflation
+    }
+}
+
+impl InflationRates {
+    /// 使用 `currency` 的国家的年通胀率
+    pub fn for_currency(&self, currency: Currency) -> f64 {
+        match currency {
+            Currency::Usd => self.us,
+            Currency::Cny => self.china,
+            Currency::Cad => self.canada,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{CurrencyRates, Money};
+
+/// 贷款来源
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LoanSource {
+    /// 国内银行的留学贷款
+    #[default]
+    Bank,
+    /// 美国私人贷款机构（如Prodigy、MPower）
+    Private,
+    /// 向家人借款
+    Family,
+}
+
+impl LoanSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LoanSource::Bank => "国内银行",
+            LoanSource::Private => "私人贷款机构",
+            LoanSource::Family => "家庭借款",
+        }
+    }
+}
+
+impl std::str::FromStr for LoanSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "bank" => Ok(LoanSource::Bank),
+            "private" => Ok(LoanSource::Private),
+            "family" => Ok(LoanSource::Family),
+            _ => Err(anyhow::anyhow!(
+                "未知的贷款来源: '{}'，可选 bank | private | family",
+                s
+            )),
+        }
+    }
+}
+
+/// 学生贷款：路径开始时一次性放款，按月计息，
+/// 学习期和宽限期内只计息不还款（利息计入本金），之后按月等额本息还款
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Loan {
+    pub source:        LoanSource,
+    pub principal:     Money,
+    /// 年利率
+    pub interest_rate: f64,
+    /// 学习期结束后开始还款前的宽限期月数
+    #[serde(default)]
+    pub grace_months:  u32,
+    /// 还款年限
+    pub term_years:    u32,
+}
+
+/// 贷款某个月的放款、利息、还款和月末余额（美元）
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoanMonth {
+    pub proceeds: f64,
+    pub interest: f64,
+    pub payment:  f64,
+    pub balance:  f64,
+}
+
+impl LoanMonth {
+    /// 按汇率系数折算为报告货币
+    pub fn scaled(self, factor: f64) -> Self {
+        Self {
+            proceeds: self.proceeds * factor,
+            interest: self.interest * factor,
+            payment:  self.payment * factor,
+            balance:  self.balance * factor,
+        }
+    }
+}
+
+impl std::ops::Add for LoanMonth {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            proceeds: self.proceeds + other.proceeds,
+            interest: self.interest + other.interest,
+            payment:  self.payment + other.payment,
+            balance:  self.balance + other.balance,
+        }
+    }
+}
+
+impl Loan {
+    pub fn validate(&self) -> Result<()> {
+        if self.principal.amount <= 0.0 {
+            return Err(anyhow::anyhow!("贷款本金必须大于0"));
+        }
+        if !(0.0..1.0).contains(&self.interest_rate) {
+            return Err(anyhow::anyhow!("贷款年利率必须在0到1之间"));
+        }
+        if self.term_years == 0 {
+            return Err(anyhow::anyhow!("还款年限必须大于0"));
+        }
+        Ok(())
+    }
+
+    /// 前 `months` 个月的还款计划，从第 `repay_start` 个月开始还款
+    pub fn schedule(&self, rates: &CurrencyRates, repay_start: u32, months: u32) -> Vec<LoanMonth> {
+        let rate = self.interest_rate / 12.0;
+        let mut balance = 0.0;
+        let mut payment = 0.0;
+        (1..=months)
+            .map(|month| {
+                let proceeds = if month == 1 {
+                    self.principal.to_usd(rates)
+                } else {
+                    0.0
+                };
+                balance += proceeds;
+                if month == repay_start {
+                    payment = amortized_payment(balance, rate, self.term_years * 12);
+                }
+                let interest = balance * rate;
+                balance += interest;
+                let paid = if month < repay_start {
+                    0.0
+                } else if balance - payment < 0.01 {
+                    // 最后一期还清剩余余额（含舍入误差）
+                    balance
+                } else {
+                    payment
+                };
+                balance -= paid;
+                LoanMonth {
+                    proceeds,
+                    interest,
+                    payment: paid,
+                    balance,
+                }
+            })
+            .collect()
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        format!(
+            "{} {}，年利率 {:.2}%，宽限期 {} 个月，{} 年还清",
+            self.source.label(),
+            self.principal,
+            self.interest_rate * 100.0,
+            self.grace_months,
+            self.term_years
+        )
+    }
+}
+
+/// 解析 "<来源>:<本金>:<年利率>[:<宽限期月数>[:<还款年数>]]"，
+/// 如 "private:60000:0.115" 或 "bank:400000CNY:0.04:6:10"
+impl std::str::FromStr for Loan {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(':').map(str::trim).collect();
+        let [source, principal, rate, rest @ ..] = parts.as_slice() else {
+            return Err(anyhow::anyhow!(
+                "无效的贷款: '{}'，格式为 <来源>:<本金>:<年利率>[:<宽限期月数>[:<还款年数>]]",
+                s
+            ));
+        };
+        let number = |index: usize, default: u32| -> Result<u32> {
+            rest.get(index).map_or(Ok(default), |value| {
+                value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("无效的贷款期限: '{}'", value))
+            })
+        };
+        let loan = Loan {
+            source:        source.parse()?,
+            principal:     principal.parse()?,
+            interest_rate: rate
+                .parse()
+                .map_err(|_| anyhow::anyhow!("无效的贷款利率: '{}'", rate))?,
+            grace_months:  number(0, 6)?,
+            term_years:    number(1, 10)?,
+        };
+        loan.validate()?;
+        Ok(loan)
+    }
+}
+
+/// 余额 `balance` 按月利率 `rate` 在 `months` 个月内等额本息还清的月供
+fn amortized_payment(balance: f64, rate: f64, months: u32) -> f64 {
+    if rate == 0.0 {
+        balance / months as f64
+    } else {
+        balance * rate / (1.0 - (1.0 + rate).powi(-(months as i32)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Currency;
+
+    #[test]
+    fn test_loan_schedule() {
+        let loan: Loan = "private:60000:0.12:6:10".parse().unwrap();
+        assert_eq!(loan.source, LoanSource::Private);
+        assert_eq!(loan.principal, Money::usd(60000.0));
+
+        // 学习24个月加宽限期6个月，第31个月开始还款
+        let months = loan.schedule(&CurrencyRates::default(), 31, 180);
+        assert_eq!(months[0].proceeds, 60000.0);
+        assert!((months[0].interest - 600.0).abs() < 1e-9);
+        assert_eq!(months[29].payment, 0.0);
+        assert!((months[29].balance - 60000.0 * 1.01f64.powi(30)).abs() < 1e-6);
+
+        // 等额本息，120个月后还清
+        assert!(months[30].payment > 0.0);
+        assert!((months[30].payment - months[100].payment).abs() < 1e-9);
+        assert!(months[149].balance.abs() < 1e-6);
+        assert_eq!(months[150].payment, 0.0);
+
+        // 人民币贷款按汇率折算
+        let bank: Loan = "bank:72000CNY:0.04".parse().unwrap();
+        assert_eq!(bank.principal.currency, Currency::Cny);
+        assert_eq!((bank.grace_months, bank.term_years), (6, 10));
+        let bank_months = bank.schedule(&CurrencyRates::default(), 1, 12);
+        assert!((bank_months[0].proceeds - 10000.0).abs() < 1e-6);
+
+        assert!("family:0:0.0".parse::<Loan>().is_err());
+        assert!("gift:1000:0.0".parse::<Loan>().is_err());
+    }
+}
@@ -1,2 +1,26 @@
+pub mod cost_breakdown;
+pub mod cost_level;
+pub mod employment;
+pub mod funding;
+pub mod household;
+pub mod inflation;
+pub mod loan;
+pub mod money;
 pub mod profile;
+pub mod scenario;
+pub mod tax;
+pub mod timeline;
+pub mod work_auth;
+pub use cost_breakdown::*;
+pub use cost_level::*;
+pub use employment::*;
+pub use funding::*;
+pub use household::*;
+pub use inflation::*;
+pub use loan::*;
+pub use money::*;
 pub use profile::*;
+pub use scenario::*;
+pub use tax::*;
+pub use timeline::*;
+pub use work_auth::*;
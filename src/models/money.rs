@@ -0,0 +1,369 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{BUNDLED_REGIONS_YAML, RegionsConfig};
+
+/// 支持的货币
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    #[default]
+    Usd,
+    Cny,
+    Cad,
+}
+
+impl Currency {
+    pub const ALL: [Currency; 3] = [Currency::Usd, Currency::Cny, Currency::Cad];
+
+    /// ISO货币代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Cny => "CNY",
+            Currency::Cad => "CAD",
+        }
+    }
+
+    /// 金额前缀符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Usd => "$",
+            Currency::Cny => "¥",
+            Currency::Cad => "C$",
+        }
+    }
+
+    /// 中文名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Currency::Usd => "美元",
+            Currency::Cny => "人民币",
+            Currency::Cad => "加元",
+        }
+    }
+
+    /// 按 USD → CNY → CAD 循环切换
+    pub fn next(&self) -> Currency {
+        match self {
+            Currency::Usd => Currency::Cny,
+            Currency::Cny => Currency::Cad,
+            Currency::Cad => Currency::Usd,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.code()) }
+}
+
+impl FromStr for Currency {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "USD" => Ok(Currency::Usd),
+            "CNY" | "RMB" => Ok(Currency::Cny),
+            "CAD" => Ok(Currency::Cad),
+            _ => Err(anyhow::anyhow!(
+                "不支持的货币: '{}' (可选: USD, CNY, CAD)",
+                s
+            )),
+        }
+    }
+}
+
+/// 货币换算表（默认与 regions.yaml 的 exchange_rates 一致）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurrencyRates {
+    /// 1美元折合人民币
+    pub usd_to_cny: f64,
+    /// 1加元折合美元
+    pub cad_to_usd: f64,
+}
+
+impl Default for CurrencyRates {
+    /// 内置 regions.yaml 中的汇率
+    fn default() -> Self {
+        RegionsConfig::from_yaml_str(BUNDLED_REGIONS_YAML)
+            .expect("内置地区配置无效")
+            .exchange_rates
+            .rates()
+    }
+}
+
+impl CurrencyRates {
+    /// USD/CNY、CAD/CNY 分别变动 `usd_cny`、`cad_cny` 后的换算表
+    pub fn moved(&self, usd_cny: f64, cad_cny: f64) -> CurrencyRates {
+        let usd_to_cny = self.usd_to_cny * (1.0 + usd_cny);
+        let cad_to_cny = self.cad_to_usd * self.usd_to_cny * (1.0 + cad_cny);
+        CurrencyRates {
+            usd_to_cny,
+            cad_to_usd: cad_to_cny / usd_to_cny,
+        }
+    }
+
+    /// 1单位指定货币折合多少美元
+    pub fn usd_per_unit(&self, currency: Currency) -> f64 {
+        match currency {
+            Currency::Usd => 1.0,
+            Currency::Cny => 1.0 / self.usd_to_cny,
+            Currency::Cad => self.cad_to_usd,
+        }
+    }
+
+    /// 将 `amount` 从 `from` 换算为 `to`（经美元中转）
+    pub fn convert(&self, amount: f64, from: Currency, to: Currency) -> f64 {
+        if from == to {
+            return amount;
+        }
+        amount * self.usd_per_unit(from) / self.usd_per_unit(to)
+    }
+}
+
+/// 汇率走势，以人民币计价的美元和加元汇率逐年变化
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FxPath {
+    /// 汇率保持不变
+    #[default]
+    Constant,
+    /// USD/CNY、CAD/CNY 每年按固定比例变化，正数表示人民币贬值
+    Trend { usd_cny: f64, cad_cny: f64 },
+}
+
+impl FxPath {
+    /// 每年的USD/CNY、CAD/CNY变动率
+    pub fn annual_change(&self) -> (f64, f64) {
+        match *self {
+            FxPath::Constant => (0.0, 0.0),
+            FxPath::Trend { usd_cny, cad_cny } => (usd_cny, cad_cny),
+        }
+    }
+
+    /// 在当前走势上叠加 `years` 年内的总变动，0.2表示期末汇率高20%
+    pub fn with_total_move(&self, usd_cny: f64, cad_cny: f64, years: u32) -> FxPath {
+        let periods = years.saturating_sub(1).max(1) as f64;
+        let (base_usd, base_cad) = self.annual_change();
+        let annual =
+            |base: f64, change: f64| (1.0 + base) * (1.0 + change).powf(1.0 / periods) - 1.0;
+        FxPath::Trend {
+            usd_cny: annual(base_usd, usd_cny),
+            cad_cny: annual(base_cad, cad_cny),
+        }
+    }
+}
+
+impl fmt::Display for FxPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FxPath::Constant => write!(f, "constant"),
+            FxPath::Trend { usd_cny, cad_cny } => write!(f, "trend:{},{}", usd_cny, cad_cny),
+        }
+    }
+}
+
+/// 格式: `constant`、`trend:<USD/CNY年变动>[,<CAD/CNY年变动>]`
+impl FromStr for FxPath {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s.trim().split_once(':').unwrap_or((s.trim(), ""));
+        let params = params
+            .split(',')
+            .filter(|param| !param.trim().is_empty())
+            .map(|param| {
+                param
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| anyhow::anyhow!("无效的汇率变动: '{}'", param))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        match (kind.to_lowercase().as_str(), params.as_slice()) {
+            ("constant", []) => Ok(FxPath::Constant),
+            ("trend", [usd_cny]) => Ok(FxPath::Trend {
+                usd_cny: *usd_cny,
+                cad_cny: 0.0,
+            }),
+            ("trend", [usd_cny, cad_cny]) => Ok(FxPath::Trend {
+                usd_cny: *usd_cny,
+                cad_cny: *cad_cny,
+            }),
+            _ => Err(anyhow::anyhow!(
+                "无效的汇率走势: '{}'，可选 constant | trend:<USD/CNY>[,<CAD/CNY>]",
+                s
+            )),
+        }
+    }
+}
+
+/// 带货币的金额，文件中写作数字（美元）或 "375000 CNY"
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "MoneyInput", into = "String")]
+pub struct Money {
+    pub amount:   f64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: f64, currency: Currency) -> Self { Self { amount, currency } }
+
+    pub fn usd(amount: f64) -> Self { Self::new(amount, Currency::Usd) }
+
+    /// 换算为指定货币
+    pub fn to(self, currency: Currency, rates: &CurrencyRates) -> Money {
+        Money::new(
+            rates.convert(self.amount, self.currency, currency),
+            currency,
+        )
+    }
+
+    /// 换算后的美元金额
+    pub fn to_usd(self, rates: &CurrencyRates) -> f64 { self.to(Currency::Usd, rates).amount }
+
+    /// 紧凑格式，如 $1.2M、-¥350.0K
+    pub fn compact(&self) -> String {
+        let symbol = self.currency.symbol();
+        let amount = self.amount.abs();
+        let sign = if self.amount < 0.0 { "-" } else { "" };
+        if amount < 0.01 {
+            format!("{}0", symbol)
+        } else if amount >= 1_000_000.0 {
+            format!("{}{}{:.1}M", sign, symbol, amount / 1_000_000.0)
+        } else if amount >= 1_000.0 {
+            format!("{}{}{:.1}K", sign, symbol, amount / 1_000.0)
+        } else {
+            format!("{}{}{:.0}", sign, symbol, amount)
+        }
+    }
+}
+
+/// 取整并加千分位，如 ¥1,234,567
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rounded = self.amount.abs().round() as u64;
+        let digits = rounded.to_string();
+        let mut grouped = String::new();
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(ch);
+        }
+
+        let sign = if self.amount < 0.0 && rounded > 0 {
+            "-"
+        } else {
+            ""
+        };
+        write!(f, "{}{}{}", sign, self.currency.symbol(), grouped)
+    }
+}
+
+/// 解析 "375000 CNY"、"375000cny"、"1.2e5 CNY" 等格式，未写货币时为美元
+impl FromStr for Money {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // 货币代码是末尾的字母，金额本身可能含指数（如 1.2e5）
+        let amount = s.trim_end_matches(|ch: char| ch.is_ascii_alphabetic());
+        let currency = &s[amount.len()..];
+        let amount = amount
+            .trim()
+            .replace(['_', ','], "")
+            .parse::<f64>()
+            .map_err(|_| anyhow::anyhow!("无效的金额: '{}'", s))?;
+        let currency = match currency.trim() {
+            "" => Currency::Usd,
+            code => code.parse()?,
+        };
+        Ok(Money::new(amount, currency))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MoneyInput {
+    Amount(f64),
+    Text(String),
+}
+
+impl TryFrom<MoneyInput> for Money {
+    type Error = anyhow::Error;
+
+    fn try_from(input: MoneyInput) -> Result<Self, Self::Error> {
+        match input {
+            MoneyInput::Amount(amount) => Ok(Money::usd(amount)),
+            MoneyInput::Text(text) => text.parse(),
+        }
+    }
+}
+
+impl From<Money> for String {
+    fn from(money: Money) -> Self { format!("{} {}", money.amount, money.currency) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fx_path() {
+        assert_eq!("constant".parse::<FxPath>().unwrap(), FxPath::Constant);
+        assert_eq!(
+            "trend:0.02".parse::<FxPath>().unwrap(),
+            FxPath::Trend {
+                usd_cny: 0.02,
+                cad_cny: 0.0,
+            }
+        );
+        assert!("trend".parse::<FxPath>().is_err());
+
+        // 10年内累计变动±20%：第1年为基准汇率，之后9年复利
+        let path = FxPath::Constant.with_total_move(0.2, -0.2, 10);
+        let (usd_cny, cad_cny) = path.annual_change();
+        assert!(((1.0 + usd_cny).powi(9) - 1.2).abs() < 1e-9);
+        assert!(((1.0 + cad_cny).powi(9) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_money() {
+        let rates = CurrencyRates::default();
+        let salary: Money = "375,000 CNY".parse().unwrap();
+        assert_eq!(salary, Money::new(375000.0, Currency::Cny));
+        assert!((salary.to_usd(&rates) - 375000.0 / 7.2).abs() < 1e-6);
+        assert_eq!("120000".parse::<Money>().unwrap(), Money::usd(120000.0));
+        assert_eq!("60000cad".parse::<Money>().unwrap().currency, Currency::Cad);
+        assert!("12 EUR".parse::<Money>().is_err());
+        assert_eq!(
+            "1e5 CNY".parse::<Money>().unwrap(),
+            Money::new(100000.0, Currency::Cny)
+        );
+        assert_eq!("1.2E5USD".parse::<Money>().unwrap(), Money::usd(120000.0));
+        let parsed: Money = serde_yaml::from_str("\"375000 CNY\"").unwrap();
+        assert_eq!(parsed, salary);
+        assert_eq!(
+            serde_yaml::from_str::<Money>("95000").unwrap(),
+            Money::usd(95000.0)
+        );
+
+        // 加元经美元换算为人民币
+        let cad = rates.convert(1.0, Currency::Cad, Currency::Cny);
+        assert!((cad - 0.73 * 7.2).abs() < 1e-9);
+
+        assert_eq!(
+            Money::new(-1234567.4, Currency::Cny).to_string(),
+            "-¥1,234,567"
+        );
+        assert_eq!(Money::new(1_260_000.0, Currency::Cad).compact(), "C$1.3M");
+        assert_eq!(Money::usd(-677200.0).compact(), "-$677.2K");
+
+        // 人民币贬值10%，加元兑人民币不变
+        let moved = rates.moved(0.1, 0.0);
+        assert!((moved.usd_to_cny - 7.92).abs() < 1e-9);
+        assert!((moved.convert(1.0, Currency::Cad, Currency::Cny) - cad).abs() < 1e-9);
+        assert_eq!(Money::usd(0.0).compact(), "$0");
+    }
+}
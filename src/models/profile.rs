@@ -6,6 +6,11 @@ use diesel::{prelude::*, sqlite::SqliteConnection};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{
+    CostBreakdown, CostCategory, CostLevel, Currency, EmploymentRisk, FundingAward, Household, Loan,
+    ScenarioSet, TermSchedule, Timeline, WorkAuthorization,
+};
+
 // Diesel schema definition
 diesel::table! {
     profiles (id) {
@@ -15,6 +20,7 @@ diesel::table! {
         location_country -> Text,
         location_city -> Nullable<Text>,
         location_currency -> Text,
+        location_region -> Nullable<Text>,
         work_start_delay -> Integer,
         work_duration_limit -> Nullable<Integer>,
         initial_salary_usd -> Double,
@@ -28,6 +34,15 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         description -> Nullable<Text>,
+        cost_breakdown -> Nullable<Text>,
+        cost_level -> Text,
+        household -> Nullable<Text>,
+        scenarios -> Nullable<Text>,
+        timeline -> Nullable<Text>,
+        loans -> Nullable<Text>,
+        funding -> Nullable<Text>,
+        work_authorization -> Nullable<Text>,
+        employment -> Nullable<Text>,
     }
 }
 
@@ -51,9 +66,9 @@ impl std::str::FromStr for ProfileType {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "Education" => Ok(ProfileType::Education),
-            "Work" => Ok(ProfileType::Work),
+        match s.to_ascii_lowercase().as_str() {
+            "education" => Ok(ProfileType::Education),
+            "work" => Ok(ProfileType::Work),
             _ => Err(anyhow::anyhow!("Invalid profile type: '{}'", s)),
         }
     }
@@ -65,6 +80,14 @@ pub struct Location {
     pub country:  String,
     pub city:     Option<String>,
     pub currency: String,
+    /// 地区key（对应 regions.yaml），用于匹配地区税表
+    #[serde(default)]
+    pub region:   Option<String>,
+}
+
+impl Location {
+    /// 当地货币，不支持的货币代码返回None
+    pub fn native_currency(&self) -> Option<Currency> { self.currency.parse().ok() }
 }
 
 /// 工作参数
@@ -89,6 +112,75 @@ pub struct FinancialParams {
 pub struct CostParams {
     pub total_cost_usd: f64,
     pub cost_duration:  u32,
+    /// 分项成本明细，存在时按费用实际发生的年份计入
+    #[serde(default)]
+    pub breakdown:      Option<CostBreakdown>,
+}
+
+impl CostParams {
+    /// 按总额平均分摊的成本参数
+    pub fn new(total_cost_usd: f64, cost_duration: u32) -> Result<Self> {
+        if total_cost_usd < 0.0 {
+            return Err(anyhow::anyhow!("总成本不能为负数"));
+        }
+        if cost_duration == 0 {
+            return Err(anyhow::anyhow!("成本分摊年数必须大于0"));
+        }
+        Ok(Self {
+            total_cost_usd,
+            cost_duration,
+            breakdown: None,
+        })
+    }
+
+    /// 由分项明细生成的成本参数，总成本按 `study_months` 个月的学习期和学期安排计算
+    pub fn itemized(
+        breakdown: CostBreakdown,
+        cost_duration: u32,
+        study_months: u32,
+        term: &TermSchedule,
+    ) -> Self {
+        Self {
+            total_cost_usd: breakdown.total_for_schedule(study_months, term),
+            cost_duration,
+            breakdown: Some(breakdown),
+        }
+    }
+
+    /// 年均成本
+    pub fn annual_cost(&self) -> f64 { self.total_cost_usd / self.cost_duration as f64 }
+
+    /// 第 `year` 年（从1开始）应支付的成本
+    pub fn cost_for_year(&self, year: u32) -> f64 {
+        match &self.breakdown {
+            Some(breakdown) => breakdown.cost_for_year(year, self.cost_duration),
+            None if year <= self.cost_duration => self.annual_cost(),
+            None => 0.0,
+        }
+    }
+
+    /// 第 `month` 个月（从1开始）应支付的成本，学习期共 `study_months` 个月
+    ///
+    /// 没有分项明细时，总成本在各学期开学时平均支付
+    pub fn cost_for_month(&self, month: u32, study_months: u32, term: &TermSchedule) -> f64 {
+        match &self.breakdown {
+            Some(breakdown) => breakdown.cost_for_month(month, study_months, term),
+            None if term.starts.contains(&month) => {
+                self.total_cost_usd / term.starts.len() as f64
+            }
+            None => 0.0,
+        }
+    }
+
+    /// 第 `month` 个月应支付的学费，没有分项明细时总成本均视为学费
+    pub fn tuition_for_month(&self, month: u32, study_months: u32, term: &TermSchedule) -> f64 {
+        match &self.breakdown {
+            Some(breakdown) => {
+                breakdown.category_cost_for_month(CostCategory::Tuition, month, study_months, term)
+            }
+            None => self.cost_for_month(month, study_months, term),
+        }
+    }
 }
 
 /// **核心 Profile 结构** - 统一的数据模型
@@ -102,6 +194,26 @@ pub struct Profile {
     pub financial_params: FinancialParams,
     pub cost_params: Option<CostParams>,
     pub first_year_opportunity_cost: Option<f64>,
+    pub cost_level: CostLevel,
+    pub household: Household,
+    /// 附加的情景集合，为空时使用标准情景
+    #[serde(default)]
+    pub scenarios: ScenarioSet,
+    /// 入学月份、学习期月数和学制
+    #[serde(default)]
+    pub timeline: Timeline,
+    /// 学生贷款，可组合多个来源
+    #[serde(default)]
+    pub loans: Vec<Loan>,
+    /// 奖学金、学费减免和助教/助研津贴
+    #[serde(default)]
+    pub funding: Vec<FundingAward>,
+    /// 毕业后的美国工作许可及H-1B抽签，None表示不考虑身份风险
+    #[serde(default)]
+    pub work_authorization: Option<WorkAuthorization>,
+    /// 开始工作前的求职期和工作期间的裁员风险
+    #[serde(default)]
+    pub employment: EmploymentRisk,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub description: Option<String>,
@@ -117,6 +229,7 @@ pub struct ProfileDbRecord {
     pub location_country: String,
     pub location_city: Option<String>,
     pub location_currency: String,
+    pub location_region: Option<String>,
     pub work_start_delay: i32,
     pub work_duration_limit: Option<i32>,
     pub initial_salary_usd: f64,
@@ -130,6 +243,15 @@ pub struct ProfileDbRecord {
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
     pub description: Option<String>,
+    pub cost_breakdown: Option<String>,
+    pub cost_level: String,
+    pub household: Option<String>,
+    pub scenarios: Option<String>,
+    pub timeline: Option<String>,
+    pub loans: Option<String>,
+    pub funding: Option<String>,
+    pub work_authorization: Option<String>,
+    pub employment: Option<String>,
 }
 
 impl Profile {
@@ -151,6 +273,14 @@ impl Profile {
             financial_params,
             cost_params: None,
             first_year_opportunity_cost: None,
+            cost_level: CostLevel::default(),
+            household: Household::default(),
+            scenarios: ScenarioSet::default(),
+            timeline: Timeline::default(),
+            loans: Vec::new(),
+            funding: Vec::new(),
+            work_authorization: None,
+            employment: EmploymentRisk::default(),
             created_at: now,
             updated_at: now,
             description: None,
@@ -168,6 +298,46 @@ impl Profile {
         self
     }
 
+    pub fn with_cost_level(mut self, cost_level: CostLevel) -> Self {
+        self.cost_level = cost_level;
+        self
+    }
+
+    pub fn with_household(mut self, household: Household) -> Self {
+        self.household = household;
+        self
+    }
+
+    pub fn with_scenarios(mut self, scenarios: ScenarioSet) -> Self {
+        self.scenarios = scenarios;
+        self
+    }
+
+    pub fn with_timeline(mut self, timeline: Timeline) -> Self {
+        self.timeline = timeline;
+        self
+    }
+
+    pub fn with_loans(mut self, loans: Vec<Loan>) -> Self {
+        self.loans = loans;
+        self
+    }
+
+    pub fn with_funding(mut self, funding: Vec<FundingAward>) -> Self {
+        self.funding = funding;
+        self
+    }
+
+    pub fn with_work_authorization(mut self, work_authorization: WorkAuthorization) -> Self {
+        self.work_authorization = Some(work_authorization);
+        self
+    }
+
+    pub fn with_employment(mut self, employment: EmploymentRisk) -> Self {
+        self.employment = employment;
+        self
+    }
+
     pub fn with_description(mut self, description: String) -> Self {
         self.description = Some(description);
         self
@@ -190,6 +360,7 @@ impl TryFrom<ProfileDbRecord> for Profile {
                 country:  db.location_country,
                 city:     db.location_city,
                 currency: db.location_currency,
+                region:   db.location_region,
             },
             work_params: WorkParams {
                 start_delay:    db.work_start_delay as u32,
@@ -206,10 +377,45 @@ impl TryFrom<ProfileDbRecord> for Profile {
                 (Some(total), Some(duration)) if duration > 0 => Some(CostParams {
                     total_cost_usd: total,
                     cost_duration:  duration as u32,
+                    breakdown:      db
+                        .cost_breakdown
+                        .as_deref()
+                        .map(serde_json::from_str)
+                        .transpose()?,
                 }),
                 _ => None,
             },
             first_year_opportunity_cost: db.first_year_opportunity_cost,
+            cost_level: db.cost_level.parse()?,
+            household: match db.household.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => Household::default(),
+            },
+            scenarios: match db.scenarios.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => ScenarioSet::default(),
+            },
+            timeline: match db.timeline.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => Timeline::default(),
+            },
+            loans: match db.loans.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => Vec::new(),
+            },
+            funding: match db.funding.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => Vec::new(),
+            },
+            work_authorization: db
+                .work_authorization
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()?,
+            employment: match db.employment.as_deref() {
+                Some(json) => serde_json::from_str(json)?,
+                None => EmploymentRisk::default(),
+            },
             created_at: DateTime::from_naive_utc_and_offset(db.created_at, Utc),
             updated_at: DateTime::from_naive_utc_and_offset(db.updated_at, Utc),
             description: db.description,
@@ -217,20 +423,29 @@ impl TryFrom<ProfileDbRecord> for Profile {
     }
 }
 
-impl From<Profile> for ProfileDbRecord {
-    fn from(profile: Profile) -> Self {
-        let (total_cost_usd, cost_duration) = match profile.cost_params {
-            Some(cost) => (Some(cost.total_cost_usd), Some(cost.cost_duration as i32)),
-            None => (None, None),
+impl TryFrom<Profile> for ProfileDbRecord {
+    type Error = serde_json::Error;
+
+    fn try_from(profile: Profile) -> std::result::Result<Self, Self::Error> {
+        let (total_cost_usd, cost_duration, cost_breakdown) = match profile.cost_params {
+            Some(cost) => (
+                Some(cost.total_cost_usd),
+                Some(cost.cost_duration as i32),
+                cost.breakdown
+                    .map(|breakdown| serde_json::to_string(&breakdown))
+                    .transpose()?,
+            ),
+            None => (None, None, None),
         };
 
-        ProfileDbRecord {
+        Ok(ProfileDbRecord {
             id: profile.id.to_string(),
             name: profile.name,
             profile_type: profile.profile_type.to_string(),
             location_country: profile.location.country,
             location_city: profile.location.city,
             location_currency: profile.location.currency,
+            location_region: profile.location.region,
             work_start_delay: profile.work_params.start_delay as i32,
             work_duration_limit: profile.work_params.duration_limit.map(|x| x as i32),
             initial_salary_usd: profile.financial_params.initial_salary_usd,
@@ -244,7 +459,44 @@ impl From<Profile> for ProfileDbRecord {
             created_at: profile.created_at.naive_utc(),
             updated_at: profile.updated_at.naive_utc(),
             description: profile.description,
-        }
+            cost_breakdown,
+            cost_level: profile.cost_level.to_string(),
+            household: if profile.household.is_single() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.household)?)
+            },
+            scenarios: if profile.scenarios.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.scenarios)?)
+            },
+            timeline: if profile.timeline.is_default() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.timeline)?)
+            },
+            loans: if profile.loans.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.loans)?)
+            },
+            funding: if profile.funding.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.funding)?)
+            },
+            work_authorization: profile
+                .work_authorization
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?,
+            employment: if profile.employment.is_default() {
+                None
+            } else {
+                Some(serde_json::to_string(&profile.employment)?)
+            },
+        })
     }
 }
 
@@ -258,7 +510,7 @@ pub struct CalculationContext<'a> {
 
 impl Profile {
     /// 获取计算上下文（避免数据复制）
-    pub fn calculation_context(&self) -> CalculationContext {
+    pub fn calculation_context(&self) -> CalculationContext<'_> {
         CalculationContext {
             work_params:                 &self.work_params,
             financial_params:            &self.financial_params,
@@ -268,12 +520,75 @@ impl Profile {
     }
 }
 
+/// 路径计算参数 - 计算器按年模拟时使用的扁平化参数
+#[derive(Debug, Clone)]
+pub struct PathCalculationParams {
+    pub work_start_delay:            u32,
+    pub work_duration_limit:         Option<u32>,
+    pub initial_salary_usd:          f64,
+    pub salary_growth_rate:          f64,
+    pub living_cost_usd:             f64,
+    pub living_cost_growth:          f64,
+    /// 统一税率（地区未配置税表时使用）
+    pub tax_rate:                    f64,
+    /// 地区key，用于匹配地区税表
+    pub region:                      Option<String>,
+    pub total_cost_usd:              Option<f64>,
+    pub cost_params:                 Option<CostParams>,
+    pub first_year_opportunity_cost: Option<f64>,
+    pub cost_level:                  CostLevel,
+    pub household:                   Household,
+    /// 开始工作前的月数
+    pub work_delay_months:           u32,
+    /// 产生学习成本的月数（无学习成本时为0）
+    pub study_months:                u32,
+    pub term_schedule:               TermSchedule,
+    pub loans:                       Vec<Loan>,
+    pub funding:                     Vec<FundingAward>,
+    pub employment:                  EmploymentRisk,
+}
+
+impl Profile {
+    /// 转换为路径计算参数
+    pub fn to_path_params(&self) -> PathCalculationParams {
+        let study_months = self.cost_params.as_ref().map_or(0, |cost| {
+            self.timeline
+                .study_months
+                .unwrap_or(cost.cost_duration * 12)
+        });
+        PathCalculationParams {
+            work_start_delay:            self.work_params.start_delay,
+            work_duration_limit:         self.work_params.duration_limit,
+            initial_salary_usd:          self.financial_params.initial_salary_usd,
+            salary_growth_rate:          self.financial_params.salary_growth_rate,
+            living_cost_usd:             self.financial_params.living_cost_usd,
+            living_cost_growth:          self.financial_params.living_cost_growth,
+            tax_rate:                    self.financial_params.tax_rate,
+            region:                      self.location.region.clone(),
+            total_cost_usd:              self.cost_params.as_ref().map(|c| c.total_cost_usd),
+            cost_params:                 self.cost_params.clone(),
+            first_year_opportunity_cost: self.first_year_opportunity_cost,
+            cost_level:                  self.cost_level,
+            household:                   self.household.clone(),
+            work_delay_months:           self
+                .timeline
+                .study_months
+                .unwrap_or(self.work_params.start_delay * 12),
+            study_months,
+            term_schedule:               self.timeline.term_schedule(study_months),
+            loans:                       self.loans.clone(),
+            funding:                     self.funding.clone(),
+            employment:                  self.employment.clone(),
+        }
+    }
+}
+
 /// 数据库操作trait
 pub trait ProfileRepository {
-    fn save(&self, profile: &Profile) -> Result<()>;
-    fn find_by_id(&self, id: Uuid) -> Result<Option<Profile>>;
-    fn find_all(&self) -> Result<Vec<Profile>>;
-    fn delete(&self, id: Uuid) -> Result<()>;
+    fn save(&mut self, profile: &Profile) -> Result<()>;
+    fn find_by_id(&mut self, id: Uuid) -> Result<Option<Profile>>;
+    fn find_all(&mut self) -> Result<Vec<Profile>>;
+    fn delete(&mut self, id: Uuid) -> Result<()>;
 }
 
 /// Diesel 实现
@@ -285,11 +600,11 @@ impl<'a> DieselProfileRepository<'a> {
     pub fn new(conn: &'a mut SqliteConnection) -> Self { Self { conn } }
 }
 
-impl<'a> ProfileRepository for DieselProfileRepository<'a> {
-    fn save(&self, profile: &Profile) -> Result<()> {
-        use crate::profiles::dsl::*;
+impl ProfileRepository for DieselProfileRepository<'_> {
+    fn save(&mut self, profile: &Profile) -> Result<()> {
+        use crate::models::profiles::dsl::*;
 
-        let db_record = ProfileDbRecord::from(profile.clone());
+        let db_record = ProfileDbRecord::try_from(profile.clone())?;
         diesel::insert_into(profiles)
             .values(&db_record)
             .on_conflict(id)
@@ -299,8 +614,8 @@ impl<'a> ProfileRepository for DieselProfileRepository<'a> {
         Ok(())
     }
 
-    fn find_by_id(&self, profile_id: Uuid) -> Result<Option<Profile>> {
-        use crate::profiles::dsl::*;
+    fn find_by_id(&mut self, profile_id: Uuid) -> Result<Option<Profile>> {
+        use crate::models::profiles::dsl::*;
 
         let record: Option<ProfileDbRecord> = profiles
             .filter(id.eq(profile_id.to_string()))
@@ -313,21 +628,73 @@ impl<'a> ProfileRepository for DieselProfileRepository<'a> {
         }
     }
 
-    fn find_all(&self) -> Result<Vec<Profile>> {
-        use crate::profiles::dsl::*;
+    /// 无法解析的记录会被跳过并打印警告
+    fn find_all(&mut self) -> Result<Vec<Profile>> {
+        use crate::models::profiles::dsl::*;
 
         let records: Vec<ProfileDbRecord> = profiles.load(self.conn)?;
-        records.into_iter().map(Profile::try_from).collect()
+        let mut all = Vec::new();
+        for db_record in records {
+            match Profile::try_from(db_record) {
+                Ok(profile) => all.push(profile),
+                Err(e) => eprintln!("⚠️ 解析Profile失败: {}", e),
+            }
+        }
+        Ok(all)
     }
 
-    fn delete(&self, profile_id: Uuid) -> Result<()> {
-        use crate::profiles::dsl::*;
+    fn delete(&mut self, profile_id: Uuid) -> Result<()> {
+        use crate::models::profiles::dsl::*;
 
         diesel::delete(profiles.filter(id.eq(profile_id.to_string()))).execute(self.conn)?;
         Ok(())
     }
 }
 
+/// 测试用Profile：教育路径学习2年、初期成本8万美元，工作路径立即开始工作
+#[cfg(test)]
+pub(crate) fn test_profile(
+    profile_type: ProfileType,
+    currency: Currency,
+    salary: f64,
+    living_cost: f64,
+) -> Profile {
+    let country = match currency {
+        Currency::Usd => "USA",
+        Currency::Cad => "Canada",
+        Currency::Cny => "China",
+    };
+    let start_delay = match profile_type {
+        ProfileType::Education => 2,
+        ProfileType::Work => 0,
+    };
+    let profile = Profile::new(
+        "Test".to_string(),
+        profile_type,
+        Location {
+            country:  country.to_string(),
+            city:     None,
+            currency: currency.code().to_string(),
+            region:   None,
+        },
+        WorkParams {
+            start_delay,
+            duration_limit: None,
+        },
+        FinancialParams {
+            initial_salary_usd: salary,
+            salary_growth_rate: 0.04,
+            living_cost_usd:    living_cost,
+            living_cost_growth: 0.03,
+            tax_rate:           0.25,
+        },
+    );
+    match profile_type {
+        ProfileType::Education => profile.with_cost_params(CostParams::new(80000.0, 2).unwrap()),
+        ProfileType::Work => profile,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,6 +708,7 @@ mod tests {
                 country:  "USA".to_string(),
                 city:     Some("NYC".to_string()),
                 currency: "USD".to_string(),
+                region:   None,
             },
             WorkParams {
                 start_delay:    0,
@@ -353,15 +721,34 @@ mod tests {
                 living_cost_growth: 0.03,
                 tax_rate:           0.22,
             },
-        );
+        )
+        .with_scenarios(ScenarioSet::standard())
+        .with_timeline(Timeline {
+            start_month: 1,
+            study_months: Some(16),
+            ..Timeline::default()
+        })
+        .with_loans(vec!["bank:400000CNY:0.04:6:10".parse().unwrap()])
+        .with_funding(vec!["stipend:2200:0.4".parse().unwrap()])
+        .with_work_authorization("上海工作:0.3,0.3,0.3".parse().unwrap())
+        .with_employment(EmploymentRisk {
+            job_search: vec!["0:0.4".parse().unwrap(), "3:0.6".parse().unwrap()],
+            layoff:     Some("0.05:6".parse().unwrap()),
+        });
 
         // 转换为数据库记录
-        let db_record = ProfileDbRecord::from(profile.clone());
+        let db_record = ProfileDbRecord::try_from(profile.clone()).unwrap();
 
         // 再转换回来
         let restored_profile = Profile::try_from(db_record).unwrap();
 
         assert_eq!(profile.id, restored_profile.id);
         assert_eq!(profile.name, restored_profile.name);
+        assert_eq!(profile.scenarios, restored_profile.scenarios);
+        assert_eq!(profile.timeline, restored_profile.timeline);
+        assert_eq!(profile.loans, restored_profile.loans);
+        assert_eq!(profile.funding, restored_profile.funding);
+        assert_eq!(profile.work_authorization, restored_profile.work_authorization);
+        assert_eq!(profile.employment, restored_profile.employment);
     }
 }
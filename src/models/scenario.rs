@@ -0,0 +1,306 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{CostLevel, Profile};
+
+/// 概率之和允许的误差
+pub(super) const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+/// 对单个参数的覆盖方式，文本格式为 `set:<值>`、`scale:<系数>`、`add:<增量>`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ParamOverride {
+    /// 替换为固定值
+    Set(f64),
+    /// 乘以系数
+    Scale(f64),
+    /// 加上增量（比率参数如 +0.01 表示增加1个百分点）
+    Add(f64),
+}
+
+impl ParamOverride {
+    pub fn apply(&self, value: f64) -> f64 {
+        match *self {
+            ParamOverride::Set(set) => set,
+            ParamOverride::Scale(factor) => value * factor,
+            ParamOverride::Add(delta) => value + delta,
+        }
+    }
+
+    /// 简短说明，如 `×1.15`、`+0.01`
+    fn label(&self) -> String {
+        match self {
+            ParamOverride::Set(value) => format!("={}", value),
+            ParamOverride::Scale(factor) => format!("×{}", factor),
+            ParamOverride::Add(delta) => format!("{:+}", delta),
+        }
+    }
+}
+
+impl fmt::Display for ParamOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamOverride::Set(value) => write!(f, "set:{}", value),
+            ParamOverride::Scale(factor) => write!(f, "scale:{}", factor),
+            ParamOverride::Add(delta) => write!(f, "add:{}", delta),
+        }
+    }
+}
+
+impl FromStr for ParamOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, value) = s.trim().split_once(':').ok_or_else(|| {
+            anyhow::anyhow!("无效的参数覆盖: '{}'，格式为 set|scale|add:<数值>", s)
+        })?;
+        let value: f64 = value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("无效的参数覆盖: '{}'", s))?;
+        match kind.to_lowercase().as_str() {
+            "set" => Ok(ParamOverride::Set(value)),
+            "scale" => Ok(ParamOverride::Scale(value)),
+            "add" => Ok(ParamOverride::Add(value)),
+            _ => Err(anyhow::anyhow!(
+                "未知的覆盖方式: '{}'，可选 set | scale | add",
+                kind
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for ParamOverride {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> { value.parse() }
+}
+
+impl From<ParamOverride> for String {
+    fn from(value: ParamOverride) -> Self { value.to_string() }
+}
+
+/// 情景：带发生概率的一组参数覆盖，未指定的参数沿用Profile和计算器的设定
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scenario {
+    pub name:                   String,
+    /// 发生概率（0-1）
+    pub probability:            f64,
+    // Profile财务参数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_salary_usd:     Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salary_growth_rate:     Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub living_cost_usd:        Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub living_cost_growth:     Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate:               Option<ParamOverride>,
+    /// 初期总成本，分项明细按比例缩放
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_cost_usd:         Option<ParamOverride>,
+    // 计算器设定
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investment_return_rate: Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investment_portion:     Option<ParamOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_level:             Option<CostLevel>,
+}
+
+impl Scenario {
+    pub fn new(name: impl Into<String>, probability: f64) -> Self {
+        Self {
+            name: name.into(),
+            probability,
+            ..Self::default()
+        }
+    }
+
+    /// 应用财务参数覆盖后的Profile
+    pub fn apply_to_profile(&self, profile: &Profile) -> Profile {
+        let mut profile = profile.clone();
+        let financial = &mut profile.financial_params;
+        let overrides = [
+            (&mut financial.initial_salary_usd, self.initial_salary_usd),
+            (&mut financial.salary_growth_rate, self.salary_growth_rate),
+            (&mut financial.living_cost_usd, self.living_cost_usd),
+            (&mut financial.living_cost_growth, self.living_cost_growth),
+            (&mut financial.tax_rate, self.tax_rate),
+        ];
+        for (value, param) in overrides {
+            if let Some(param) = param {
+                *value = param.apply(*value);
+            }
+        }
+
+        if let (Some(param), Some(cost)) = (self.total_cost_usd, profile.cost_params.as_mut()) {
+            let total = param.apply(cost.total_cost_usd).max(0.0);
+            if let Some(breakdown) = cost.breakdown.as_mut() {
+                let ratio = if cost.total_cost_usd > 0.0 {
+                    total / cost.total_cost_usd
+                } else {
+                    0.0
+                };
+                for item in &mut breakdown.items {
+                    item.amount_usd *= ratio;
+                }
+            }
+            cost.total_cost_usd = total;
+        }
+        if let Some(cost_level) = self.cost_level {
+            profile.cost_level = cost_level;
+        }
+        profile
+    }
+
+    /// 覆盖项的简要说明
+    pub fn summary(&self) -> String {
+        let params = [
+            ("年薪", self.initial_salary_usd),
+            ("薪资增长", self.salary_growth_rate),
+            ("生活成本", self.living_cost_usd),
+            ("生活成本增长", self.living_cost_growth),
+            ("税率", self.tax_rate),
+            ("初期成本", self.total_cost_usd),
+            ("投资回报", self.investment_return_rate),
+            ("投资比例", self.investment_portion),
+        ];
+        let mut parts: Vec<String> = params
+            .into_iter()
+            .filter_map(|(label, param)| param.map(|param| format!("{}{}", label, param.label())))
+            .collect();
+        if let Some(cost_level) = self.cost_level {
+            parts.push(format!("档位={}", cost_level.label()));
+        }
+        if parts.is_empty() {
+            "无调整".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// 一组互斥的情景，概率之和为1
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ScenarioSet {
+    pub scenarios: Vec<Scenario>,
+}
+
+impl ScenarioSet {
+    /// 乐观25% / 基准50% / 悲观25%
+    pub fn standard() -> Self {
+        Self {
+            scenarios: vec![
+                Scenario {
+                    initial_salary_usd: Some(ParamOverride::Scale(1.15)),
+                    salary_growth_rate: Some(ParamOverride::Add(0.015)),
+                    investment_return_rate: Some(ParamOverride::Add(0.02)),
+                    ..Scenario::new("乐观", 0.25)
+                },
+                Scenario::new("基准", 0.5),
+                Scenario {
+                    initial_salary_usd: Some(ParamOverride::Scale(0.85)),
+                    salary_growth_rate: Some(ParamOverride::Add(-0.015)),
+                    living_cost_usd: Some(ParamOverride::Scale(1.1)),
+                    investment_return_rate: Some(ParamOverride::Add(-0.03)),
+                    ..Scenario::new("悲观", 0.25)
+                },
+            ],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.scenarios.is_empty() }
+
+    /// 检查情景名称和概率
+    pub fn validate(&self) -> Result<()> {
+        if self.scenarios.is_empty() {
+            return Err(anyhow::anyhow!("情景集合不能为空"));
+        }
+        for (i, scenario) in self.scenarios.iter().enumerate() {
+            if scenario.name.trim().is_empty() {
+                return Err(anyhow::anyhow!("第 {} 个情景缺少名称", i + 1));
+            }
+            if self.scenarios[..i].iter().any(|s| s.name == scenario.name) {
+                return Err(anyhow::anyhow!("情景名称重复: '{}'", scenario.name));
+            }
+            if !(0.0..=1.0).contains(&scenario.probability) {
+                return Err(anyhow::anyhow!(
+                    "情景 '{}' 的概率必须在0到1之间",
+                    scenario.name
+                ));
+            }
+        }
+        let total: f64 = self.scenarios.iter().map(|s| s.probability).sum();
+        if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+            return Err(anyhow::anyhow!(
+                "情景概率之和必须为100%，当前为 {:.1}%",
+                total * 100.0
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, test_profile};
+
+    #[test]
+    fn test_param_override() {
+        assert_eq!(ParamOverride::Set(0.3).apply(0.2), 0.3);
+        assert_eq!(ParamOverride::Scale(1.5).apply(100.0), 150.0);
+        assert!((ParamOverride::Add(-0.01).apply(0.03) - 0.02).abs() < 1e-12);
+
+        assert_eq!(
+            "add:-0.015".parse::<ParamOverride>().unwrap(),
+            ParamOverride::Add(-0.015)
+        );
+        assert_eq!(ParamOverride::Scale(1.15).to_string(), "scale:1.15");
+        assert!("scale".parse::<ParamOverride>().is_err());
+        assert!("mul:2".parse::<ParamOverride>().is_err());
+    }
+
+    #[test]
+    fn test_apply_to_profile() {
+        let profile = test_profile(ProfileType::Education, Currency::Usd, 100000.0, 30000.0);
+
+        let scenario = Scenario {
+            initial_salary_usd: Some(ParamOverride::Scale(0.8)),
+            tax_rate: Some(ParamOverride::Set(0.3)),
+            total_cost_usd: Some(ParamOverride::Add(20000.0)),
+            ..Scenario::new("悲观", 1.0)
+        };
+        let adjusted = scenario.apply_to_profile(&profile);
+        assert_eq!(adjusted.financial_params.initial_salary_usd, 80000.0);
+        assert_eq!(adjusted.financial_params.tax_rate, 0.3);
+        assert_eq!(adjusted.financial_params.living_cost_usd, 30000.0);
+        assert_eq!(adjusted.cost_params.unwrap().total_cost_usd, 100000.0);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(ScenarioSet::standard().validate().is_ok());
+        assert!(ScenarioSet::default().validate().is_err());
+
+        let mut set = ScenarioSet::standard();
+        set.scenarios[1].probability = 0.4;
+        assert!(set.validate().is_err());
+
+        let yaml =
+            "- name: 好\n  probability: 0.6\n  initial_salary_usd: scale:1.2\n- name: 差\n  \
+             probability: 0.4\n";
+        let set: ScenarioSet = serde_yaml::from_str(yaml).unwrap();
+        assert!(set.validate().is_ok());
+        assert_eq!(
+            set.scenarios[0].initial_salary_usd,
+            Some(ParamOverride::Scale(1.2))
+        );
+    }
+}
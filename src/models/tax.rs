@@ -0,0 +1,422 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{BUNDLED_TAXES_YAML, TaxConfig};
+
+/// 解析内置的 taxes.yaml
+fn bundled_taxes() -> TaxConfig {
+    TaxConfig::from_yaml_str(BUNDLED_TAXES_YAML).expect("内置税务配置无效")
+}
+
+/// 累进税率中的一档：应税收入中不超过 `up_to` 的部分按 `rate`
+/// 计税，最高一档不设上限
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TaxBracket {
+    #[serde(default)]
+    pub up_to: Option<f64>,
+    pub rate:  f64,
+}
+
+/// 按累进税率表计算应纳税额
+pub fn progressive_tax(brackets: &[TaxBracket], taxable_income: f64) -> f64 {
+    let mut tax = 0.0;
+    let mut lower = 0.0;
+    for bracket in brackets {
+        let upper = bracket.up_to.unwrap_or(f64::INFINITY);
+        if taxable_income > lower {
+            tax += (taxable_income.min(upper) - lower) * bracket.rate;
+        }
+        lower = upper;
+    }
+    tax
+}
+
+/// 所得税：扣除标准扣除额后按累进税率计税，税率表为空表示不征收
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IncomeTax {
+    #[serde(default)]
+    pub standard_deduction: f64,
+    #[serde(default)]
+    pub brackets:           Vec<TaxBracket>,
+}
+
+impl IncomeTax {
+    pub fn tax(&self, gross_income: f64) -> f64 {
+        progressive_tax(
+            &self.brackets,
+            (gross_income - self.standard_deduction).max(0.0),
+        )
+    }
+}
+
+/// 美国工资税（FICA）：社会保障税有工资上限，高收入部分加征医疗保险税
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Fica {
+    pub social_security_rate:          f64,
+    pub social_security_wage_base:     f64,
+    pub medicare_rate:                 f64,
+    pub additional_medicare_rate:      f64,
+    pub additional_medicare_threshold: f64,
+}
+
+impl Fica {
+    pub fn tax(&self, wages: f64) -> f64 {
+        wages.min(self.social_security_wage_base) * self.social_security_rate
+            + wages * self.medicare_rate
+            + (wages - self.additional_medicare_threshold).max(0.0) * self.additional_medicare_rate
+    }
+}
+
+/// 州税（key与 regions.yaml 中的美国地区一致）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StateTax {
+    pub name:         String,
+    #[serde(default)]
+    pub income_tax:   IncomeTax,
+    /// 州工资税（如加州SDI），按全部工资计征
+    #[serde(default)]
+    pub payroll_rate: f64,
+}
+
+/// 年度税负明细（与收入同币种）
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct TaxBreakdown {
+    /// 联邦（国家）所得税
+    pub income_tax:  f64,
+    /// 州（省、地方）所得税
+    pub local_tax:   f64,
+    /// 工资税和社保类缴费
+    pub payroll_tax: f64,
+}
+
+impl TaxBreakdown {
+    pub fn total(&self) -> f64 { self.income_tax + self.local_tax + self.payroll_tax }
+
+    /// 按汇率换算为其他货币
+    pub fn converted(&self, rate: f64) -> Self {
+        Self {
+            income_tax:  self.income_tax * rate,
+            local_tax:   self.local_tax * rate,
+            payroll_tax: self.payroll_tax * rate,
+        }
+    }
+
+    /// 实际税率
+    pub fn effective_rate(&self, gross_income: f64) -> f64 {
+        if gross_income > 0.0 {
+            self.total() / gross_income
+        } else {
+            0.0
+        }
+    }
+}
+
+/// 美国税表（单身申报，工资收入）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UsTaxRules {
+    /// 税表对应的纳税年度
+    pub tax_year: u32,
+    pub federal:  IncomeTax,
+    pub fica:     Fica,
+    #[serde(default)]
+    pub states:   BTreeMap<String, StateTax>,
+}
+
+impl UsTaxRules {
+    /// 按地区key查找州税
+    pub fn state(&self, region: &str) -> Option<&StateTax> { self.states.get(region) }
+
+    /// 年薪 `salary` 在 `state` 的税负
+    pub fn breakdown(&self, salary: f64, state: &StateTax) -> TaxBreakdown {
+        TaxBreakdown {
+            income_tax:  self.federal.tax(salary),
+            local_tax:   state.income_tax.tax(salary),
+            payroll_tax: self.fica.tax(salary) + salary * state.payroll_rate,
+        }
+    }
+}
+
+impl Default for UsTaxRules {
+    /// 内置 taxes.yaml 中的税表
+    fn default() -> Self { bundled_taxes().us }
+}
+
+/// 加拿大所得税（联邦或省）：基本个人免税额与社保缴费按最低一档税率抵免，
+/// `surtax` 为按基本税额累进加征的附加税（如安大略）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CanadaIncomeTax {
+    pub basic_personal_amount: f64,
+    pub brackets:              Vec<TaxBracket>,
+    #[serde(default)]
+    pub surtax:                Vec<TaxBracket>,
+}
+
+impl CanadaIncomeTax {
+    /// `credits` 为可抵免的其他金额（CPP、EI等缴费）
+    pub fn tax(&self, income: f64, credits: f64) -> f64 {
+        let lowest_rate = self.brackets.first().map_or(0.0, |bracket| bracket.rate);
+        let basic = (progressive_tax(&self.brackets, income)
+            - lowest_rate * (self.basic_personal_amount + credits))
+            .max(0.0);
+        basic + progressive_tax(&self.surtax, basic)
+    }
+}
+
+/// 养老金计划缴费（CPP/QPP）：基本免缴额以上至最高缴费收入按 `rate`，
+/// 其上至第二档上限按 `second_rate`（CPP2）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PensionPlan {
+    pub basic_exemption:     f64,
+    pub max_earnings:        f64,
+    pub rate:                f64,
+    pub second_max_earnings: f64,
+    pub second_rate:         f64,
+}
+
+impl PensionPlan {
+    pub fn contribution(&self, wages: f64) -> f64 {
+        (wages.min(self.max_earnings) - self.basic_exemption).max(0.0) * self.rate
+            + (wages.min(self.second_max_earnings) - self.max_earnings).max(0.0) * self.second_rate
+    }
+}
+
+/// 按比例缴纳且有收入上限的保险（EI、魁北克QPIP）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InsurancePremium {
+    pub rate:                   f64,
+    pub max_insurable_earnings: f64,
+}
+
+impl InsurancePremium {
+    pub fn premium(&self, wages: f64) -> f64 { wages.min(self.max_insurable_earnings) * self.rate }
+}
+
+/// 省税及该省特有的缴费规则
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProvinceTax {
+    pub name:                 String,
+    pub income_tax:           CanadaIncomeTax,
+    /// 替代CPP的省养老金计划（魁北克QPP）
+    #[serde(default)]
+    pub pension:              Option<PensionPlan>,
+    /// 该省适用的EI费率（魁北克较低）
+    #[serde(default)]
+    pub employment_insurance: Option<InsurancePremium>,
+    /// 省育儿保险（魁北克QPIP）
+    #[serde(default)]
+    pub parental_insurance:   Option<InsurancePremium>,
+    /// 联邦税减免比例（魁北克16.5%）
+    #[serde(default)]
+    pub federal_abatement:    f64,
+}
+
+/// 加拿大税表（金额为CAD）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CanadaTaxRules {
+    /// 税表对应的纳税年度
+    pub tax_year:             u32,
+    pub federal:              CanadaIncomeTax,
+    pub pension:              PensionPlan,
+    pub employment_insurance: InsurancePremium,
+    #[serde(default)]
+    pub provinces:            BTreeMap<String, ProvinceTax>,
+    /// regions.yaml 中的加拿大地区key -> 省份key
+    #[serde(default)]
+    pub regions:              BTreeMap<String, String>,
+}
+
+impl CanadaTaxRules {
+    /// 按地区key查找省税
+    pub fn province(&self, region: &str) -> Option<&ProvinceTax> {
+        self.provinces.get(self.regions.get(region)?)
+    }
+
+    /// 年薪 `salary`（CAD）在 `province` 的税负
+    pub fn breakdown(&self, salary: f64, province: &ProvinceTax) -> TaxBreakdown {
+        let pension = province
+            .pension
+            .as_ref()
+            .unwrap_or(&self.pension)
+            .contribution(salary);
+        let employment_insurance = province
+            .employment_insurance
+            .as_ref()
+            .unwrap_or(&self.employment_insurance)
+            .premium(salary);
+        let parental_insurance = province
+            .parental_insurance
+            .as_ref()
+            .map_or(0.0, |insurance| insurance.premium(salary));
+        let payroll_tax = pension + employment_insurance + parental_insurance;
+
+        TaxBreakdown {
+            income_tax: self.federal.tax(salary, payroll_tax) * (1.0 - province.federal_abatement),
+            local_tax: province.income_tax.tax(salary, payroll_tax),
+            payroll_tax,
+        }
+    }
+}
+
+impl Default for CanadaTaxRules {
+    /// 内置 taxes.yaml 中的税表
+    fn default() -> Self { bundled_taxes().canada }
+}
+
+/// 城市的社保和住房公积金缴费规则（个人部分）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChinaCityTax {
+    pub name:                   String,
+    /// 养老、医疗、失业保险个人缴费比例合计
+    pub social_insurance_rate:  f64,
+    pub housing_fund_rate:      f64,
+    /// 年缴费基数下限（当地社平工资的60%）
+    pub contribution_base_min:  f64,
+    /// 年缴费基数上限（当地社平工资的300%）
+    pub contribution_base_max:  f64,
+    /// 住房租金专项附加扣除（年）
+    #[serde(default)]
+    pub housing_rent_deduction: f64,
+}
+
+/// 中国个人所得税表（综合所得，金额为CNY）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChinaTaxRules {
+    /// 税表对应的纳税年度
+    pub tax_year:        u32,
+    /// 基本减除费用（每月5000元）
+    pub basic_deduction: f64,
+    pub brackets:        Vec<TaxBracket>,
+    /// 每个子女的子女教育/婴幼儿照护专项附加扣除（年）
+    pub child_deduction: f64,
+    /// 城市key与 regions.yaml 中的国内城市一致
+    #[serde(default)]
+    pub cities:          BTreeMap<String, ChinaCityTax>,
+}
+
+impl ChinaTaxRules {
+    /// 按地区key查找城市
+    pub fn city(&self, region: &str) -> Option<&ChinaCityTax> { self.cities.get(region) }
+
+    /// 当年工作 `months` 个月取得收入 `salary`（CNY）在 `city` 的税负，
+    /// `children` 为可扣除的子女数
+    ///
+    /// 社保和住房公积金按月缴纳，缴费基数的上下限按工作月数折算，
+    /// 封顶后的缴费计入 `payroll_tax`
+    pub fn breakdown(
+        &self,
+        salary: f64,
+        months: f64,
+        city: &ChinaCityTax,
+        children: usize,
+    ) -> TaxBreakdown {
+        let share = months / 12.0;
+        let base = salary.clamp(
+            city.contribution_base_min * share,
+            city.contribution_base_max * share,
+        );
+        let payroll_tax = base * (city.social_insurance_rate + city.housing_fund_rate);
+        let taxable = salary
+            - self.basic_deduction
+            - payroll_tax
+            - city.housing_rent_deduction
+            - self.child_deduction * children as f64;
+
+        TaxBreakdown {
+            income_tax: progressive_tax(&self.brackets, taxable.max(0.0)),
+            local_tax: 0.0,
+            payroll_tax,
+        }
+    }
+}
+
+impl Default for ChinaTaxRules {
+    /// 内置 taxes.yaml 中的税表
+    fn default() -> Self { bundled_taxes().china }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_us_tax() {
+        let rules = UsTaxRules::default();
+
+        // 应税收入 100000 - 14600 = 85400
+        let federal = 11600.0 * 0.10 + (47150.0 - 11600.0) * 0.12 + (85400.0 - 47150.0) * 0.22;
+        assert!((rules.federal.tax(100000.0) - federal).abs() < 1e-6);
+        assert_eq!(rules.federal.tax(10000.0), 0.0);
+
+        // 社会保障税封顶，超过20万的部分加征0.9%
+        let fica = 168600.0 * 0.062 + 250000.0 * 0.0145 + 50000.0 * 0.009;
+        assert!((rules.fica.tax(250000.0) - fica).abs() < 1e-6);
+
+        let texas = rules.breakdown(100000.0, rules.state("texas").unwrap());
+        assert_eq!(texas.local_tax, 0.0);
+        let california = rules.breakdown(100000.0, rules.state("california_bay_area").unwrap());
+        assert!(california.local_tax > 0.0);
+        assert!(california.effective_rate(100000.0) > texas.effective_rate(100000.0));
+
+        // 收入越高实际税率越高
+        let state = rules.state("arizona").unwrap();
+        let low = rules.breakdown(80000.0, state).effective_rate(80000.0);
+        let high = rules.breakdown(300000.0, state).effective_rate(300000.0);
+        assert!(low < high);
+    }
+
+    #[test]
+    fn test_canada_tax() {
+        let rules = CanadaTaxRules::default();
+
+        // CPP: (68500 - 3500) * 5.95% + (73200 - 68500) * 4%，EI封顶
+        assert!((rules.pension.contribution(100000.0) - (3867.5 + 188.0)).abs() < 1e-6);
+        assert!((rules.employment_insurance.premium(100000.0) - 1049.12).abs() < 1e-6);
+        assert_eq!(rules.pension.contribution(3000.0), 0.0);
+
+        // 低收入时免税额抵免后不缴所得税
+        let alberta = rules.province("alberta").unwrap();
+        assert_eq!(rules.breakdown(15000.0, alberta).income_tax, 0.0);
+
+        // 多伦多与渥太华同属安大略省；高收入时卡尔加里税负更低
+        let ontario = rules.province("ontario_major").unwrap();
+        assert_eq!(rules.province("capital"), Some(ontario));
+        let calgary = rules.breakdown(200000.0, alberta);
+        let toronto = rules.breakdown(200000.0, ontario);
+        assert_eq!(calgary.payroll_tax, toronto.payroll_tax);
+        assert!(calgary.total() < toronto.total());
+
+        // 魁北克：QPP、QPIP和联邦税减免
+        let quebec = rules.breakdown(100000.0, rules.province("quebec").unwrap());
+        assert!(quebec.payroll_tax > rules.breakdown(100000.0, alberta).payroll_tax);
+        assert!(rules.province("atlantis").is_none());
+    }
+
+    #[test]
+    fn test_china_tax() {
+        let rules = ChinaTaxRules::default();
+        let shanghai = rules.city("shanghai").unwrap();
+
+        // 年薪37.5万：社保公积金 375000 * 17.5% = 65625
+        // 应纳税所得额 375000 - 60000 - 65625 - 18000 = 231375
+        let tax = rules.breakdown(375000.0, 12.0, shanghai, 0);
+        assert!((tax.payroll_tax - 65625.0).abs() < 1e-6);
+        let expected = 36000.0 * 0.03 + 108000.0 * 0.10 + (231375.0 - 144000.0) * 0.20;
+        assert!((tax.income_tax - expected).abs() < 1e-6);
+
+        // 子女专项附加扣除降低个税
+        assert!(rules.breakdown(375000.0, 12.0, shanghai, 1).income_tax < tax.income_tax);
+
+        // 缴费基数封顶
+        let high = rules.breakdown(1000000.0, 12.0, shanghai, 0);
+        assert!((high.payroll_tax - 443052.0 * 0.175).abs() < 1e-6);
+        assert_eq!(rules.breakdown(60000.0, 12.0, shanghai, 0).income_tax, 0.0);
+
+        // 工作3个月：缴费基数下限按3个月折算，月薪1万元按实际工资缴费
+        let partial = rules.breakdown(30000.0, 3.0, shanghai, 0);
+        assert!((partial.payroll_tax - 30000.0 * 0.175).abs() < 1e-6);
+        // 月薪低于下限时按下限的3/12缴费
+        let low = rules.breakdown(15000.0, 3.0, shanghai, 0);
+        assert!((low.payroll_tax - 88608.0 / 4.0 * 0.175).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,172 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::TermSchedule;
+
+/// 学制：决定每学年的开学月份，学费在每个学期开学时支付
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TermSystem {
+    /// 春秋两学期（1月、9月开学）
+    #[default]
+    Semester,
+    /// 含夏季学期的三学期（1月、5月、9月开学）
+    Trimester,
+    /// 学季制，不含夏季（1月、4月、9月开学）
+    Quarter,
+}
+
+impl TermSystem {
+    /// 每学年各学期的开学月份
+    pub fn start_months(&self) -> &'static [u32] {
+        match self {
+            TermSystem::Semester => &[1, 9],
+            TermSystem::Trimester => &[1, 5, 9],
+            TermSystem::Quarter => &[1, 4, 9],
+        }
+    }
+
+    pub fn terms_per_year(&self) -> u32 { self.start_months().len() as u32 }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TermSystem::Semester => "两学期制",
+            TermSystem::Trimester => "三学期制",
+            TermSystem::Quarter => "学季制",
+        }
+    }
+}
+
+impl std::str::FromStr for TermSystem {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "semester" => Ok(TermSystem::Semester),
+            "trimester" => Ok(TermSystem::Trimester),
+            "quarter" => Ok(TermSystem::Quarter),
+            _ => Err(anyhow::anyhow!(
+                "未知的学制: '{}'，可选 semester | trimester | quarter",
+                s
+            )),
+        }
+    }
+}
+
+/// 路径的月度时间安排
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct Timeline {
+    /// 路径开始的日历月份（1-12），如春季入学为1，秋季入学为9
+    pub start_month:  u32,
+    /// 学习期月数（如16个月的项目），未设置时按整年的工作延迟计算
+    pub study_months: Option<u32>,
+    pub term_system:  TermSystem,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            start_month:  9,
+            study_months: None,
+            term_system:  TermSystem::Semester,
+        }
+    }
+}
+
+impl Timeline {
+    pub fn is_default(&self) -> bool { *self == Self::default() }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=12).contains(&self.start_month) {
+            return Err(anyhow::anyhow!("开始月份必须在1到12之间"));
+        }
+        if self.study_months == Some(0) {
+            return Err(anyhow::anyhow!("学习期月数必须大于0"));
+        }
+        Ok(())
+    }
+
+    /// 第 `month` 个月（从1开始）对应的日历月份
+    pub fn calendar_month(&self, month: u32) -> u32 { (self.start_month + month - 2) % 12 + 1 }
+
+    /// 学习期内各学期开学的月份（从1开始），第1个月总是开学月
+    ///
+    /// 入学月份不是标准开学月时（如8月入学），
+    /// 两个月内的下一个开学月不再重复计费
+    pub fn term_starts(&self, study_months: u32) -> Vec<u32> {
+        let mut starts: Vec<u32> = Vec::new();
+        for month in 1..=study_months {
+            let is_start = month == 1
+                || (self
+                    .term_system
+                    .start_months()
+                    .contains(&self.calendar_month(month))
+                    && starts.last().is_none_or(|last| month - last > 2));
+            if is_start {
+                starts.push(month);
+            }
+        }
+        starts
+    }
+
+    /// 学习期的学期安排
+    pub fn term_schedule(&self, study_months: u32) -> TermSchedule {
+        TermSchedule {
+            starts:         self.term_starts(study_months),
+            terms_per_year: self.term_system.terms_per_year(),
+        }
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        let study = self
+            .study_months
+            .map(|months| format!("，学习期 {} 个月", months))
+            .unwrap_or_default();
+        format!(
+            "{}月开始{}，{}",
+            self.start_month,
+            study,
+            self.term_system.label()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_term_starts() {
+        // 秋季入学两年制：9月、1月各开学两次
+        let fall = Timeline::default();
+        assert_eq!(fall.calendar_month(1), 9);
+        assert_eq!(fall.calendar_month(5), 1);
+        assert_eq!(fall.term_starts(24), vec![1, 5, 13, 17]);
+
+        // 春季入学16个月：1月、9月、次年1月，夏季不收学费
+        let spring = Timeline {
+            start_month: 1,
+            study_months: Some(16),
+            ..Timeline::default()
+        };
+        assert_eq!(spring.term_starts(16), vec![1, 9, 13]);
+
+        // 8月入学时9月不再单独开学
+        let august = Timeline {
+            start_month: 8,
+            ..Timeline::default()
+        };
+        assert_eq!(august.term_starts(12), vec![1, 6]);
+
+        assert!(
+            Timeline {
+                start_month: 13,
+                ..Timeline::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
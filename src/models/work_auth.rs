@@ -0,0 +1,165 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// 毕业后的美国工作许可：OPT、STEM OPT延期，期间参加H-1B抽签，
+/// 全部未中签时在工作许可到期后转入后备Profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkAuthorization {
+    /// OPT月数
+    #[serde(default = "default_opt_months")]
+    pub opt_months:            u32,
+    /// STEM OPT延期月数，非STEM专业为0
+    #[serde(default)]
+    pub stem_extension_months: u32,
+    /// 各次H-1B抽签的中签率，按抽签先后排列
+    pub lottery_odds:          Vec<f64>,
+    /// 全部未中签时转入的Profile（ID、ID前缀或名称），如回上海工作或转往加拿大
+    pub fallback:              String,
+}
+
+fn default_opt_months() -> u32 { 12 }
+
+impl WorkAuthorization {
+    /// OPT加STEM延期的总月数
+    pub fn authorized_months(&self) -> u32 { self.opt_months + self.stem_extension_months }
+
+    /// 每年一次抽签，工作许可期内最多可参加的次数
+    pub fn max_attempts(&self) -> usize { self.authorized_months().div_ceil(12) as usize }
+
+    /// 第k次抽签（从0开始）才中签的概率
+    pub fn win_probabilities(&self) -> Vec<f64> {
+        let mut remaining = 1.0;
+        self.lottery_odds
+            .iter()
+            .map(|odds| {
+                let win = remaining * odds;
+                remaining *= 1.0 - odds;
+                win
+            })
+            .collect()
+    }
+
+    /// 全部抽签未中的概率
+    pub fn failure_probability(&self) -> f64 {
+        self.lottery_odds.iter().map(|odds| 1.0 - odds).product()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.opt_months == 0 {
+            return Err(anyhow::anyhow!("OPT月数必须大于0"));
+        }
+        if self.lottery_odds.is_empty() {
+            return Err(anyhow::anyhow!("至少需要一次H-1B抽签"));
+        }
+        if self.lottery_odds.len() > self.max_attempts() {
+            return Err(anyhow::anyhow!(
+                "{}个月的工作许可期内最多参加{}次抽签",
+                self.authorized_months(),
+                self.max_attempts()
+            ));
+        }
+        if self
+            .lottery_odds
+            .iter()
+            .any(|odds| !(0.0..=1.0).contains(odds))
+        {
+            return Err(anyhow::anyhow!("中签率必须在0到1之间"));
+        }
+        if self.fallback.trim().is_empty() {
+            return Err(anyhow::anyhow!("必须指定未中签时转入的Profile"));
+        }
+        Ok(())
+    }
+
+    /// 简短描述
+    pub fn summary(&self) -> String {
+        let odds: Vec<String> = self
+            .lottery_odds
+            .iter()
+            .map(|odds| format!("{:.0}%", odds * 100.0))
+            .collect();
+        format!(
+            "OPT {} 个月 + STEM延期 {} 个月，抽签 {} 次（中签率 {}），未中签转入 {}",
+            self.opt_months,
+            self.stem_extension_months,
+            self.lottery_odds.len(),
+            odds.join(" / "),
+            self.fallback
+        )
+    }
+}
+
+/// 解析 "<后备Profile>:<中签率>[,<中签率>...][:<OPT月数>[:<STEM延期月数>]]"，
+/// 如 "上海工作:0.3,0.3,0.3" 或 "Toronto:0.35:12:0"，STEM延期默认24个月
+impl std::str::FromStr for WorkAuthorization {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(':').map(str::trim).collect();
+        let [fallback, odds, rest @ ..] = parts.as_slice() else {
+            return Err(anyhow::anyhow!(
+                "无效的工作许可: '{}'，格式为 \
+                 <后备Profile>:<中签率>[,<中签率>...][:<OPT月数>[:<STEM延期月数>]]",
+                s
+            ));
+        };
+        let months = |index: usize, default: u32| -> Result<u32> {
+            rest.get(index).map_or(Ok(default), |value| {
+                value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("无效的月数: '{}'", value))
+            })
+        };
+        let auth = WorkAuthorization {
+            opt_months:            months(0, default_opt_months())?,
+            stem_extension_months: months(1, 24)?,
+            lottery_odds:          odds
+                .split(',')
+                .map(|odds| {
+                    odds.trim()
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("无效的中签率: '{}'", odds))
+                })
+                .collect::<Result<_>>()?,
+            fallback:              fallback.to_string(),
+        };
+        auth.validate()?;
+        Ok(auth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_authorization() {
+        let auth: WorkAuthorization = "上海工作:0.3,0.3,0.3".parse().unwrap();
+        assert_eq!(auth.fallback, "上海工作");
+        assert_eq!(auth.authorized_months(), 36);
+
+        // 三次抽签：30%、21%、14.7%，全部未中 34.3%
+        let wins = auth.win_probabilities();
+        assert!((wins[1] - 0.21).abs() < 1e-9);
+        assert!((wins[2] - 0.147).abs() < 1e-9);
+        assert!((auth.failure_probability() - 0.343).abs() < 1e-9);
+        assert!((wins.iter().sum::<f64>() + auth.failure_probability() - 1.0).abs() < 1e-9);
+
+        // 非STEM专业只有12个月OPT，只能抽签一次
+        let non_stem: WorkAuthorization = "Toronto:0.35:12:0".parse().unwrap();
+        assert_eq!(non_stem.max_attempts(), 1);
+        assert!(
+            "Toronto:0.35,0.35:12:0"
+                .parse::<WorkAuthorization>()
+                .is_err()
+        );
+
+        // 配置文件中的写法
+        let auth: WorkAuthorization =
+            serde_yaml::from_str("lottery_odds: [0.35]\nfallback: Toronto").unwrap();
+        assert_eq!((auth.opt_months, auth.stem_extension_months), (12, 0));
+
+        assert!("上海工作:1.5".parse::<WorkAuthorization>().is_err());
+        assert!("上海工作".parse::<WorkAuthorization>().is_err());
+    }
+}
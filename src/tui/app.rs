@@ -0,0 +1,522 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::DefaultTerminal;
+use uuid::Uuid;
+
+use super::draw;
+use crate::{
+    calculator::{
+        PathYearlyData, ROICalculator, StudyCostCalculator, StudyCostEstimate, StudyDuration,
+    },
+    cli::{CountryArg, base_calculator},
+    config::SchoolEntry,
+    db::DatabaseManager,
+    models::{CostLevel, Profile},
+};
+
+/// 学习年数可调范围
+const STUDY_YEARS_RANGE: (u32, u32) = (1, 6);
+/// 分析年限可调范围
+const ANALYSIS_YEARS_RANGE: (u32, u32) = (1, 40);
+
+/// 标签页
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Profiles,
+    Schools,
+    Compare,
+    Settings,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Profiles, Tab::Schools, Tab::Compare, Tab::Settings];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Tab::Profiles => "Profile",
+            Tab::Schools => "学校",
+            Tab::Compare => "对比",
+            Tab::Settings => "设置",
+        }
+    }
+
+    fn index(&self) -> usize { Tab::ALL.iter().position(|tab| tab == self).unwrap_or(0) }
+
+    fn next(&self) -> Tab { Tab::ALL[(self.index() + 1) % Tab::ALL.len()] }
+
+    fn previous(&self) -> Tab { Tab::ALL[(self.index() + Tab::ALL.len() - 1) % Tab::ALL.len()] }
+}
+
+/// TUI应用状态
+pub struct App {
+    pub tab:              Tab,
+    pub profiles:         Vec<Profile>,
+    pub selected_profile: usize,
+    /// 加入对比的Profile
+    pub compared:         HashSet<Uuid>,
+    /// 对比基准Profile（如留在国内工作）
+    pub baseline:         Option<Uuid>,
+    pub schools:          Option<StudyCostCalculator>,
+    pub selected_school:  usize,
+    pub country:          CountryArg,
+    /// None时使用各Profile自带的成本档位
+    pub cost_level:       Option<CostLevel>,
+    pub study_years:      u32,
+    /// Profile页并排显示三种成本档位的净资产走势
+    pub show_levels:      bool,
+    pub calculator:       ROICalculator,
+    pub show_help:        bool,
+    pub status:           String,
+    pub should_quit:      bool,
+    db:                   Option<DatabaseManager>,
+}
+
+impl App {
+    pub fn new(profiles: Vec<Profile>, schools: Option<StudyCostCalculator>) -> Self {
+        // 学校配置加载成功时使用 regions.yaml 中的汇率和通胀假设
+        let (rates, inflation) = schools
+            .as_ref()
+            .map(|schools| {
+                let config = schools.living_cost_estimator().config();
+                (config.exchange_rates.rates(), config.inflation)
+            })
+            .unwrap_or_default();
+        Self {
+            tab: Tab::Profiles,
+            profiles,
+            selected_profile: 0,
+            compared: HashSet::new(),
+            baseline: None,
+            schools,
+            selected_school: 0,
+            country: CountryArg::Both,
+            cost_level: None,
+            study_years: 2,
+            show_levels: false,
+            calculator: ROICalculator {
+                rates,
+                inflation,
+                ..ROICalculator::default()
+            },
+            show_help: false,
+            status: "按 h 查看帮助".to_string(),
+            should_quit: false,
+            db: None,
+        }
+    }
+
+    /// 从数据库和 config/ 目录加载
+    pub fn load() -> Result<Self> {
+        let mut db = DatabaseManager::new()?;
+        let profiles = db.get_profiles()?;
+        let schools = StudyCostCalculator::load_default();
+
+        let mut app = Self::new(profiles, schools.as_ref().ok().cloned());
+        if let Err(e) = schools {
+            app.status = format!("⚠️ 学校配置加载失败: {}", e);
+        }
+        // 使用 config/ 下的汇率、通胀和税表
+        match base_calculator() {
+            Ok(calculator) => app.calculator = calculator,
+            Err(e) => app.status = format!("⚠️ 配置加载失败: {}", e),
+        }
+        app.db = Some(db);
+        Ok(app)
+    }
+
+    /// 事件循环
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.should_quit {
+            terminal.draw(|frame| draw(frame, self))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle_key(key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if self.show_help {
+            if matches!(
+                key.code,
+                KeyCode::Char('h') | KeyCode::F(1) | KeyCode::Esc | KeyCode::Char('q')
+            ) {
+                self.show_help = false;
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('h') | KeyCode::F(1) => self.show_help = true,
+            KeyCode::Tab | KeyCode::Right => self.tab = self.tab.next(),
+            KeyCode::BackTab | KeyCode::Left => self.tab = self.tab.previous(),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle_compared(),
+            KeyCode::Char('m') => self.toggle_baseline(),
+            KeyCode::Char('1') => self.set_country(CountryArg::Us),
+            KeyCode::Char('2') => self.set_country(CountryArg::Canada),
+            KeyCode::Char('3') => self.set_country(CountryArg::Both),
+            KeyCode::Char('b') => self.set_cost_level(CostLevel::Budget),
+            KeyCode::Char('s') => self.set_cost_level(CostLevel::Standard),
+            KeyCode::Char('c') => self.set_cost_level(CostLevel::Comfortable),
+            KeyCode::Char('v') => self.toggle_levels(),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.adjust_years(1),
+            KeyCode::Char('-') => self.adjust_years(-1),
+            KeyCode::Char('u') => self.cycle_currency(),
+            KeyCode::Char('i') => self.toggle_real_terms(),
+            KeyCode::Char('f') => self.cycle_funding_mode(),
+            KeyCode::Char('r') => self.reload(),
+            _ => {}
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = match self.tab {
+            Tab::Schools => self.visible_schools().len(),
+            _ => self.profiles.len(),
+        };
+        let selected = match self.tab {
+            Tab::Schools => &mut self.selected_school,
+            _ => &mut self.selected_profile,
+        };
+        if len > 0 {
+            *selected = (*selected as isize + delta).clamp(0, len as isize - 1) as usize;
+        }
+    }
+
+    fn toggle_compared(&mut self) {
+        let Some(profile) = self.selected_profile() else {
+            return;
+        };
+        let (id, name) = (profile.id, profile.name.clone());
+        if self.compared.remove(&id) {
+            self.status = format!("已移出对比: {}", name);
+        } else {
+            self.compared.insert(id);
+            self.status = format!("已加入对比: {}", name);
+        }
+    }
+
+    fn toggle_baseline(&mut self) {
+        let Some(profile) = self.selected_profile() else {
+            return;
+        };
+        let (id, name) = (profile.id, profile.name.clone());
+        if self.baseline == Some(id) {
+            self.baseline = None;
+            self.status = format!("已取消基准: {}", name);
+        } else {
+            self.baseline = Some(id);
+            self.status = format!("已设为基准: {}", name);
+        }
+    }
+
+    fn set_country(&mut self, country: CountryArg) {
+        self.country = country;
+        self.selected_school = 0;
+        self.status = format!("国家: {}", country.label());
+    }
+
+    fn set_cost_level(&mut self, level: CostLevel) {
+        self.cost_level = Some(level);
+        self.calculator.cost_level = Some(level);
+        self.status = format!("成本档位: {}", level.label());
+    }
+
+    fn toggle_levels(&mut self) {
+        self.show_levels = !self.show_levels;
+        self.status = if self.show_levels {
+            "净资产走势: 三种成本档位对比".to_string()
+        } else {
+            "净资产走势: 当前档位".to_string()
+        };
+    }
+
+    fn cycle_currency(&mut self) {
+        let currency = self.calculator.reporting_currency.next();
+        self.calculator.reporting_currency = currency;
+        self.status = format!("报告货币: {} ({})", currency.label(), currency);
+    }
+
+    fn cycle_funding_mode(&mut self) {
+        let mode = self.calculator.funding_mode.next();
+        self.calculator.funding_mode = mode;
+        self.status = format!("资助: {}", mode.label());
+    }
+
+    fn toggle_real_terms(&mut self) {
+        self.calculator.real_terms = !self.calculator.real_terms;
+        self.status = format!("金额口径: {}", self.calculator.value_label());
+    }
+
+    /// 学校页调整学习年数，其余页调整分析年限
+    fn adjust_years(&mut self, delta: i32) {
+        let clamp = |value: u32, (min, max): (u32, u32)| {
+            (value as i32 + delta).clamp(min as i32, max as i32) as u32
+        };
+        if self.tab == Tab::Schools {
+            self.study_years = clamp(self.study_years, STUDY_YEARS_RANGE);
+            self.status = format!("学习年数: {}", self.study_years);
+        } else {
+            self.calculator.total_years = clamp(self.calculator.total_years, ANALYSIS_YEARS_RANGE);
+            self.status = format!("分析年限: {}", self.calculator.total_years);
+        }
+    }
+
+    /// 重新从数据库读取Profile
+    fn reload(&mut self) {
+        let Some(db) = self.db.as_mut() else {
+            return;
+        };
+        match db.get_profiles() {
+            Ok(profiles) => {
+                self.status = format!("已重新加载 {} 个Profile", profiles.len());
+                self.compared
+                    .retain(|id| profiles.iter().any(|profile| &profile.id == id));
+                self.baseline = self
+                    .baseline
+                    .filter(|id| profiles.iter().any(|profile| &profile.id == id));
+                self.profiles = profiles;
+                self.selected_profile = self
+                    .selected_profile
+                    .min(self.profiles.len().saturating_sub(1));
+            }
+            Err(e) => self.status = format!("⚠️ 重新加载失败: {}", e),
+        }
+    }
+
+    pub fn selected_profile(&self) -> Option<&Profile> { self.profiles.get(self.selected_profile) }
+
+    /// 当前Profile的逐年数据
+    pub fn selected_path(&self) -> Vec<PathYearlyData> {
+        self.selected_profile()
+            .map(|profile| self.calculator.calculate_profile_path(profile))
+            .unwrap_or_default()
+    }
+
+    /// 当前Profile在三种成本档位下的逐年数据
+    pub fn selected_level_paths(&self) -> Vec<(CostLevel, Vec<PathYearlyData>)> {
+        self.selected_profile()
+            .map(|profile| self.calculator.compare_cost_levels(profile))
+            .unwrap_or_default()
+    }
+
+    /// 参与对比的Profile（未选择时对比全部），基准Profile排在第一位
+    pub fn compared_profiles(&self) -> Vec<&Profile> {
+        let mut profiles: Vec<&Profile> = self
+            .profiles
+            .iter()
+            .filter(|profile| {
+                self.compared.is_empty()
+                    || self.compared.contains(&profile.id)
+                    || self.baseline == Some(profile.id)
+            })
+            .collect();
+        if let Some(index) = profiles
+            .iter()
+            .position(|profile| self.baseline == Some(profile.id))
+        {
+            let baseline = profiles.remove(index);
+            profiles.insert(0, baseline);
+        }
+        profiles
+    }
+
+    /// 当前是否按基准Profile做增量对比
+    pub fn has_baseline(&self) -> bool {
+        self.baseline
+            .is_some_and(|id| self.profiles.iter().any(|profile| profile.id == id))
+    }
+
+    /// 当前国家筛选下的学校
+    pub fn visible_schools(&self) -> Vec<&SchoolEntry> {
+        let countries = self.country.countries();
+        self.schools
+            .iter()
+            .flat_map(|schools| schools.catalog().entries())
+            .filter(|entry| countries.contains(&entry.country))
+            .collect()
+    }
+
+    pub fn study_duration(&self) -> StudyDuration { StudyDuration::new(self.study_years, 0) }
+
+    /// 当前学校在所选年数和档位下的成本估算
+    pub fn selected_school_estimate(&self) -> Option<Result<StudyCostEstimate>> {
+        let schools = self.schools.as_ref()?;
+        let entry = *self.visible_schools().get(self.selected_school)?;
+        Some(schools.estimate(
+            entry,
+            self.study_duration(),
+            self.cost_level.unwrap_or_default(),
+        ))
+    }
+}
+
+/// 启动全屏TUI
+pub fn run_tui() -> Result<()> {
+    let mut app = App::load()?;
+    let mut terminal = ratatui::try_init()?;
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Currency, ProfileType, test_profile};
+
+    fn app() -> App {
+        let profiles = ["上海工作", "美国硕士", "加拿大硕士"]
+            .into_iter()
+            .zip([
+                ProfileType::Work,
+                ProfileType::Education,
+                ProfileType::Education,
+            ])
+            .map(|(name, profile_type)| {
+                let mut profile = test_profile(profile_type, Currency::Usd, 100000.0, 30000.0);
+                profile.name = name.to_string();
+                profile
+            })
+            .collect();
+        App::new(profiles, None)
+    }
+
+    fn press(app: &mut App, codes: &[KeyCode]) {
+        for &code in codes {
+            app.handle_key(KeyEvent::from(code));
+        }
+    }
+
+    fn compared_names(app: &App) -> Vec<&str> {
+        app.compared_profiles()
+            .into_iter()
+            .map(|profile| profile.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_and_quit() {
+        let mut app = app();
+        press(&mut app, &[KeyCode::Tab, KeyCode::Right]);
+        assert_eq!(app.tab, Tab::Compare);
+        press(&mut app, &[KeyCode::BackTab, KeyCode::Left, KeyCode::Left]);
+        assert_eq!(app.tab, Tab::Settings);
+        press(&mut app, &[KeyCode::Tab]);
+
+        // 选择停在列表两端
+        press(
+            &mut app,
+            &[
+                KeyCode::Up,
+                KeyCode::Down,
+                KeyCode::Char('j'),
+                KeyCode::Down,
+            ],
+        );
+        assert_eq!(app.selected_profile, 2);
+        press(&mut app, &[KeyCode::Char('k')]);
+        assert_eq!(app.selected_profile().unwrap().name, "美国硕士");
+
+        // 帮助打开时 q 只关闭帮助
+        press(
+            &mut app,
+            &[KeyCode::Char('h'), KeyCode::Char('m'), KeyCode::Char('q')],
+        );
+        assert!(!app.show_help);
+        assert!(!app.should_quit);
+        assert_eq!(app.baseline, None);
+        press(&mut app, &[KeyCode::Char('q')]);
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_compare_and_baseline() {
+        let mut app = app();
+        let ids: Vec<Uuid> = app.profiles.iter().map(|profile| profile.id).collect();
+
+        // 未选择时对比全部
+        assert_eq!(compared_names(&app), ["上海工作", "美国硕士", "加拿大硕士"]);
+
+        press(
+            &mut app,
+            &[
+                KeyCode::Down,
+                KeyCode::Enter,
+                KeyCode::Down,
+                KeyCode::Char(' '),
+            ],
+        );
+        assert_eq!(app.compared, HashSet::from([ids[1], ids[2]]));
+        assert_eq!(compared_names(&app), ["美国硕士", "加拿大硕士"]);
+
+        // 基准排在第一位，即使未加入对比
+        press(&mut app, &[KeyCode::Up, KeyCode::Up, KeyCode::Char('m')]);
+        assert_eq!(app.baseline, Some(ids[0]));
+        assert!(app.has_baseline());
+        assert_eq!(compared_names(&app), ["上海工作", "美国硕士", "加拿大硕士"]);
+
+        press(
+            &mut app,
+            &[KeyCode::Down, KeyCode::Down, KeyCode::Char('m')],
+        );
+        assert_eq!(app.baseline, Some(ids[2]));
+        assert_eq!(compared_names(&app), ["加拿大硕士", "美国硕士"]);
+
+        // 再次按键取消
+        press(&mut app, &[KeyCode::Char('m'), KeyCode::Enter]);
+        assert_eq!(app.baseline, None);
+        assert!(!app.has_baseline());
+        assert_eq!(app.compared, HashSet::from([ids[1]]));
+    }
+
+    #[test]
+    fn test_settings_keys() {
+        let mut app = app();
+
+        press(&mut app, &[KeyCode::Char('b')]);
+        assert_eq!(app.cost_level, Some(CostLevel::Budget));
+        assert_eq!(app.calculator.cost_level, Some(CostLevel::Budget));
+        press(&mut app, &[KeyCode::Char('c')]);
+        assert_eq!(app.calculator.cost_level, Some(CostLevel::Comfortable));
+        press(&mut app, &[KeyCode::Char('s')]);
+        assert_eq!(app.calculator.cost_level, Some(CostLevel::Standard));
+
+        press(&mut app, &[KeyCode::Char('2')]);
+        assert_eq!(app.country, CountryArg::Canada);
+        press(&mut app, &[KeyCode::Char('1')]);
+        assert_eq!(app.country, CountryArg::Us);
+        press(&mut app, &[KeyCode::Char('3')]);
+        assert_eq!(app.country, CountryArg::Both);
+
+        // 学校页调整学习年数，其余页调整分析年限
+        press(&mut app, &[KeyCode::Char('+'), KeyCode::Char('=')]);
+        assert_eq!(app.calculator.total_years, 12);
+        press(&mut app, &[KeyCode::Tab]);
+        assert_eq!(app.tab, Tab::Schools);
+        press(&mut app, &[KeyCode::Char('-'); 3]);
+        assert_eq!(app.study_years, STUDY_YEARS_RANGE.0);
+        assert_eq!(app.calculator.total_years, 12);
+
+        press(&mut app, &[KeyCode::Char('v'), KeyCode::Char('i')]);
+        assert!(app.show_levels);
+        assert!(app.calculator.real_terms);
+
+        let currency = app.calculator.reporting_currency;
+        let funding_mode = app.calculator.funding_mode;
+        press(&mut app, &[KeyCode::Char('u'), KeyCode::Char('f')]);
+        assert_eq!(app.calculator.reporting_currency, currency.next());
+        assert_eq!(app.calculator.funding_mode, funding_mode.next());
+
+        // 没有数据库时重新加载不改变状态
+        press(&mut app, &[KeyCode::Char('r')]);
+        assert_eq!(app.profiles.len(), 3);
+    }
+}
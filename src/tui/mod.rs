@@ -0,0 +1,4 @@
+pub mod app;
+pub mod view;
+pub use app::*;
+pub use view::*;
@@ -0,0 +1,719 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, GraphType, List, ListItem, ListState,
+        Paragraph, Row, Table, Tabs, Wrap,
+    },
+};
+
+use super::{App, Tab};
+use crate::{
+    calculator::{BaselineComparison, PathYearlyData, ROICalculator},
+    models::{Profile, ProfileType},
+};
+
+/// 多条曲线使用的颜色
+const SERIES_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Yellow,
+    Color::Green,
+    Color::Magenta,
+    Color::Red,
+    Color::Blue,
+];
+
+const HIGHLIGHT: Style = Style::new().fg(Color::Black).bg(Color::Cyan);
+
+/// 绘制整个界面
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    draw_tabs(frame, app, header);
+    match app.tab {
+        Tab::Profiles => draw_profiles(frame, app, body),
+        Tab::Schools => draw_schools(frame, app, body),
+        Tab::Compare => draw_compare(frame, app, body),
+        Tab::Settings => draw_settings(frame, app, body),
+    }
+    draw_footer(frame, app, footer);
+
+    if app.show_help {
+        draw_help(frame);
+    }
+}
+
+fn draw_tabs(frame: &mut Frame, app: &App, area: Rect) {
+    let titles: Vec<&str> = Tab::ALL.iter().map(|tab| tab.title()).collect();
+    let selected = Tab::ALL.iter().position(|tab| *tab == app.tab).unwrap_or(0);
+    let level = app
+        .cost_level
+        .map(|level| level.label())
+        .unwrap_or("按Profile");
+
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " 🎯 ROI Calculator │ {} │ 档位: {} │ 学习 {} 年 │ 分析 {} 年 ",
+            app.country.label(),
+            level,
+            app.study_years,
+            app.calculator.total_years
+        )));
+    frame.render_widget(tabs, area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = Line::from(vec![
+        Span::styled(
+            " q 退出  h 帮助  Tab 切换  ↑↓ 选择  Enter 加入对比 │ ",
+            Style::new().fg(Color::DarkGray),
+        ),
+        Span::raw(app.status.as_str()),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn draw_profiles(frame: &mut Frame, app: &App, area: Rect) {
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(30), Constraint::Percentage(70)]).areas(area);
+
+    let items: Vec<ListItem> = app
+        .profiles
+        .iter()
+        .map(|profile| {
+            let marker = if app.baseline == Some(profile.id) {
+                "★ "
+            } else if app.compared.contains(&profile.id) {
+                "✓ "
+            } else {
+                "  "
+            };
+            let icon = match profile.profile_type {
+                ProfileType::Education => "🎓",
+                ProfileType::Work => "💼",
+            };
+            ListItem::new(format!("{}{} {}", marker, icon, profile.name))
+        })
+        .collect();
+    let mut state = ListState::default().with_selected(Some(app.selected_profile));
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Profile ({}) ", app.profiles.len())),
+        )
+        .highlight_style(HIGHLIGHT);
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let Some(profile) = app.selected_profile() else {
+        let empty =
+            Paragraph::new("暂无Profile，可使用 `profile add` 或交互式菜单创建后按 r 重新加载")
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(empty, detail_area);
+        return;
+    };
+
+    let [info_area, table_area, chart_area] = Layout::vertical([
+        Constraint::Length(8),
+        Constraint::Percentage(45),
+        Constraint::Min(8),
+    ])
+    .areas(detail_area);
+
+    let path = app.selected_path();
+    draw_profile_info(frame, profile, &app.calculator, info_area);
+    draw_yearly_table(frame, &path, &app.calculator, table_area);
+    if app.show_levels {
+        let levels = app.selected_level_paths();
+        let series: Vec<(&str, &[PathYearlyData])> = levels
+            .iter()
+            .map(|(level, path)| (level.label(), path.as_slice()))
+            .collect();
+        draw_net_worth_chart(
+            frame,
+            &app.calculator,
+            " 净资产走势（成本档位对比） ",
+            &series,
+            chart_area,
+        );
+    } else {
+        draw_net_worth_chart(
+            frame,
+            &app.calculator,
+            " 净资产走势 ",
+            &[(profile.name.as_str(), path.as_slice())],
+            chart_area,
+        );
+    }
+}
+
+fn draw_profile_info(frame: &mut Frame, profile: &Profile, calculator: &ROICalculator, area: Rect) {
+    let financial = &profile.financial_params;
+    // 地区配置了税表时显示首年实际税率
+    let salary = financial.initial_salary_usd;
+    let tax_rate = calculator
+        .regional_tax(
+            salary,
+            profile.location.region.as_deref(),
+            profile.household.minor_children(1),
+        )
+        .map_or(financial.tax_rate, |tax| tax.effective_rate(salary));
+    let location = match &profile.location.city {
+        Some(city) => format!("{}, {}", city, profile.location.country),
+        None => profile.location.country.clone(),
+    };
+    let cost = match &profile.cost_params {
+        Some(cost) => format!(
+            "{} (分摊{}年)",
+            calculator.format_amount(cost.total_cost_usd),
+            cost.cost_duration
+        ),
+        None => "无".to_string(),
+    };
+
+    let loans = match profile.loans.as_slice() {
+        [] => "无".to_string(),
+        loans => loans
+            .iter()
+            .map(|loan| {
+                format!(
+                    "{} {}",
+                    loan.source.label(),
+                    calculator.format_amount(loan.principal.to_usd(&calculator.rates))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" + "),
+    };
+
+    let funding = match profile.funding.as_slice() {
+        [] => "无".to_string(),
+        _ => {
+            let params = profile.to_path_params();
+            format!(
+                "{} ({})",
+                calculator.format_amount(calculator.funding_total(&params)),
+                calculator.funding_mode.label()
+            )
+        }
+    };
+
+    let lines = vec![
+        Line::from(format!(
+            "📍 {} ({})   ⏱️ 工作延迟 {} 年",
+            location, profile.location.currency, profile.work_params.start_delay
+        )),
+        Line::from(format!(
+            "💰 初始年薪 {} (+{:.1}%/年)   🏛️ 税率 {:.1}%",
+            calculator.format_amount(financial.initial_salary_usd),
+            financial.salary_growth_rate * 100.0,
+            tax_rate * 100.0
+        )),
+        Line::from(format!(
+            "🏠 生活成本 {}/年 (+{:.1}%/年)   📈 档位 {}",
+            calculator.format_amount(financial.living_cost_usd),
+            financial.living_cost_growth * 100.0,
+            profile.cost_level.label()
+        )),
+        Line::from(format!(
+            "💸 初期成本 {}   👨‍👩‍👧 {}",
+            cost,
+            profile.household.summary()
+        )),
+        Line::from(format!("🏦 学生贷款 {}   🎓 资助 {}", loans, funding)),
+        Line::from(profile.description.clone().unwrap_or_default()),
+    ];
+
+    let info = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", profile.name)),
+    );
+    frame.render_widget(info, area);
+}
+
+fn draw_yearly_table(
+    frame: &mut Frame,
+    path: &[PathYearlyData],
+    calculator: &ROICalculator,
+    area: Rect,
+) {
+    let header = Row::new([
+        "年份",
+        "收入",
+        "生活成本",
+        "可支配",
+        "累计投资",
+        "贷款余额",
+        "净资产",
+    ])
+    .style(Style::new().add_modifier(Modifier::BOLD));
+    let rows = path.iter().map(|data| {
+        Row::new(vec![
+            data.year.to_string(),
+            calculator.format_amount(data.income_usd),
+            calculator.format_amount(data.living_cost_usd),
+            calculator.format_amount(data.disposable_income_usd),
+            calculator.format_amount(data.total_investment),
+            calculator.format_amount(data.debt),
+            calculator.format_amount(data.net_worth),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(" 逐年数据 "));
+    frame.render_widget(table, area);
+}
+
+/// 绘制一条或多条净资产曲线
+fn draw_net_worth_chart(
+    frame: &mut Frame,
+    calculator: &ROICalculator,
+    title: &str,
+    series: &[(&str, &[PathYearlyData])],
+    area: Rect,
+) {
+    let points: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|(_, path)| {
+            path.iter()
+                .map(|data| (data.year as f64, data.net_worth))
+                .collect()
+        })
+        .collect();
+
+    let max_year = points
+        .iter()
+        .flatten()
+        .map(|(year, _)| *year)
+        .fold(1.0, f64::max);
+    let (min_worth, max_worth) = points
+        .iter()
+        .flatten()
+        .fold((0.0f64, 0.0f64), |(min, max), (_, worth)| {
+            (min.min(*worth), max.max(*worth))
+        });
+    let max_worth = if max_worth > min_worth {
+        max_worth
+    } else {
+        min_worth + 1.0
+    };
+
+    let datasets: Vec<Dataset> = series
+        .iter()
+        .zip(&points)
+        .enumerate()
+        .map(|(i, ((name, _), data))| {
+            Dataset::default()
+                .name(name.to_string())
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::new().fg(SERIES_COLORS[i % SERIES_COLORS.len()]))
+                .data(data)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .x_axis(
+            Axis::default()
+                .title("年份")
+                .bounds([1.0, max_year])
+                .labels(["1".to_string(), format!("{:.0}", max_year)]),
+        )
+        .y_axis(
+            Axis::default()
+                .title("净资产")
+                .bounds([min_worth, max_worth])
+                .labels([
+                    calculator.format_amount(min_worth),
+                    calculator.format_amount((min_worth + max_worth) / 2.0),
+                    calculator.format_amount(max_worth),
+                ]),
+        );
+    frame.render_widget(chart, area);
+}
+
+fn draw_schools(frame: &mut Frame, app: &App, area: Rect) {
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(area);
+
+    let calculator = &app.calculator;
+    let schools = app.visible_schools();
+    let items: Vec<ListItem> = schools
+        .iter()
+        .map(|entry| ListItem::new(format!("{} ({})", entry.school.short_name, entry.tier)))
+        .collect();
+    let mut state = ListState::default().with_selected(Some(app.selected_school));
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " 学校 - {} ({}) ",
+            app.country.label(),
+            schools.len()
+        )))
+        .highlight_style(HIGHLIGHT);
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let block = Block::default().borders(Borders::ALL).title(" 成本估算 ");
+    let estimate = match app.selected_school_estimate() {
+        Some(Ok(estimate)) => estimate,
+        Some(Err(e)) => {
+            frame.render_widget(
+                Paragraph::new(format!("⚠️ {}", e)).block(block),
+                detail_area,
+            );
+            return;
+        }
+        None => {
+            let message = if app.schools.is_some() {
+                "没有符合条件的学校"
+            } else {
+                "未加载学校配置，可运行 `config generate` 生成"
+            };
+            frame.render_widget(Paragraph::new(message).block(block), detail_area);
+            return;
+        }
+    };
+
+    let [summary_area, table_area] =
+        Layout::vertical([Constraint::Length(7), Constraint::Min(0)]).areas(detail_area);
+
+    let summary = Paragraph::new(vec![
+        Line::from(Span::styled(
+            estimate.school.clone(),
+            Style::new().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "📍 {}   📅 {}   💡 {}",
+            estimate.region,
+            estimate.duration,
+            estimate.cost_level.label()
+        )),
+        Line::from(format!(
+            "基础成本 (学费+生活费): {}",
+            calculator.format_amount(estimate.base_cost())
+        )),
+        Line::from(format!(
+            "附加费用: {}",
+            calculator.format_amount(estimate.additional_usd)
+        )),
+        Line::from(Span::styled(
+            format!("总留学成本: {}", calculator.format_amount(estimate.total())),
+            Style::new().fg(Color::Yellow),
+        )),
+    ])
+    .block(block);
+    frame.render_widget(summary, summary_area);
+
+    let rows = estimate.categories.iter().map(|cost| {
+        Row::new(vec![
+            cost.category.clone(),
+            calculator.format_amount(cost.amount_usd),
+        ])
+    });
+    let table = Table::new(rows, [Constraint::Fill(1), Constraint::Length(14)])
+        .header(
+            Row::new([
+                "费用类别".to_string(),
+                format!("金额 ({})", calculator.reporting_currency),
+            ])
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().borders(Borders::ALL).title(" 费用分解 "));
+    frame.render_widget(table, table_area);
+}
+
+fn draw_compare(frame: &mut Frame, app: &App, area: Rect) {
+    let profiles = app.compared_profiles();
+    if profiles.is_empty() {
+        let empty = Paragraph::new("暂无可对比的Profile")
+            .block(Block::default().borders(Borders::ALL).title(" 对比 "));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let calculator = &app.calculator;
+    let comparison = calculator.compare_profiles(profiles.iter().copied());
+
+    let baseline_rows = if app.has_baseline() {
+        profiles.len() as u16 + 2
+    } else {
+        0
+    };
+    let [table_area, baseline_area, chart_area] = Layout::vertical([
+        Constraint::Length(profiles.len() as u16 + 3),
+        Constraint::Length(baseline_rows),
+        Constraint::Min(8),
+    ])
+    .areas(area);
+
+    let rows = comparison.ranking().into_iter().map(|ranking| {
+        Row::new(vec![
+            ranking.rank.to_string(),
+            ranking.name,
+            calculator.format_amount(ranking.final_net_worth),
+            ranking
+                .roi
+                .map(|roi| format!("{:.1}%", roi * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            calculator.format_amount(ranking.metrics.npv),
+            format_irr(ranking.metrics.irr),
+            calculator.format_amount(ranking.gap_to_best),
+        ])
+    });
+    let title = if app.compared.is_empty() {
+        " 全部Profile对比（在Profile页按Enter选择） "
+    } else {
+        " 已选Profile对比 "
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new([
+            "排名",
+            "Profile",
+            "最终净资产",
+            "ROI",
+            "NPV",
+            "IRR",
+            "与第一名差距",
+        ])
+        .style(Style::new().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(table, table_area);
+
+    if app.has_baseline() {
+        draw_baseline_table(
+            frame,
+            &comparison.relative_to(0),
+            &app.calculator,
+            baseline_area,
+        );
+    }
+
+    let series: Vec<(&str, &[PathYearlyData])> = comparison
+        .paths
+        .iter()
+        .map(|path| (path.name.as_str(), path.data.as_slice()))
+        .collect();
+    draw_net_worth_chart(frame, &app.calculator, " 净资产对比 ", &series, chart_area);
+}
+
+fn format_irr(irr: Option<f64>) -> String {
+    irr.map(|irr| format!("{:.1}%", irr * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// 相对基准Profile的增量
+fn draw_baseline_table(
+    frame: &mut Frame,
+    deltas: &[BaselineComparison],
+    calculator: &ROICalculator,
+    area: Rect,
+) {
+    let Some(baseline_name) = deltas.first().map(|d| d.baseline_name.as_str()) else {
+        return;
+    };
+    let rows = deltas.iter().map(|d| {
+        Row::new(vec![
+            d.name.clone(),
+            calculator.format_amount(d.incremental_cost),
+            calculator.format_amount(d.max_shortfall()),
+            calculator.format_amount(d.final_delta()),
+            d.breakeven_year
+                .map(|year| format!("第{}年", year))
+                .unwrap_or_else(|| "未回本".to_string()),
+            calculator.format_amount(d.metrics.npv),
+            format_irr(d.metrics.irr),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+        ],
+    )
+    .header(
+        Row::new([
+            "Profile",
+            "增量成本",
+            "最大资金缺口",
+            "最终增量净资产",
+            "回本年份",
+            "增量NPV",
+            "增量IRR",
+        ])
+        .style(Style::new().add_modifier(Modifier::BOLD)),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" 相对基准「{}」 ", baseline_name)),
+    );
+    frame.render_widget(table, area);
+}
+
+fn draw_settings(frame: &mut Frame, app: &App, area: Rect) {
+    let calculator = &app.calculator;
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "计算参数",
+            Style::new().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("  分析年限: {} 年", calculator.total_years)),
+        Line::from(format!(
+            "  投资回报率: {:.1}%",
+            calculator.investment_return_rate * 100.0
+        )),
+        Line::from(format!(
+            "  投资比例: {:.0}%",
+            calculator.investment_portion * 100.0
+        )),
+        Line::from(format!(
+            "  折现率: {:.1}%",
+            calculator.discount_rate * 100.0
+        )),
+        Line::from(format!(
+            "  成本档位: {}",
+            app.cost_level
+                .map(|level| level.label())
+                .unwrap_or("按Profile设置")
+        )),
+        Line::from(format!(
+            "  报告货币: {} ({})",
+            calculator.reporting_currency.label(),
+            calculator.reporting_currency
+        )),
+        Line::from(format!(
+            "  金额口径: {}（通胀 {:.1}%）",
+            calculator.value_label(),
+            calculator.inflation_rate() * 100.0
+        )),
+        Line::from(format!("  资助: {}", calculator.funding_mode.label())),
+        Line::from(format!("  学习年数: {} 年", app.study_years)),
+        Line::from(format!("  国家筛选: {}", app.country.label())),
+        Line::from(""),
+        Line::from(Span::styled(
+            "配置文件",
+            Style::new().add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    match &app.schools {
+        Some(schools) => {
+            let rates = &schools.living_cost_estimator().config().exchange_rates;
+            lines.push(Line::from(format!(
+                "  学校: {} 所",
+                schools.catalog().entries().len()
+            )));
+            lines.push(Line::from(format!(
+                "  汇率 ({}): USD/CNY {:.2}, CAD/USD {:.2}",
+                rates.last_updated, rates.usd_to_cny, rates.cad_to_usd
+            )));
+        }
+        None => lines.push(Line::from("  未加载")),
+    }
+
+    let settings =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" 设置 "));
+    frame.render_widget(settings, area);
+}
+
+fn draw_help(frame: &mut Frame) {
+    let keys = [
+        ("q / Esc", "退出程序"),
+        ("h / F1", "显示/隐藏帮助"),
+        ("Tab / ←→", "切换标签页"),
+        ("↑↓ / j k", "选择Profile或学校"),
+        ("Enter / 空格", "将Profile加入/移出对比"),
+        ("m", "将Profile设为/取消对比基准"),
+        ("1 / 2 / 3", "美国 / 加拿大 / 两国"),
+        ("b / s / c", "节约型 / 标准型 / 舒适型档位"),
+        ("v", "净资产走势切换为三种档位对比"),
+        ("+ / -", "学校页调整学习年数，其余页调整分析年限"),
+        ("u", "切换报告货币 (USD / CNY / CAD)"),
+        ("i", "切换名义值 / 实际值（扣除通胀）"),
+        ("f", "切换资助计入方式（已确认 / 期望值 / 不计）"),
+        ("r", "从数据库重新加载Profile"),
+    ];
+    let lines: Vec<Line> = keys
+        .iter()
+        .map(|(key, action)| {
+            Line::from(vec![
+                Span::styled(format!("{:<14}", key), Style::new().fg(Color::Cyan)),
+                Span::raw(*action),
+            ])
+        })
+        .collect();
+
+    let area = centered_rect(60, keys.len() as u16 + 2, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" 帮助 ")
+                .title_alignment(Alignment::Center),
+        ),
+        area,
+    );
+}
+
+/// 居中的弹出区域
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let [_, middle, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(height),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    let [_, center, _] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .areas(middle);
+    center
+}
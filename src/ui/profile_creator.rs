@@ -2,12 +2,16 @@ use std::fmt;
 
 use anyhow::Result;
 use chrono::Utc;
-use dialoguer::{Confirm, Input, Select, theme::ColorfulTheme};
+use dialoguer::{Confirm, Input, MultiSelect, Select, theme::ColorfulTheme};
 use uuid::Uuid;
 
 use crate::{
+    config::LivingCostEstimator,
     db::DatabaseManager,
-    models::{CostParams, FinancialParams, Location, Profile, ProfileType, WorkParams},
+    models::{
+        Child, CostLevel, CostParams, EmploymentRisk, FinancialParams, Household, Location, Money,
+        Profile, ProfileType, ScenarioSet, Timeline, WorkParams,
+    },
 };
 
 /// 状态机的状态定义
@@ -21,7 +25,6 @@ pub enum CreationState {
     CostParams,
     OpportunityParams,
     Summary,
-    Confirmation,
     Complete,
     Cancelled,
 }
@@ -59,6 +62,7 @@ pub struct ProfileBuilder {
     pub living_cost_usd:    Option<f64>,
     pub living_cost_growth: Option<f64>,
     pub tax_rate:           Option<f64>,
+    pub cost_level:         Option<CostLevel>,
 
     // 成本参数
     pub cost_params: Option<Option<CostParams>>,
@@ -66,6 +70,9 @@ pub struct ProfileBuilder {
     // 机会成本
     pub first_year_opportunity_cost: Option<Option<f64>>,
 
+    // 随行家属
+    pub household: Option<Household>,
+
     // 描述
     pub description: Option<String>,
 }
@@ -106,6 +113,7 @@ impl ProfileBuilder {
                 country:  self.country.unwrap(),
                 city:     self.city,
                 currency: self.currency.unwrap(),
+                region:   None,
             },
             work_params: WorkParams {
                 start_delay:    self.work_start_delay.unwrap(),
@@ -120,6 +128,14 @@ impl ProfileBuilder {
             },
             cost_params: self.cost_params.unwrap(),
             first_year_opportunity_cost: self.first_year_opportunity_cost.unwrap(),
+            cost_level: self.cost_level.unwrap_or_default(),
+            household: self.household.unwrap_or_default(),
+            scenarios: ScenarioSet::default(),
+            timeline: Timeline::default(),
+            loans: Vec::new(),
+            funding: Vec::new(),
+            work_authorization: None,
+            employment: EmploymentRisk::default(),
             created_at: now,
             updated_at: now,
             description: self.description,
@@ -127,21 +143,66 @@ impl ProfileBuilder {
     }
 }
 
+/// 选择成本档位
+fn prompt_cost_level(theme: &ColorfulTheme, default: CostLevel) -> Result<CostLevel> {
+    let labels: Vec<&str> = CostLevel::ALL.iter().map(|level| level.label()).collect();
+    let default_idx = CostLevel::ALL
+        .iter()
+        .position(|&level| level == default)
+        .unwrap_or(1);
+
+    let idx = Select::with_theme(theme)
+        .with_prompt("成本档位")
+        .items(&labels)
+        .default(default_idx)
+        .interact()?;
+
+    Ok(CostLevel::ALL[idx])
+}
+
+/// 设置随行家属（配偶、子女）
+fn prompt_household(theme: &ColorfulTheme, current: &Household) -> Result<Household> {
+    let spouse = Confirm::with_theme(theme)
+        .with_prompt("是否有配偶随行?")
+        .default(current.spouse)
+        .interact()?;
+
+    let child_count: usize = Input::with_theme(theme)
+        .with_prompt("随行子女数量")
+        .with_initial_text(current.children.len().to_string())
+        .interact_text()?;
+
+    let mut children = Vec::with_capacity(child_count);
+    for i in 0..child_count {
+        let initial = current.children.get(i).map(|child| child.age).unwrap_or(0);
+        let age: u32 = Input::with_theme(theme)
+            .with_prompt(format!("第{}个子女第1年的年龄", i + 1))
+            .with_initial_text(initial.to_string())
+            .interact_text()?;
+        children.push(Child { age });
+    }
+
+    Ok(Household { spouse, children })
+}
+
 /// 状态机实现
-pub struct ProfileCreationStateMachine {
-    current_state: CreationState,
-    builder:       ProfileBuilder,
-    db:            DatabaseManager,
-    theme:         ColorfulTheme,
+pub struct ProfileCreationStateMachine<'a> {
+    current_state:         CreationState,
+    builder:               ProfileBuilder,
+    db:                    &'a mut DatabaseManager,
+    theme:                 ColorfulTheme,
+    living_cost_estimator: Option<LivingCostEstimator>,
 }
 
-impl ProfileCreationStateMachine {
-    pub fn new(db: DatabaseManager) -> Self {
+impl<'a> ProfileCreationStateMachine<'a> {
+    pub fn new(db: &'a mut DatabaseManager) -> Self {
         Self {
             current_state: CreationState::Start,
             builder: ProfileBuilder::new(),
             db,
             theme: ColorfulTheme::default(),
+            // 地区配置缺失时退回手动输入生活成本
+            living_cost_estimator: LivingCostEstimator::load_default().ok(),
         }
     }
 
@@ -208,7 +269,6 @@ impl ProfileCreationStateMachine {
                     println!("\n❌ 已取消创建Profile");
                     return Ok(None);
                 }
-                _ => unreachable!(),
             }
         }
     }
@@ -338,9 +398,14 @@ impl ProfileCreationStateMachine {
             .with_initial_text("0.03")
             .interact_text()?;
 
+        let living_cost_initial = self
+            .prompt_region_living_cost()?
+            .map(|cost| format!("{:.0}", cost))
+            .unwrap_or_else(|| "30000".to_string());
+
         let living_cost_usd: f64 = Input::with_theme(&self.theme)
             .with_prompt("初始年生活成本 (USD)")
-            .with_initial_text("30000")
+            .with_initial_text(&living_cost_initial)
             .interact_text()?;
 
         let living_cost_growth: f64 = Input::with_theme(&self.theme)
@@ -353,13 +418,15 @@ impl ProfileCreationStateMachine {
             .with_initial_text("0.25")
             .interact_text()?;
 
+        let cost_level = prompt_cost_level(&self.theme, CostLevel::default())?;
+
         // 验证输入
-        if salary_growth_rate < 0.0 || salary_growth_rate > 1.0 {
+        if !(0.0..=1.0).contains(&salary_growth_rate) {
             println!("⚠️ 薪资增长率应该在0-1之间");
             return Ok(UserAction::Retry);
         }
 
-        if tax_rate < 0.0 || tax_rate > 1.0 {
+        if !(0.0..=1.0).contains(&tax_rate) {
             println!("⚠️ 税率应该在0-1之间");
             return Ok(UserAction::Retry);
         }
@@ -369,10 +436,46 @@ impl ProfileCreationStateMachine {
         self.builder.living_cost_usd = Some(living_cost_usd);
         self.builder.living_cost_growth = Some(living_cost_growth);
         self.builder.tax_rate = Some(tax_rate);
+        self.builder.cost_level = Some(cost_level);
 
         Ok(UserAction::Continue)
     }
 
+    /// 根据地区配置估算年生活成本，用作输入框的预填值
+    fn prompt_region_living_cost(&self) -> Result<Option<f64>> {
+        let Some(estimator) = &self.living_cost_estimator else {
+            return Ok(None);
+        };
+
+        let use_region = Confirm::with_theme(&self.theme)
+            .with_prompt("是否按地区配置估算生活成本?")
+            .default(true)
+            .interact()?;
+        if !use_region {
+            return Ok(None);
+        }
+
+        let region_keys = estimator.config().region_keys();
+        let region_idx = Select::with_theme(&self.theme)
+            .with_prompt("选择地区")
+            .items(&region_keys)
+            .default(0)
+            .interact()?;
+
+        let breakdown = estimator.estimate(region_keys[region_idx])?;
+        println!("🏠 {} 年生活成本估算 (USD):", breakdown.region_name);
+        println!("   住房: ${:.0}", breakdown.housing);
+        println!("   饮食: ${:.0}", breakdown.food);
+        println!("   交通: ${:.0}", breakdown.transport);
+        println!("   水电: ${:.0}", breakdown.utilities);
+        if breakdown.other > 0.0 {
+            println!("   其他: ${:.0}", breakdown.other);
+        }
+        println!("   合计: ${:.0}", breakdown.total());
+
+        Ok(Some(breakdown.total()))
+    }
+
     fn handle_cost_params(&mut self) -> Result<UserAction> {
         println!("\n💸 第5步: 成本参数设置");
 
@@ -445,6 +548,8 @@ impl ProfileCreationStateMachine {
             None
         };
 
+        let household = prompt_household(&self.theme, &Household::default())?;
+
         // 可选描述
         let description: String = Input::with_theme(&self.theme)
             .with_prompt("描述 (可选)")
@@ -452,6 +557,7 @@ impl ProfileCreationStateMachine {
             .interact_text()?;
 
         self.builder.first_year_opportunity_cost = Some(opportunity_cost);
+        self.builder.household = Some(household);
         self.builder.description = if description.is_empty() {
             None
         } else {
@@ -504,14 +610,15 @@ impl ProfileCreationStateMachine {
         }
 
         if let Some(salary) = self.builder.initial_salary_usd {
-            println!("💰 初始年薪: ${:,.0}", salary);
+            println!("💰 初始年薪: {}", Money::usd(salary));
         }
 
         if let Some(ref cost_params) = self.builder.cost_params {
             match cost_params {
                 Some(cost) => println!(
-                    "💸 总成本: ${:,.0} (分摊{}年)",
-                    cost.total_cost_usd, cost.cost_duration
+                    "💸 总成本: {} (分摊{}年)",
+                    Money::usd(cost.total_cost_usd),
+                    cost.cost_duration
                 ),
                 None => println!("💸 无初期成本"),
             }
@@ -519,10 +626,14 @@ impl ProfileCreationStateMachine {
 
         if let Some(ref opp_cost) = self.builder.first_year_opportunity_cost {
             match opp_cost {
-                Some(cost) => println!("🎯 机会成本: ${:,.0}", cost),
+                Some(cost) => println!("🎯 机会成本: {}", Money::usd(*cost)),
                 None => println!("🎯 无机会成本"),
             }
         }
+
+        if let Some(ref household) = self.builder.household {
+            println!("👨‍👩‍👧 随行家属: {}", household.summary());
+        }
     }
 
     fn handle_summary_confirmation(&self) -> Result<UserAction> {
@@ -621,7 +732,7 @@ impl ProfileManager {
         Ok(())
     }
 
-    fn display_main_menu(&self) -> Result<()> {
+    fn display_main_menu(&mut self) -> Result<()> {
         println!("\n{}", "=".repeat(60));
         println!("🎯 职业发展路径Profile管理系统");
         println!("{}", "=".repeat(60));
@@ -650,13 +761,13 @@ impl ProfileManager {
         Ok(())
     }
 
-    fn create_profile(&mut self) -> Result<()> {
-        let mut creator = ProfileCreationStateMachine::new(self.db.clone());
+    pub fn create_profile(&mut self) -> Result<()> {
+        let mut creator = ProfileCreationStateMachine::new(&mut self.db);
         creator.run()?;
         Ok(())
     }
 
-    fn list_profiles(&self) -> Result<()> {
+    pub fn list_profiles(&mut self) -> Result<()> {
         let profiles = self
             .db
             .get_profiles()
@@ -682,12 +793,12 @@ impl ProfileManager {
             };
 
             println!(
-                "{}. {} {} | 📍 {} | 💰 ${:,.0}/年",
+                "{}. {} {} | 📍 {} | 💰 {}/年",
                 i + 1,
                 type_icon,
                 profile.name,
                 location_str,
-                profile.financial_params.initial_salary_usd
+                Money::usd(profile.financial_params.initial_salary_usd)
             );
         }
 
@@ -702,7 +813,7 @@ impl ProfileManager {
         Ok(())
     }
 
-    fn view_profile_details(&self) -> Result<()> {
+    fn view_profile_details(&mut self) -> Result<()> {
         let profile = self.select_profile("查看详情")?;
         if let Some(profile) = profile {
             self.display_profile_details(&profile);
@@ -710,16 +821,11 @@ impl ProfileManager {
         Ok(())
     }
 
-    fn edit_profile(&mut self) -> Result<()> {
-        println!("\n✏️ 编辑功能开发中...");
-        Ok(())
-    }
-
     fn delete_profile(&mut self) -> Result<()> {
         let profile = self.select_profile("删除")?;
         if let Some(profile) = profile {
             let confirm = Confirm::with_theme(&self.theme)
-                .with_prompt(&format!(
+                .with_prompt(format!(
                     "确定要删除Profile '{}'吗？此操作不可撤销!",
                     profile.name
                 ))
@@ -728,7 +834,7 @@ impl ProfileManager {
 
             if confirm {
                 self.db
-                    .delete_profile(profile.id)
+                    .delete_profile(&profile.id)
                     .map_err(|e| anyhow::anyhow!("{}", e))?;
                 println!("✅ Profile '{}' 已删除", profile.name);
             }
@@ -736,7 +842,23 @@ impl ProfileManager {
         Ok(())
     }
 
-    fn select_profile(&self, action: &str) -> Result<Option<Profile>> {
+    /// 选择要比较的Profile（多选）
+    pub fn select_profiles_for_comparison(&mut self) -> Result<Vec<Profile>> {
+        let profiles = self
+            .db
+            .get_profiles()
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        let names: Vec<_> = profiles.iter().map(|p| p.name.as_str()).collect();
+
+        let selected = MultiSelect::with_theme(&self.theme)
+            .with_prompt("选择要比较的Profile（至少2个）")
+            .items(&names)
+            .interact()?;
+
+        Ok(selected.into_iter().map(|i| profiles[i].clone()).collect())
+    }
+
+    fn select_profile(&mut self, action: &str) -> Result<Option<Profile>> {
         let profiles = self
             .db
             .get_profiles()
@@ -762,7 +884,7 @@ impl ProfileManager {
         choices.push("❌ 取消".to_string());
 
         let choice = Select::with_theme(&self.theme)
-            .with_prompt(&format!("选择要{}的Profile", action))
+            .with_prompt(format!("选择要{}的Profile", action))
             .items(&choices)
             .interact()?;
 
@@ -798,37 +920,40 @@ impl ProfileManager {
         }
 
         println!(
-            "💰 初始年薪: ${:,.0}",
-            profile.financial_params.initial_salary_usd
+            "💰 初始年薪: {}",
+            Money::usd(profile.financial_params.initial_salary_usd)
         );
         println!(
             "📈 薪资增长率: {:.1}%",
             profile.financial_params.salary_growth_rate * 100.0
         );
         println!(
-            "🏠 生活成本: ${:,.0}/年",
-            profile.financial_params.living_cost_usd
+            "🏠 生活成本: {}/年",
+            Money::usd(profile.financial_params.living_cost_usd)
         );
         println!(
             "📊 生活成本增长率: {:.1}%",
             profile.financial_params.living_cost_growth * 100.0
         );
         println!("🏛️ 税率: {:.1}%", profile.financial_params.tax_rate * 100.0);
+        println!("📈 成本档位: {}", profile.cost_level.label());
 
         match &profile.cost_params {
             Some(cost) => {
-                println!("💸 总成本: ${:,.0}", cost.total_cost_usd);
+                println!("💸 总成本: {}", Money::usd(cost.total_cost_usd));
                 println!("📅 成本分摊: {} 年", cost.cost_duration);
-                println!("💸 年均成本: ${:,.0}", cost.annual_cost());
+                println!("💸 年均成本: {}", Money::usd(cost.annual_cost()));
             }
             None => println!("💸 无初期成本"),
         }
 
         match profile.first_year_opportunity_cost {
-            Some(cost) => println!("🎯 机会成本: ${:,.0}", cost),
+            Some(cost) => println!("🎯 机会成本: {}", Money::usd(cost)),
             None => println!("🎯 无机会成本"),
         }
 
+        println!("👨‍👩‍👧 随行家属: {}", profile.household.summary());
+
         if let Some(ref description) = profile.description {
             println!("📝 描述: {}", description);
         }
@@ -854,11 +979,11 @@ impl ProfileManager {
 }
 
 /// 高级Profile编辑器 - 使用状态机实现
-pub struct ProfileEditStateMachine {
+pub struct ProfileEditStateMachine<'a> {
     current_state:    EditState,
     original_profile: Profile,
     builder:          ProfileBuilder,
-    db:               DatabaseManager,
+    db:               &'a mut DatabaseManager,
     theme:            ColorfulTheme,
 }
 
@@ -873,13 +998,12 @@ pub enum EditState {
     EditCostParams,
     EditOpportunityParams,
     Summary,
-    Confirmation,
     Complete,
     Cancelled,
 }
 
-impl ProfileEditStateMachine {
-    pub fn new(profile: Profile, db: DatabaseManager) -> Self {
+impl<'a> ProfileEditStateMachine<'a> {
+    pub fn new(profile: Profile, db: &'a mut DatabaseManager) -> Self {
         let builder = ProfileBuilder {
             name: Some(profile.name.clone()),
             profile_type: Some(profile.profile_type),
@@ -893,8 +1017,10 @@ impl ProfileEditStateMachine {
             living_cost_usd: Some(profile.financial_params.living_cost_usd),
             living_cost_growth: Some(profile.financial_params.living_cost_growth),
             tax_rate: Some(profile.financial_params.tax_rate),
+            cost_level: Some(profile.cost_level),
             cost_params: Some(profile.cost_params.clone()),
             first_year_opportunity_cost: Some(profile.first_year_opportunity_cost),
+            household: Some(profile.household.clone()),
             description: profile.description.clone(),
         };
 
@@ -964,6 +1090,15 @@ impl ProfileEditStateMachine {
                     let mut updated_profile = self.builder.clone().build()?;
                     updated_profile.id = self.original_profile.id; // 保持原始ID
                     updated_profile.created_at = self.original_profile.created_at; // 保持创建时间
+                    updated_profile.scenarios = self.original_profile.scenarios.clone(); // 保留情景集合
+                    updated_profile.timeline = self.original_profile.timeline; // 保留时间安排
+                    updated_profile.loans = self.original_profile.loans.clone(); // 保留贷款
+                    updated_profile.funding = self.original_profile.funding.clone(); // 保留资助
+                    updated_profile.work_authorization = self.original_profile.work_authorization.clone(); // 保留工作许可
+                    updated_profile.employment = self.original_profile.employment.clone(); // 保留就业风险
+                    if updated_profile.location.country == self.original_profile.location.country {
+                        updated_profile.location.region = self.original_profile.location.region.clone(); // 保留地区
+                    }
                     updated_profile.updated_at = Utc::now(); // 更新修改时间
 
                     self.save_profile(&updated_profile)?;
@@ -1079,7 +1214,7 @@ impl ProfileEditStateMachine {
 
         let work_start_delay: u32 = Input::with_theme(&self.theme)
             .with_prompt("开始工作前的延迟年数")
-            .with_initial_text(&self.builder.work_start_delay.unwrap().to_string())
+            .with_initial_text(self.builder.work_start_delay.unwrap().to_string())
             .interact_text()?;
 
         let current_limit = self.builder.work_duration_limit.as_ref().unwrap();
@@ -1114,36 +1249,39 @@ impl ProfileEditStateMachine {
 
         let initial_salary_usd: f64 = Input::with_theme(&self.theme)
             .with_prompt("初始年薪 (USD)")
-            .with_initial_text(&self.builder.initial_salary_usd.unwrap().to_string())
+            .with_initial_text(self.builder.initial_salary_usd.unwrap().to_string())
             .interact_text()?;
 
         let salary_growth_rate: f64 = Input::with_theme(&self.theme)
             .with_prompt("年薪增长率 (小数形式)")
-            .with_initial_text(&self.builder.salary_growth_rate.unwrap().to_string())
+            .with_initial_text(self.builder.salary_growth_rate.unwrap().to_string())
             .interact_text()?;
 
         let living_cost_usd: f64 = Input::with_theme(&self.theme)
             .with_prompt("初始年生活成本 (USD)")
-            .with_initial_text(&self.builder.living_cost_usd.unwrap().to_string())
+            .with_initial_text(self.builder.living_cost_usd.unwrap().to_string())
             .interact_text()?;
 
         let living_cost_growth: f64 = Input::with_theme(&self.theme)
             .with_prompt("生活成本年增长率 (小数形式)")
-            .with_initial_text(&self.builder.living_cost_growth.unwrap().to_string())
+            .with_initial_text(self.builder.living_cost_growth.unwrap().to_string())
             .interact_text()?;
 
         let tax_rate: f64 = Input::with_theme(&self.theme)
             .with_prompt("税率 (小数形式)")
-            .with_initial_text(&self.builder.tax_rate.unwrap().to_string())
+            .with_initial_text(self.builder.tax_rate.unwrap().to_string())
             .interact_text()?;
 
+        let cost_level =
+            prompt_cost_level(&self.theme, self.builder.cost_level.unwrap_or_default())?;
+
         // 验证输入
-        if salary_growth_rate < 0.0 || salary_growth_rate > 1.0 {
+        if !(0.0..=1.0).contains(&salary_growth_rate) {
             println!("⚠️ 薪资增长率应该在0-1之间");
             return Ok(UserAction::Retry);
         }
 
-        if tax_rate < 0.0 || tax_rate > 1.0 {
+        if !(0.0..=1.0).contains(&tax_rate) {
             println!("⚠️ 税率应该在0-1之间");
             return Ok(UserAction::Retry);
         }
@@ -1153,6 +1291,7 @@ impl ProfileEditStateMachine {
         self.builder.living_cost_usd = Some(living_cost_usd);
         self.builder.living_cost_growth = Some(living_cost_growth);
         self.builder.tax_rate = Some(tax_rate);
+        self.builder.cost_level = Some(cost_level);
 
         println!("✅ 财务参数已更新");
         Ok(UserAction::Continue)
@@ -1230,6 +1369,9 @@ impl ProfileEditStateMachine {
             None
         };
 
+        let current_household = self.builder.household.clone().unwrap_or_default();
+        let household = prompt_household(&self.theme, &current_household)?;
+
         let current_desc = self.builder.description.as_deref().unwrap_or("");
         let description: String = Input::with_theme(&self.theme)
             .with_prompt("描述 (可选)")
@@ -1238,6 +1380,7 @@ impl ProfileEditStateMachine {
             .interact_text()?;
 
         self.builder.first_year_opportunity_cost = Some(opportunity_cost);
+        self.builder.household = Some(household);
         self.builder.description = if description.is_empty() {
             None
         } else {
@@ -1335,13 +1478,19 @@ impl ProfileEditStateMachine {
             self.builder.tax_rate.unwrap(),
         );
 
+        self.compare_field(
+            "📈 成本档位",
+            self.original_profile.cost_level.label(),
+            self.builder.cost_level.unwrap_or_default().label(),
+        );
+
         // 成本参数比较
         let original_cost = match &self.original_profile.cost_params {
-            Some(cost) => format!("${:,.0} ({}年)", cost.total_cost_usd, cost.cost_duration),
+            Some(cost) => format!("{} ({}年)", Money::usd(cost.total_cost_usd), cost.cost_duration),
             None => "无成本".to_string(),
         };
         let new_cost = match self.builder.cost_params.as_ref().unwrap() {
-            Some(cost) => format!("${:,.0} ({}年)", cost.total_cost_usd, cost.cost_duration),
+            Some(cost) => format!("{} ({}年)", Money::usd(cost.total_cost_usd), cost.cost_duration),
             None => "无成本".to_string(),
         };
         self.compare_field("💸 成本参数", &original_cost, &new_cost);
@@ -1350,17 +1499,23 @@ impl ProfileEditStateMachine {
         let original_opp = self
             .original_profile
             .first_year_opportunity_cost
-            .map(|x| format!("${:,.0}", x))
+            .map(|x| Money::usd(x).to_string())
             .unwrap_or_else(|| "无".to_string());
         let new_opp = self
             .builder
             .first_year_opportunity_cost
             .as_ref()
             .unwrap()
-            .map(|x| format!("${:,.0}", x))
+            .map(|x| Money::usd(x).to_string())
             .unwrap_or_else(|| "无".to_string());
         self.compare_field("🎯 机会成本", &original_opp, &new_opp);
 
+        self.compare_field(
+            "👨‍👩‍👧 随行家属",
+            &self.original_profile.household.summary(),
+            &self.builder.household.clone().unwrap_or_default().summary(),
+        );
+
         println!("{}", "=".repeat(60));
     }
 
@@ -1382,9 +1537,9 @@ impl ProfileEditStateMachine {
 
     fn compare_currency_field(&self, label: &str, original: f64, new: f64) {
         if (original - new).abs() > 0.01 {
-            println!("{}: ${:,.0} → ${:,.0}", label, original, new);
+            println!("{}: {} → {}", label, Money::usd(original), Money::usd(new));
         } else {
-            println!("{}: ${:,.0} (无更改)", label, original);
+            println!("{}: {} (无更改)", label, Money::usd(original));
         }
     }
 
@@ -1415,16 +1570,16 @@ impl ProfileEditStateMachine {
 
     fn save_profile(&mut self, profile: &Profile) -> Result<()> {
         self.db
-            .save_profile(profile)
+            .update_profile(profile)
             .map_err(|e| anyhow::anyhow!("保存Profile失败: {}", e))
     }
 }
 
 impl ProfileManager {
-    fn edit_profile(&mut self) -> Result<()> {
+    pub fn edit_profile(&mut self) -> Result<()> {
         let profile = self.select_profile("编辑")?;
         if let Some(profile) = profile {
-            let mut editor = ProfileEditStateMachine::new(profile, self.db.clone());
+            let mut editor = ProfileEditStateMachine::new(profile, &mut self.db);
             editor.run()?;
         }
         Ok(())